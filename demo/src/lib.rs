@@ -8,6 +8,7 @@ mod app;
 mod plot_demo;
 
 pub use app::TemplateApp;
+pub use plot_demo::PlotDemo;
 
 /// Create a [`Hyperlink`](egui::Hyperlink) to this egui source code file on github.
 #[macro_export]
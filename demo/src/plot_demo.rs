@@ -6,6 +6,7 @@ use egui::{
 };
 
 use egui_plot::{
+    gallery::{Gallery, PlotExample},
     Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner,
     GridInput, GridMark, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint,
     PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
@@ -13,26 +14,6 @@ use egui_plot::{
 
 // ----------------------------------------------------------------------------
 
-#[derive(PartialEq, Eq, serde::Deserialize, serde::Serialize)]
-enum Panel {
-    Lines,
-    Markers,
-    Legend,
-    Charts,
-    Items,
-    Interaction,
-    CustomAxes,
-    LinkedAxes,
-}
-
-impl Default for Panel {
-    fn default() -> Self {
-        Self::Lines
-    }
-}
-
-// ----------------------------------------------------------------------------
-
 #[derive(Default, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct PlotDemo {
     line_demo: LineDemo,
@@ -43,10 +24,24 @@ pub struct PlotDemo {
     interaction_demo: InteractionDemo,
     custom_axes_demo: CustomAxesDemo,
     linked_axes_demo: LinkedAxesDemo,
-    open_panel: Panel,
+    gallery: Gallery,
 }
 
 impl PlotDemo {
+    /// All the plots of the demo, in the order of their tabs.
+    pub fn examples_mut(&mut self) -> Vec<&mut dyn PlotExample> {
+        vec![
+            &mut self.line_demo,
+            &mut self.marker_demo,
+            &mut self.legend_demo,
+            &mut self.charts_demo,
+            &mut self.items_demo,
+            &mut self.interaction_demo,
+            &mut self.custom_axes_demo,
+            &mut self.linked_axes_demo,
+        ]
+    }
+
     pub fn ui(&mut self, ui: &mut egui::Ui) {
         ui.horizontal(|ui| {
             egui::reset_button(ui, self, "Reset");
@@ -65,44 +60,10 @@ impl PlotDemo {
             ui.add(crate::egui_github_link_file!());
         });
         ui.separator();
-        ui.horizontal_wrapped(|ui| {
-            ui.selectable_value(&mut self.open_panel, Panel::Lines, "Lines");
-            ui.selectable_value(&mut self.open_panel, Panel::Markers, "Markers");
-            ui.selectable_value(&mut self.open_panel, Panel::Legend, "Legend");
-            ui.selectable_value(&mut self.open_panel, Panel::Charts, "Charts");
-            ui.selectable_value(&mut self.open_panel, Panel::Items, "Items");
-            ui.selectable_value(&mut self.open_panel, Panel::Interaction, "Interaction");
-            ui.selectable_value(&mut self.open_panel, Panel::CustomAxes, "Custom Axes");
-            ui.selectable_value(&mut self.open_panel, Panel::LinkedAxes, "Linked Axes");
-        });
-        ui.separator();
 
-        match self.open_panel {
-            Panel::Lines => {
-                self.line_demo.ui(ui);
-            }
-            Panel::Markers => {
-                self.marker_demo.ui(ui);
-            }
-            Panel::Legend => {
-                self.legend_demo.ui(ui);
-            }
-            Panel::Charts => {
-                self.charts_demo.ui(ui);
-            }
-            Panel::Items => {
-                self.items_demo.ui(ui);
-            }
-            Panel::Interaction => {
-                self.interaction_demo.ui(ui);
-            }
-            Panel::CustomAxes => {
-                self.custom_axes_demo.ui(ui);
-            }
-            Panel::LinkedAxes => {
-                self.linked_axes_demo.ui(ui);
-            }
-        }
+        let mut gallery = std::mem::take(&mut self.gallery);
+        gallery.ui(ui, &mut self.examples_mut());
+        self.gallery = gallery;
     }
 }
 
@@ -254,7 +215,11 @@ impl LineDemo {
     }
 }
 
-impl LineDemo {
+impl PlotExample for LineDemo {
+    fn name(&self) -> &'static str {
+        "Lines"
+    }
+
     fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         ScrollArea::horizontal().show(ui, |ui| {
             self.options_ui(ui);
@@ -334,6 +299,12 @@ impl MarkerDemo {
             })
             .collect()
     }
+}
+
+impl PlotExample for MarkerDemo {
+    fn name(&self) -> &'static str {
+        "Markers"
+    }
 
     fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         ui.horizontal(|ui| {
@@ -394,6 +365,12 @@ impl LegendDemo {
             100,
         ))
     }
+}
+
+impl PlotExample for LegendDemo {
+    fn name(&self) -> &'static str {
+        "Legend"
+    }
 
     fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         ScrollArea::horizontal().show(ui, |ui| {
@@ -414,7 +391,9 @@ impl LegendDemo {
             })
             .response
     }
+}
 
+impl LegendDemo {
     fn settings_ui(&mut self, ui: &mut egui::Ui) {
         let Self { config } = self;
         egui::Grid::new("settings").show(ui, |ui| {
@@ -502,9 +481,14 @@ impl CustomAxesDemo {
 
         marks
     }
+}
+
+impl PlotExample for CustomAxesDemo {
+    fn name(&self) -> &'static str {
+        "Custom Axes"
+    }
 
-    #[allow(clippy::unused_self)]
-    fn ui(&self, ui: &mut egui::Ui) -> Response {
+    fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         const MINS_PER_DAY: f64 = CustomAxesDemo::MINS_PER_DAY;
         const MINS_PER_H: f64 = CustomAxesDemo::MINS_PER_H;
 
@@ -640,6 +624,12 @@ impl LinkedAxesDemo {
         plot_ui.line(Self::sin());
         plot_ui.line(Self::cos());
     }
+}
+
+impl PlotExample for LinkedAxesDemo {
+    fn name(&self) -> &'static str {
+        "Linked Axes"
+    }
 
     fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         ui.horizontal(|ui| {
@@ -657,7 +647,9 @@ impl LinkedAxesDemo {
             .show(ui, |ui| self.plots_ui(ui))
             .inner
     }
+}
 
+impl LinkedAxesDemo {
     fn plots_ui(&self, ui: &mut egui::Ui) -> Response {
         ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
         let link_group_id = ui.id().with("linked_demo");
@@ -699,7 +691,11 @@ struct ItemsDemo {
     texture: Option<egui::TextureHandle>,
 }
 
-impl ItemsDemo {
+impl PlotExample for ItemsDemo {
+    fn name(&self) -> &'static str {
+        "Items"
+    }
+
     fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         let n = 100;
         let mut sin_values: Vec<_> = (0..=n)
@@ -747,18 +743,32 @@ impl ItemsDemo {
             .show_y(false)
             .data_aspect(1.0);
         plot.show(ui, |plot_ui| {
-            plot_ui.hline(HLine::new(9.0).name("Lines horizontal"));
-            plot_ui.hline(HLine::new(-9.0).name("Lines horizontal"));
-            plot_ui.vline(VLine::new(9.0).name("Lines vertical"));
-            plot_ui.vline(VLine::new(-9.0).name("Lines vertical"));
+            for y in [9.0, -9.0] {
+                let hline = HLine::new(y).name("Lines horizontal");
+                plot_ui.hline(hline.show_in_legend(true).include_in_auto_bounds(true));
+            }
+            for x in [9.0, -9.0] {
+                let vline = VLine::new(x).name("Lines vertical");
+                plot_ui.vline(vline.show_in_legend(true).include_in_auto_bounds(true));
+            }
             plot_ui.line(line.name("Line with fill"));
             plot_ui.polygon(polygon.name("Convex polygon"));
             plot_ui.points(points.name("Points with stems"));
-            plot_ui.text(Text::new(PlotPoint::new(-3.0, -3.0), "wow").name("Text"));
-            plot_ui.text(Text::new(PlotPoint::new(-2.0, 2.5), "so graph").name("Text"));
-            plot_ui.text(Text::new(PlotPoint::new(3.0, 3.0), "much color").name("Text"));
-            plot_ui.text(Text::new(PlotPoint::new(2.5, -2.0), "such plot").name("Text"));
-            plot_ui.image(image.name("Image"));
+            for (x, y, text) in [
+                (-3.0, -3.0, "wow"),
+                (-2.0, 2.5, "so graph"),
+                (3.0, 3.0, "much color"),
+                (2.5, -2.0, "such plot"),
+            ] {
+                let text = Text::new(PlotPoint::new(x, y), text).name("Text");
+                plot_ui.text(text.show_in_legend(true));
+            }
+            plot_ui.image(
+                image
+                    .name("Image")
+                    .show_in_legend(true)
+                    .include_in_auto_bounds(true),
+            );
             plot_ui.arrows(arrows.name("Arrows"));
         })
         .response
@@ -770,9 +780,12 @@ impl ItemsDemo {
 #[derive(Default, PartialEq, serde::Deserialize, serde::Serialize)]
 struct InteractionDemo {}
 
-impl InteractionDemo {
-    #[allow(clippy::unused_self)]
-    fn ui(&self, ui: &mut egui::Ui) -> Response {
+impl PlotExample for InteractionDemo {
+    fn name(&self) -> &'static str {
+        "Interaction"
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         let id = ui.make_persistent_id("interaction_demo");
 
         // This demonstrates how to read info about the plot _before_ showing it:
@@ -893,7 +906,11 @@ impl Default for ChartsDemo {
     }
 }
 
-impl ChartsDemo {
+impl PlotExample for ChartsDemo {
+    fn name(&self) -> &'static str {
+        "Charts"
+    }
+
     fn ui(&mut self, ui: &mut egui::Ui) -> Response {
         ScrollArea::horizontal().show(ui, |ui| {
             self.options_ui(ui);
@@ -904,7 +921,9 @@ impl ChartsDemo {
             Chart::BoxPlot => self.box_plot(ui),
         }
     }
+}
 
+impl ChartsDemo {
     fn options_ui(&mut self, ui: &mut egui::Ui) -> Response {
         ui.horizontal(|ui| {
             ui.vertical(|ui| {
@@ -986,7 +1005,8 @@ impl ChartsDemo {
             Bar::new(4.5, 4.0).name("Day 5"),
         ])
         .width(0.7)
-        .name("Set 1");
+        .name("Set 1")
+        .hover_aggregate(true);
 
         let mut chart2 = BarChart::new(vec![
             Bar::new(0.5, 1.0),
@@ -997,6 +1017,7 @@ impl ChartsDemo {
         ])
         .width(0.7)
         .name("Set 2")
+        .hover_aggregate(true)
         .stack_on(&[&chart1]);
 
         let mut chart3 = BarChart::new(vec![
@@ -1008,6 +1029,7 @@ impl ChartsDemo {
         ])
         .width(0.7)
         .name("Set 3")
+        .hover_aggregate(true)
         .stack_on(&[&chart1, &chart2]);
 
         let mut chart4 = BarChart::new(vec![
@@ -1019,6 +1041,7 @@ impl ChartsDemo {
         ])
         .width(0.7)
         .name("Set 4")
+        .hover_aggregate(true)
         .stack_on(&[&chart1, &chart2, &chart3]);
 
         if !self.vertical {
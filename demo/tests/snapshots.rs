@@ -0,0 +1,90 @@
+//! Screenshot tests of every plot in the demo, rendered with `egui_kittest`.
+//!
+//! The snapshots are not checked in yet. Record them, or accept the changes after changing
+//! how plots look, with:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test -p demo --test snapshots -- --include-ignored
+//! ```
+
+use egui::Vec2;
+use egui_kittest::{Harness, SnapshotError, SnapshotOptions};
+use egui_plot::gallery::PlotExample;
+
+/// How many frames [`harness`] runs the example for, so auto-bounds and layouts can settle.
+const SETTLE_FRAMES: usize = 3;
+
+/// The name of the snapshot of the example: its name in lower case, with `_` for spaces.
+fn snapshot_name(example: &dyn PlotExample) -> String {
+    example.name().to_lowercase().replace(' ', "_")
+}
+
+/// A headless [`Harness`] showing the example in a window of the given size in ui points,
+/// after running a few frames.
+fn harness(example: &mut dyn PlotExample, size: Vec2) -> Harness<'_> {
+    let mut harness = Harness::builder().with_size(size).build_ui(move |ui| {
+        example.ui(ui);
+    });
+    for _ in 0..SETTLE_FRAMES {
+        harness.step();
+    }
+    harness
+}
+
+/// Renders the example and compares it with its snapshot in the output path of the options.
+///
+/// A missing or different snapshot is an error. The snapshot is only written when the
+/// `UPDATE_SNAPSHOTS` environment variable is set.
+fn check_snapshot(
+    example: &mut dyn PlotExample,
+    size: Vec2,
+    options: &SnapshotOptions,
+) -> Result<(), SnapshotError> {
+    let name = snapshot_name(example);
+    harness(example, size).try_snapshot_options(&name, options)
+}
+
+#[test]
+#[ignore = "the snapshots are not recorded yet, see the module docs"]
+fn test_demo_snapshots() {
+    let mut demo = demo::PlotDemo::default();
+    for example in demo.examples_mut() {
+        if let Err(err) = check_snapshot(example, egui::vec2(800.0, 600.0), &Default::default()) {
+            panic!("{err}");
+        }
+    }
+}
+
+#[test]
+fn test_missing_snapshot_fails() {
+    use egui_plot::{Line, Plot};
+
+    struct Diagonal;
+
+    impl PlotExample for Diagonal {
+        fn name(&self) -> &'static str {
+            "Diagonal Line"
+        }
+
+        fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+            Plot::new("diagonal")
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(vec![[0.0, 0.0], [1.0, 1.0]]).width(4.0));
+                })
+                .response
+        }
+    }
+
+    assert_eq!(snapshot_name(&Diagonal), "diagonal_line");
+
+    let dir = tempfile::tempdir().unwrap();
+    let options = SnapshotOptions::default().output_path(dir.path());
+    let result = check_snapshot(&mut Diagonal, Vec2::new(200.0, 150.0), &options);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_none() {
+        assert!(result.is_err(), "a missing snapshot fails");
+        assert!(
+            !dir.path().join("diagonal_line.png").exists(),
+            "and isn't recorded"
+        );
+    }
+}
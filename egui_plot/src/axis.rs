@@ -1,38 +1,20 @@
 use std::{fmt::Debug, ops::RangeInclusive, sync::Arc};
 
 use egui::{
-    emath::{remap_clamp, Rot2},
-    epaint::TextShape,
-    Pos2, Rangef, Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, WidgetText,
+    emath::Rot2, epaint::TextShape, Align2, Color32, Pos2, Rangef, Rect, Response, Sense,
+    TextStyle, TextWrapMode, Ui, Vec2, WidgetText,
 };
 
-use super::{transform::PlotTransform, GridMark};
+use super::{
+    transform::{Axis, PlotTransform},
+    GridMark,
+};
 
 // Gap between tick labels and axis label in units of the axis label height
 const AXIS_LABEL_GAP: f32 = 0.25;
 
 pub(super) type AxisFormatterFn<'a> = dyn Fn(GridMark, &RangeInclusive<f64>) -> String + 'a;
 
-/// X or Y axis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Axis {
-    /// Horizontal X-Axis
-    X = 0,
-
-    /// Vertical Y-axis
-    Y = 1,
-}
-
-impl From<Axis> for usize {
-    #[inline]
-    fn from(value: Axis) -> Self {
-        match value {
-            Axis::X => 0,
-            Axis::Y => 1,
-        }
-    }
-}
-
 /// Placement of the horizontal X-Axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VPlacement {
@@ -103,12 +85,19 @@ impl From<Placement> for VPlacement {
 #[derive(Clone)]
 pub struct AxisHints<'a> {
     pub(super) label: WidgetText,
+    pub(super) label_wrap_mode: TextWrapMode,
+    pub(super) label_max_width: f32,
     pub(super) formatter: Arc<AxisFormatterFn<'a>>,
     pub(super) min_thickness: f32,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+    pub(super) offset_notation: bool,
+    pub(super) exponent_notation: bool,
 }
 
+/// The space between the edge of a value tag and its text.
+const VALUE_TAG_PADDING: Vec2 = Vec2::new(3.0, 1.0);
+
 // TODO(JohannesProgrammiert): this just a guess. It might cease to work if a user changes font size.
 const LINE_HEIGHT: f32 = 12.0;
 
@@ -130,6 +119,8 @@ impl<'a> AxisHints<'a> {
     pub fn new(axis: Axis) -> Self {
         Self {
             label: Default::default(),
+            label_wrap_mode: TextWrapMode::Extend,
+            label_max_width: f32::INFINITY,
             formatter: Arc::new(Self::default_formatter),
             min_thickness: 14.0,
             placement: Placement::LeftBottom,
@@ -137,6 +128,8 @@ impl<'a> AxisHints<'a> {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
             },
+            offset_notation: false,
+            exponent_notation: false,
         }
     }
 
@@ -152,6 +145,18 @@ impl<'a> AxisHints<'a> {
         self
     }
 
+    /// Label the ticks with SI prefixes, like `1.2k`, `3.4M` or `5µ`, see [`si_formatter`].
+    #[inline]
+    pub fn formatter_si(self) -> Self {
+        self.formatter(si_formatter)
+    }
+
+    /// Label the ticks as a number of bytes, like `512 B` or `1.5 MiB`, see [`bytes_formatter`].
+    #[inline]
+    pub fn formatter_bytes(self) -> Self {
+        self.formatter(bytes_formatter)
+    }
+
     fn default_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
         // Example: If the step to the next tick is `0.01`, we should use 2 decimals of precision:
         let num_decimals = -mark.step_size.log10().round() as usize;
@@ -168,6 +173,27 @@ impl<'a> AxisHints<'a> {
         self
     }
 
+    /// What to do with an axis label that is longer than the axis or [`Self::label_max_width`].
+    /// Default: [`TextWrapMode::Extend`].
+    ///
+    /// With [`TextWrapMode::Wrap`] the label is broken into several lines, and the axis gets
+    /// thicker to fit them. With [`TextWrapMode::Truncate`] it is cut short with `…`.
+    #[inline]
+    pub fn label_wrap_mode(mut self, wrap_mode: TextWrapMode) -> Self {
+        self.label_wrap_mode = wrap_mode;
+        self
+    }
+
+    /// The width at which to wrap or truncate the axis label, if shorter than the axis.
+    /// Default: the length of the axis.
+    ///
+    /// Has no effect with [`TextWrapMode::Extend`], see [`Self::label_wrap_mode`].
+    #[inline]
+    pub fn label_max_width(mut self, max_width: f32) -> Self {
+        self.label_max_width = max_width;
+        self
+    }
+
     /// Specify minimum thickness of the axis
     #[inline]
     pub fn min_thickness(mut self, min_thickness: f32) -> Self {
@@ -204,6 +230,30 @@ impl<'a> AxisHints<'a> {
         self
     }
 
+    /// Label the ticks relative to a common offset, shown once at the end of the axis,
+    /// when the visible range is far from zero compared to its size. Default: `false`.
+    ///
+    /// For example, a range of `1_000_001..=1_000_010` is labeled `1` to `10` and `+1e6`,
+    /// instead of with long labels only differing in their last digits.
+    /// The formatter gets the values and the range relative to the offset.
+    #[inline]
+    pub fn offset_notation(mut self, offset_notation: bool) -> Self {
+        self.offset_notation = offset_notation;
+        self
+    }
+
+    /// Label the ticks in units of a common power of ten, shown once at the end of the axis
+    /// like `×10⁶`, when the visible values are very large or very small. Default: `false`.
+    ///
+    /// This keeps the labels short, like `1.5` instead of `1500000`.
+    /// The formatter gets the values and the range in units of the power of ten.
+    /// See also [`scientific_formatter`] to give each label its own exponent instead.
+    #[inline]
+    pub fn exponent_notation(mut self, exponent_notation: bool) -> Self {
+        self.exponent_notation = exponent_notation;
+        self
+    }
+
     pub(super) fn thickness(&self, axis: Axis) -> f32 {
         match axis {
             Axis::X => self.min_thickness.max(if self.label.is_empty() {
@@ -232,6 +282,9 @@ pub(super) struct AxisWidget<'a> {
     pub rect: Rect,
     pub transform: Option<PlotTransform>,
     pub steps: Arc<Vec<GridMark>>,
+
+    /// Where the pointer is, to tag the axis with the value there.
+    pub value_tag: Option<Pos2>,
 }
 
 impl<'a> AxisWidget<'a> {
@@ -243,6 +296,7 @@ impl<'a> AxisWidget<'a> {
             rect,
             transform: None,
             steps: Default::default(),
+            value_tag: None,
         }
     }
 
@@ -258,11 +312,18 @@ impl<'a> AxisWidget<'a> {
             return (response, 0.0);
         };
         let tick_labels_thickness = self.add_tick_labels(ui, transform, axis);
+        if let Some(pointer) = self.value_tag {
+            self.add_value_tag(ui, transform, axis, pointer);
+        }
 
+        let axis_length = match axis {
+            Axis::X => response.rect.width(),
+            Axis::Y => response.rect.height(),
+        };
         let galley = self.hints.label.into_galley(
             ui,
-            Some(TextWrapMode::Extend),
-            f32::INFINITY,
+            Some(self.hints.label_wrap_mode),
+            axis_length.min(self.hints.label_max_width),
             TextStyle::Body,
         );
 
@@ -317,9 +378,34 @@ impl<'a> AxisWidget<'a> {
         let font_id = TextStyle::Body.resolve(ui.style());
         let label_spacing = self.hints.label_spacing;
         let mut thickness: f32 = 0.0;
+
+        let offset = if self.hints.offset_notation {
+            axis_offset(&self.range)
+        } else {
+            None
+        };
+        let range = match offset {
+            Some(offset) => (self.range.start() - offset)..=(self.range.end() - offset),
+            None => self.range.clone(),
+        };
+        let exponent = if self.hints.exponent_notation {
+            axis_exponent(&range)
+        } else {
+            None
+        };
+        let unit = exponent.map_or(1.0, |exponent| 10.0_f64.powi(exponent));
+        let range = (range.start() / unit)..=(range.end() / unit);
+        if offset.is_some() || exponent.is_some() {
+            self.add_offset_label(ui, offset, exponent, axis);
+        }
+
         // Add tick labels:
         for step in self.steps.iter() {
-            let text = (self.hints.formatter)(*step, &self.range);
+            let mark = GridMark {
+                value: (step.value - offset.unwrap_or(0.0)) / unit,
+                step_size: step.step_size / unit,
+            };
+            let text = (self.hints.formatter)(mark, &range);
             if !text.is_empty() {
                 let spacing_in_points =
                     (transform.dpos_dvalue()[usize::from(axis)] * step.step_size).abs() as f32;
@@ -329,24 +415,28 @@ impl<'a> AxisWidget<'a> {
                     continue;
                 }
 
-                // Fade in labels as they get further apart:
-                let strength = remap_clamp(spacing_in_points, label_spacing, 0.0..=1.0);
-
-                let text_color = super::color_from_strength(ui, strength);
-                let galley = ui
-                    .painter()
-                    .layout_no_wrap(text, font_id.clone(), text_color);
-
-                if spacing_in_points < galley.size()[axis as usize] {
+                // The color is filled in by the text shape, once the strength is known:
+                let galley =
+                    ui.painter()
+                        .layout_no_wrap(text, font_id.clone(), Color32::PLACEHOLDER);
+                let strength = tick_label_strength(
+                    spacing_in_points,
+                    label_spacing,
+                    galley.size()[axis as usize],
+                );
+                if strength <= 0.0 {
                     continue; // the galley won't fit (likely too wide on the X axis).
                 }
+                let text_color = super::color_from_strength(ui, strength);
 
                 match axis {
                     Axis::X => {
                         thickness = thickness.max(galley.size().y);
 
                         let projected_point = super::PlotPoint::new(step.value, 0.0);
-                        let center_x = transform.position_from_point(&projected_point).x;
+                        let center_x = ui.painter().round_to_pixel_center(
+                            transform.position_from_point(&projected_point).x,
+                        );
                         let y = match VPlacement::from(self.hints.placement) {
                             VPlacement::Bottom => self.rect.min.y,
                             VPlacement::Top => self.rect.max.y - galley.size().y,
@@ -358,7 +448,9 @@ impl<'a> AxisWidget<'a> {
                         thickness = thickness.max(galley.size().x);
 
                         let projected_point = super::PlotPoint::new(0.0, step.value);
-                        let center_y = transform.position_from_point(&projected_point).y;
+                        let center_y = ui.painter().round_to_pixel_center(
+                            transform.position_from_point(&projected_point).y,
+                        );
 
                         match HPlacement::from(self.hints.placement) {
                             HPlacement::Left => {
@@ -394,4 +486,270 @@ impl<'a> AxisWidget<'a> {
         }
         thickness
     }
+
+    /// Paint the value at the pointer in a filled tag on the axis, next to the plot frame.
+    fn add_value_tag(&self, ui: &Ui, transform: PlotTransform, axis: Axis, pointer: Pos2) {
+        let value = transform.value_from_position(pointer);
+        let mark = GridMark {
+            value: match axis {
+                Axis::X => value.x,
+                Axis::Y => value.y,
+            },
+            // The size of a pixel, to show as many digits as can be told apart.
+            step_size: transform.dvalue_dpos()[usize::from(axis)].abs(),
+        };
+        let text = (self.hints.formatter)(mark, &self.range);
+        if text.is_empty() {
+            return;
+        }
+
+        let visuals = &ui.visuals().selection;
+        let galley = ui.painter().layout_no_wrap(
+            text,
+            TextStyle::Body.resolve(ui.style()),
+            visuals.stroke.color,
+        );
+        let size = galley.size() + 2.0 * VALUE_TAG_PADDING;
+        let (pos, align) = match (axis, self.hints.placement) {
+            (Axis::X, Placement::LeftBottom) => {
+                (Pos2::new(pointer.x, self.rect.top()), Align2::CENTER_TOP)
+            }
+            (Axis::X, Placement::RightTop) => (
+                Pos2::new(pointer.x, self.rect.bottom()),
+                Align2::CENTER_BOTTOM,
+            ),
+            (Axis::Y, Placement::LeftBottom) => (
+                Pos2::new(self.rect.right(), pointer.y),
+                Align2::RIGHT_CENTER,
+            ),
+            (Axis::Y, Placement::RightTop) => {
+                (Pos2::new(self.rect.left(), pointer.y), Align2::LEFT_CENTER)
+            }
+        };
+        let mut rect = align.anchor_size(pos, size);
+        // Keep the tag within the axis.
+        rect = rect.translate(match axis {
+            Axis::X => Vec2::new(
+                (self.rect.left() - rect.left()).max(0.0)
+                    - (rect.right() - self.rect.right()).max(0.0),
+                0.0,
+            ),
+            Axis::Y => Vec2::new(
+                0.0,
+                (self.rect.top() - rect.top()).max(0.0)
+                    - (rect.bottom() - self.rect.bottom()).max(0.0),
+            ),
+        });
+
+        let painter = ui.painter();
+        painter.rect_filled(rect, 2.0, visuals.bg_fill);
+        painter.galley(rect.min + VALUE_TAG_PADDING, galley, visuals.stroke.color);
+    }
+
+    /// Paint the offset and the power of ten of the tick labels at the far end of the axis.
+    fn add_offset_label(&self, ui: &Ui, offset: Option<f64>, exponent: Option<i32>, axis: Axis) {
+        let (pos, align) = match (axis, self.hints.placement) {
+            (Axis::X, Placement::LeftBottom) => (self.rect.right_bottom(), Align2::RIGHT_BOTTOM),
+            (Axis::X, Placement::RightTop) => (self.rect.right_top(), Align2::RIGHT_TOP),
+            (Axis::Y, Placement::LeftBottom) => (self.rect.right_top(), Align2::RIGHT_BOTTOM),
+            (Axis::Y, Placement::RightTop) => (self.rect.left_top(), Align2::LEFT_BOTTOM),
+        };
+        let text = match (exponent, offset) {
+            (Some(exponent), Some(offset)) => format!("×10{} {offset:+e}", superscript(exponent)),
+            (Some(exponent), None) => format!("×10{}", superscript(exponent)),
+            (None, Some(offset)) => format!("{offset:+e}"),
+            (None, None) => return,
+        };
+        ui.painter().text(
+            pos,
+            align,
+            text,
+            TextStyle::Body.resolve(ui.style()),
+            ui.visuals().text_color(),
+        );
+    }
+}
+
+/// The offset to label the ticks of the range relative to, if that saves enough digits.
+///
+/// The offset is rounded to the power of ten above the size of the range, so that the labels
+/// only show the digits that change within the range.
+fn axis_offset(range: &RangeInclusive<f64>) -> Option<f64> {
+    // Only use an offset if it saves at least this many digits, like matplotlib.
+    const MIN_SAVED_DIGITS: f64 = 4.0;
+
+    let (min, max) = (*range.start(), *range.end());
+    let span = max - min;
+    if !span.is_finite() || span <= 0.0 {
+        return None;
+    }
+    let unit = 10.0_f64.powf(span.log10().ceil());
+    let offset = (min / unit).floor() * unit;
+    let saved_digits = (offset.abs().max(max.abs()) / unit).log10();
+    (offset != 0.0 && saved_digits >= MIN_SAVED_DIGITS).then_some(offset)
+}
+
+/// The power of ten to label the ticks of the range in, if its values are very large or very small.
+fn axis_exponent(range: &RangeInclusive<f64>) -> Option<i32> {
+    let max_abs = range.start().abs().max(range.end().abs());
+    if !max_abs.is_finite() || max_abs == 0.0 {
+        return None;
+    }
+    let exponent = max_abs.log10().floor() as i32;
+    (exponent <= -3 || 4 <= exponent).then_some(exponent)
+}
+
+/// The exponent written in superscript digits, like `⁻³`.
+fn superscript(exponent: i32) -> String {
+    exponent
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
+/// Tick labels in scientific notation like `1.5e6` for very large or very small values,
+/// and like those of the default formatter otherwise.
+///
+/// Use it with [`AxisHints::formatter`] to keep the labels of such values short.
+pub fn scientific_formatter(mark: GridMark, range: &RangeInclusive<f64>) -> String {
+    let value = mark.value;
+    let exponent = value.abs().log10().floor();
+    if value == 0.0 || !value.is_finite() || (-3.0..4.0).contains(&exponent) {
+        return AxisHints::default_formatter(mark, range);
+    }
+    // Show as many decimals as it takes to tell the ticks apart.
+    let num_decimals = (exponent - mark.step_size.log10().floor()).clamp(0.0, 15.0) as usize;
+    let mantissa = value / 10.0_f64.powf(exponent);
+    format!("{mantissa:.num_decimals$}e{exponent}")
+}
+
+/// Tick labels with SI prefixes, from `y` (10⁻²⁴) to `Y` (10²⁴), like `1.2k`, `3.4M` or `5µ`.
+///
+/// Append a unit in the axis label, or wrap this in your own formatter.
+pub fn si_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+    const PREFIXES: [&str; 17] = [
+        "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+    ];
+
+    let value = mark.value;
+    if value == 0.0 || !value.is_finite() {
+        return AxisHints::default_formatter(mark, &(0.0..=0.0));
+    }
+    let index = ((value.abs().log10() / 3.0).floor() as i32 + 8).clamp(0, 16);
+    let unit = 10.0_f64.powi(3 * (index - 8));
+    format!(
+        "{}{}",
+        format_in_unit(value, mark.step_size, unit),
+        PREFIXES[index as usize]
+    )
+}
+
+/// Tick labels for a number of bytes, in binary units like `512 B`, `1.5 KiB` or `3 GiB`.
+pub fn bytes_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+    const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    let value = mark.value;
+    let index = if value == 0.0 || !value.is_finite() {
+        0
+    } else {
+        (value.abs().log2() / 10.0).floor().clamp(0.0, 6.0) as usize
+    };
+    let unit = 1024.0_f64.powi(index as i32);
+    format!(
+        "{} {}",
+        format_in_unit(value, mark.step_size, unit),
+        UNITS[index]
+    )
+}
+
+/// Formats `value / unit` with as many decimals as it takes to tell ticks `step_size` apart.
+fn format_in_unit(value: f64, step_size: f64, unit: f64) -> String {
+    let num_decimals = (-(step_size / unit).log10().floor()).clamp(0.0, 15.0) as usize;
+    emath::format_with_decimals_in_range(value / unit, 0..=num_decimals)
+}
+
+/// How strongly to paint a tick label, from `0.0` to `1.0`.
+///
+/// Labels fade in with the strength of their mark as the marks get further apart,
+/// see [`AxisHints::label_spacing`]. They fade out over the same distance as the marks get
+/// too close together for the label to fit, instead of disappearing at once.
+fn tick_label_strength(spacing_in_points: f32, label_spacing: Rangef, label_size: f32) -> f32 {
+    let fit = Rangef::new(label_size, label_size + label_spacing.span());
+    crate::mark_strength(spacing_in_points, label_spacing)
+        .min(crate::mark_strength(spacing_in_points, fit))
+}
+
+#[test]
+fn test_tick_label_strength() {
+    let label_spacing = Rangef::new(60.0, 80.0);
+
+    // A short label follows the strength of its mark.
+    assert_eq!(tick_label_strength(60.0, label_spacing, 10.0), 0.0);
+    assert_eq!(tick_label_strength(70.0, label_spacing, 10.0), 0.5);
+    assert_eq!(tick_label_strength(100.0, label_spacing, 10.0), 1.0);
+
+    // A long label fades out as it stops fitting between the marks, instead of popping out.
+    assert_eq!(tick_label_strength(130.0, label_spacing, 100.0), 1.0);
+    assert_eq!(tick_label_strength(110.0, label_spacing, 100.0), 0.5);
+    assert_eq!(tick_label_strength(100.0, label_spacing, 100.0), 0.0);
+}
+
+#[test]
+fn test_si_formatter() {
+    let mark = |value, step_size| GridMark { value, step_size };
+    let range = 0.0..=1.0;
+    assert_eq!(si_formatter(mark(1200.0, 100.0), &range), "1.2k");
+    assert_eq!(si_formatter(mark(3_400_000.0, 100_000.0), &range), "3.4M");
+    assert_eq!(si_formatter(mark(-0.000_005, 0.000_001), &range), "-5µ");
+    assert_eq!(si_formatter(mark(20.0, 10.0), &range), "20");
+    assert_eq!(bytes_formatter(mark(512.0, 256.0), &range), "512 B");
+    assert_eq!(bytes_formatter(mark(1536.0, 512.0), &range), "1.5 KiB");
+    assert_eq!(
+        bytes_formatter(mark(3.0 * 1024.0_f64.powi(3), 1e9), &range),
+        "3 GiB"
+    );
+}
+
+#[test]
+fn test_axis_exponent() {
+    assert_eq!(axis_exponent(&(0.0..=2_500_000.0)), Some(6));
+    assert_eq!(axis_exponent(&(-0.002..=0.001)), Some(-3));
+    assert_eq!(axis_exponent(&(0.0..=100.0)), None);
+    assert_eq!(superscript(-12), "⁻¹²");
+
+    let mark = |value, step_size| GridMark { value, step_size };
+    assert_eq!(
+        scientific_formatter(mark(1_500_000.0, 500_000.0), &(0.0..=1.0)),
+        "1.5e6"
+    );
+    assert_eq!(
+        scientific_formatter(mark(0.000_2, 0.000_1), &(0.0..=1.0)),
+        "2e-4"
+    );
+    assert_eq!(scientific_formatter(mark(2.5, 0.1), &(0.0..=1.0)), "2.5");
+}
+
+#[test]
+fn test_axis_offset() {
+    assert_eq!(axis_offset(&(1_000_001.0..=1_000_010.0)), Some(1_000_000.0));
+    assert_eq!(
+        axis_offset(&(-1_000_010.0..=-1_000_001.0)),
+        Some(-1_000_010.0)
+    );
+    assert_eq!(axis_offset(&(0.0..=10.0)), None);
+    assert_eq!(axis_offset(&(100.0..=110.0)), None);
+    assert_eq!(axis_offset(&(1_000_000.0..=1_000_000.0)), None);
 }
@@ -0,0 +1,114 @@
+use ahash::{HashMap, HashSet};
+use egui::{Context, Id};
+
+use crate::{PlotBounds, PlotItem};
+
+/// The bounds of the plot items, reused between frames.
+///
+/// Entries are only kept for items with a [`PlotItem::data_version`],
+/// and are invalidated as soon as that version changes.
+#[derive(Clone, Default)]
+pub(crate) struct ItemCache {
+    bounds: HashMap<Id, (u64, PlotBounds)>,
+
+    data_bounds: Option<CachedDataBounds>,
+
+    /// Entries used this frame. Everything else is evicted on [`Self::store`].
+    used: HashSet<Id>,
+}
+
+/// The bounds of all items together, and the item keys and versions they were computed from.
+#[derive(Clone)]
+struct CachedDataBounds {
+    versions: Vec<(Id, u64)>,
+    bounds: PlotBounds,
+}
+
+impl ItemCache {
+    pub fn load(ctx: &Context, plot_id: Id) -> Self {
+        ctx.data_mut(|d| d.get_temp(Self::id(plot_id)))
+            .unwrap_or_default()
+    }
+
+    pub fn store(mut self, ctx: &Context, plot_id: Id) {
+        let Self { bounds, used, .. } = &mut self;
+        bounds.retain(|id, _| used.contains(id));
+        used.clear();
+        ctx.data_mut(|d| d.insert_temp(Self::id(plot_id), self));
+    }
+
+    fn id(plot_id: Id) -> Id {
+        plot_id.with("item_cache")
+    }
+
+    /// The bounds of the item at the given index, reused from an earlier frame if possible.
+    pub fn bounds(&mut self, index: usize, item: &dyn PlotItem) -> PlotBounds {
+        let Some(version) = item.data_version() else {
+            return item.bounds();
+        };
+
+        let key = Self::key(index, item);
+        self.used.insert(key);
+
+        match self.bounds.get(&key) {
+            Some((cached_version, bounds)) if *cached_version == version => *bounds,
+            _ => {
+                let bounds = item.bounds();
+                self.bounds.insert(key, (version, bounds));
+                bounds
+            }
+        }
+    }
+
+    /// The bounds of all items together, reused from an earlier frame as long as
+    /// no item was added, removed or changed its version.
+    ///
+    /// Without a version for every item, the bounds are merged anew each frame,
+    /// reusing the bounds of the items that have one.
+    pub fn data_bounds(&mut self, items: &[Box<dyn PlotItem>]) -> PlotBounds {
+        let versions: Option<Vec<(Id, u64)>> = items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| Some((Self::key(index, item.as_ref()), item.data_version()?)))
+            .collect();
+        if let (Some(versions), Some(cached)) = (&versions, &self.data_bounds) {
+            if *versions == cached.versions {
+                // Keep the bounds of the items for when one of them changes.
+                self.used.extend(versions.iter().map(|(key, _)| *key));
+                return cached.bounds;
+            }
+        }
+
+        let mut bounds = PlotBounds::NOTHING;
+        for (index, item) in items.iter().enumerate() {
+            bounds.merge(&self.bounds(index, item.as_ref()));
+        }
+        self.data_bounds = versions.map(|versions| CachedDataBounds { versions, bounds });
+        bounds
+    }
+
+    /// Items without an id are identified by their name and position.
+    fn key(index: usize, item: &dyn PlotItem) -> Id {
+        item.id()
+            .unwrap_or_else(|| Id::new(item.name()).with(index))
+    }
+}
+
+#[test]
+fn test_data_bounds() {
+    use crate::{Line, PlotPoints};
+
+    let line = |version: u64, max: f64| -> Vec<Box<dyn PlotItem>> {
+        let points = PlotPoints::from(vec![[0.0, 0.0], [max, max]]);
+        vec![Box::new(
+            Line::new(points).name("line").data_version(version),
+        )]
+    };
+    let mut cache = ItemCache::default();
+    assert_eq!(cache.data_bounds(&line(1, 1.0)).max(), [1.0, 1.0]);
+
+    // The same version is trusted to have the same data.
+    assert_eq!(cache.data_bounds(&line(1, 2.0)).max(), [1.0, 1.0]);
+    assert_eq!(cache.data_bounds(&line(2, 2.0)).max(), [2.0, 2.0]);
+    assert_eq!(cache.data_bounds(&[]).max(), PlotBounds::NOTHING.max());
+}
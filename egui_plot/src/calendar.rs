@@ -0,0 +1,210 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    deduplicate_marks, fill_marks_between, next_power,
+    relative_time::{nice_time_step, DAY, HOUR, MINUTE},
+    GridInput, GridMark, GridSpacer,
+};
+
+const WEEK: f64 = 7.0 * DAY;
+const YEAR: f64 = 365.2425 * DAY;
+const MONTH: f64 = YEAR / 12.0;
+
+/// The Unix epoch was a Thursday, so the first Monday was four days later.
+const FIRST_MONDAY: f64 = 4.0 * DAY;
+
+/// Beyond this many seconds from the epoch, about three million years, there is no calendar grid.
+const MAX_SECONDS: f64 = 1e14;
+
+/// The distance between the marks of a time axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TimeStep {
+    /// Whole multiples of this many seconds, up to a day.
+    Seconds(f64),
+
+    /// Mondays.
+    Weeks,
+
+    /// The first day of every this many months, counted from January.
+    Months(i64),
+}
+
+impl TimeStep {
+    /// The smallest step of at least `min_step` seconds that falls on calendar boundaries.
+    fn at_least(min_step: f64) -> Self {
+        if min_step <= DAY {
+            Self::Seconds(nice_time_step(min_step))
+        } else if min_step <= WEEK {
+            Self::Weeks
+        } else {
+            let months = [1, 3, 6, 12, 24, 60]
+                .into_iter()
+                .find(|months| min_step <= *months as f64 * MONTH)
+                .unwrap_or_else(|| 12 * next_power(min_step / YEAR, 10.0) as i64);
+            Self::Months(months)
+        }
+    }
+
+    /// The (approximate) length of the step, in seconds.
+    fn seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds,
+            Self::Weeks => WEEK,
+            Self::Months(months) => months as f64 * MONTH,
+        }
+    }
+
+    fn fill_marks(self, out: &mut Vec<GridMark>, (min, max): (f64, f64)) {
+        match self {
+            Self::Seconds(seconds) => fill_marks_between(out, seconds, (min, max)),
+            Self::Weeks => {
+                let start = out.len();
+                fill_marks_between(out, WEEK, (min - FIRST_MONDAY, max - FIRST_MONDAY));
+                for mark in &mut out[start..] {
+                    mark.value += FIRST_MONDAY;
+                }
+            }
+            Self::Months(months) => {
+                let step_size = self.seconds();
+                let (year, month, _) = civil_from_days((min / DAY).floor() as i64);
+                let mut index = (12 * year + month - 1).div_euclid(months) * months;
+                loop {
+                    let value = days_from_civil(index.div_euclid(12), index.rem_euclid(12) + 1, 1)
+                        as f64
+                        * DAY;
+                    if max < value {
+                        break;
+                    }
+                    if min <= value {
+                        out.push(GridMark { value, step_size });
+                    }
+                    index += months;
+                }
+            }
+        }
+    }
+}
+
+/// Marks at whole seconds, minutes, hours, days, weeks, months and years, for an axis with
+/// seconds since the Unix epoch in UTC that is labeled with [`time_formatter`].
+///
+/// The weeks start on Monday. See also [`crate::Plot::x_axis_time`].
+pub fn time_grid_spacer() -> GridSpacer<'static> {
+    Box::new(|input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if input.base_step_size.abs() < f64::EPSILON
+            || !(min.abs() < MAX_SECONDS && max.abs() < MAX_SECONDS)
+        {
+            return Vec::new();
+        }
+
+        let smallest = TimeStep::at_least(input.base_step_size);
+        let middle = TimeStep::at_least(5.0 * smallest.seconds());
+        let largest = TimeStep::at_least(5.0 * middle.seconds());
+
+        let mut marks = Vec::new();
+        for step in [smallest, middle, largest] {
+            step.fill_marks(&mut marks, input.bounds);
+        }
+        deduplicate_marks(marks, smallest.seconds())
+    })
+}
+
+/// Tick labels for an axis with seconds since the Unix epoch in UTC, like `"2024"`, `"2024-03"`,
+/// `"2024-03-15"`, `"15:30"` or `"15:30:05"`, depending on the distance between the marks.
+///
+/// Marks at midnight show the date, so the days can be told apart when zoomed in.
+pub fn time_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+    format_time(mark.value, mark.step_size)
+}
+
+/// Formats a time in seconds since the epoch with the precision fitting a distance of `step` seconds between labels.
+fn format_time(seconds: f64, step: f64) -> String {
+    // Don't let rounding errors put a mark at midnight on the day before.
+    let days = ((seconds + 1e-3 * step) / DAY).floor();
+    let (year, month, day) = civil_from_days(days as i64);
+    let time_of_day = (seconds - days * DAY).max(0.0);
+
+    if 0.99 * YEAR <= step {
+        format!("{year}")
+    } else if 0.99 * MONTH <= step {
+        format!("{year}-{month:02}")
+    } else if DAY <= step || time_of_day < 1e-3 * step {
+        format!("{year}-{month:02}-{day:02}")
+    } else {
+        let hours = (time_of_day / HOUR).floor();
+        let minutes = ((time_of_day - hours * HOUR) / MINUTE).floor();
+        let seconds = time_of_day - hours * HOUR - minutes * MINUTE;
+        if MINUTE <= step {
+            format!("{hours:02}:{minutes:02}")
+        } else {
+            let decimals = (-step.log10().floor()).max(0.0) as usize;
+            let width = if decimals == 0 { 2 } else { 3 + decimals };
+            format!("{hours:02}:{minutes:02}:{seconds:0width$.decimals$}")
+        }
+    }
+}
+
+/// The year, month and day of the given day since the Unix epoch, in the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    // See https://howardhinnant.github.io/date_algorithms.html
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + 400 * era + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// The day since the Unix epoch of the given date, the inverse of [`civil_from_days`].
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = 365 * year_of_era + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    146_097 * era + day_of_era - 719_468
+}
+
+#[test]
+fn test_format_time() {
+    let march_1st_2024 = 1_709_251_200.0;
+    assert_eq!(civil_from_days(19_783), (2024, 3, 1));
+    assert_eq!(days_from_civil(2024, 3, 1), 19_783);
+    assert_eq!(format_time(march_1st_2024, YEAR), "2024");
+    assert_eq!(format_time(march_1st_2024, MONTH), "2024-03");
+    assert_eq!(format_time(march_1st_2024 - 1e-6, HOUR), "2024-03-01");
+    assert_eq!(format_time(march_1st_2024 + 5400.0, HOUR), "01:30");
+    assert_eq!(format_time(march_1st_2024 + 65.0, 5.0), "00:01:05");
+    assert_eq!(format_time(march_1st_2024 + 65.25, 0.01), "00:01:05.25");
+}
+
+#[test]
+fn test_time_grid_spacer() {
+    let spacer = time_grid_spacer();
+    let new_year_2024 = 1_704_067_200.0;
+    let marks = spacer(GridInput {
+        bounds: (new_year_2024 - 10.0 * DAY, new_year_2024 + 200.0 * DAY),
+        base_step_size: 10.0 * DAY,
+        previous_step_size: None,
+    });
+    assert!(marks.contains(&GridMark {
+        value: new_year_2024,
+        step_size: 6.0 * MONTH,
+    }));
+    let march_1st_2024 = 1_709_251_200.0;
+    assert!(marks.iter().any(|mark| mark.value == march_1st_2024));
+    assert!(marks
+        .iter()
+        .all(|mark| civil_from_days((mark.value / DAY) as i64).2 == 1));
+}
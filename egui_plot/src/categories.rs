@@ -0,0 +1,139 @@
+use std::ops::RangeInclusive;
+
+use crate::{generate_marks, next_power, Bar, BoxElem, BoxSpread, GridInput, GridMark, GridSpacer};
+
+/// Names for the whole-number positions along an axis, like the days of the week,
+/// for a categorical axis set with [`crate::Plot::x_axis_categories`].
+///
+/// The first category is at `0.0`, the second at `1.0`, and so on.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{BarChart, Categories, Plot};
+///
+/// let days = Categories::new(["Mon", "Tue", "Wed", "Thu", "Fri"]);
+/// let bars = vec![days.bar("Mon", 3.0), days.bar("Wed", 5.0), days.bar("Fri", 2.0)];
+/// Plot::new("week").x_axis_categories(&days).show(ui, |plot_ui| {
+///     plot_ui.bar_chart(BarChart::new(bars));
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Categories {
+    names: Vec<String>,
+}
+
+impl Categories {
+    pub fn new(names: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            names: names.into_iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    /// The number of categories.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The names of the categories, in order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The position of the category with the given name along the axis, if there is one.
+    pub fn position(&self, name: &str) -> Option<f64> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|index| index as f64)
+    }
+
+    /// The name of the category at the given position, if it is close to a whole number.
+    pub fn name_at(&self, position: f64) -> Option<&str> {
+        let index = position.round();
+        if (position - index).abs() > 1e-6 || index < 0.0 {
+            return None;
+        }
+        self.names.get(index as usize).map(String::as_str)
+    }
+
+    /// A bar of the given category, named after it.
+    ///
+    /// A bar of a category that is not in the list is placed at `NaN`, so it isn't shown.
+    pub fn bar(&self, name: &str, value: f64) -> Bar {
+        Bar::new(self.position(name).unwrap_or(f64::NAN), value).name(name)
+    }
+
+    /// A box of the given category, named after it.
+    ///
+    /// A box of a category that is not in the list is placed at `NaN`, so it isn't shown.
+    pub fn box_elem(&self, name: &str, spread: BoxSpread) -> BoxElem {
+        BoxElem::new(self.position(name).unwrap_or(f64::NAN), spread).name(name)
+    }
+
+    /// Marks at the positions of the categories only.
+    ///
+    /// When zoomed out too far to show all of them, only every 2nd, 5th, 10th, … category is shown.
+    pub fn grid_spacer(&self) -> GridSpacer<'static> {
+        let len = self.names.len() as f64;
+        Box::new(move |input: GridInput| -> Vec<GridMark> {
+            let (min, max) = input.bounds;
+            let (min, max) = (min.max(0.0), max.min(len - 0.5));
+            if max < min || !input.base_step_size.is_finite() {
+                return Vec::new();
+            }
+            let step = nice_category_step(input.base_step_size);
+            generate_marks([step, 10.0 * step, 100.0 * step], (min, max))
+        })
+    }
+
+    /// Tick labels with the names of the categories.
+    pub fn formatter(&self) -> impl Fn(GridMark, &RangeInclusive<f64>) -> String {
+        let categories = self.clone();
+        move |mark, _range| {
+            categories
+                .name_at(mark.value)
+                .unwrap_or_default()
+                .to_owned()
+        }
+    }
+}
+
+/// The smallest of 1, 2, 5, 10, 20, 50, … categories that is at least `min_step`.
+fn nice_category_step(min_step: f64) -> f64 {
+    if min_step <= 1.0 {
+        return 1.0;
+    }
+    let power = next_power(min_step, 10.0) / 10.0;
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * power)
+        .find(|step| min_step <= *step)
+        .unwrap_or(10.0 * power)
+}
+
+#[test]
+fn test_categories() {
+    let days = Categories::new(["Mon", "Tue", "Wed"]);
+    assert_eq!(days.position("Tue"), Some(1.0));
+    assert_eq!(days.position("Sun"), None);
+    assert_eq!(days.name_at(2.0), Some("Wed"));
+    assert_eq!(days.name_at(1.5), None);
+    assert_eq!(days.name_at(3.0), None);
+
+    let marks = days.grid_spacer()(GridInput {
+        bounds: (-1.0, 10.0),
+        base_step_size: 0.1,
+        previous_step_size: None,
+    });
+    let values: Vec<f64> = marks.iter().map(|mark| mark.value).collect();
+    assert_eq!(values, [0.0, 1.0, 2.0]);
+
+    assert_eq!(nice_category_step(0.3), 1.0);
+    assert_eq!(nice_category_step(3.0), 5.0);
+    assert_eq!(nice_category_step(12.0), 20.0);
+}
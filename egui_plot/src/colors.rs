@@ -0,0 +1,66 @@
+//! Color helpers used by the built-in items, for custom items and overlays that should match them.
+
+use std::ops::RangeInclusive;
+
+use egui::{emath::lerp, epaint::Hsva, Color32, Rgba};
+
+/// The default palette of [`crate::Heatmap`] and [`crate::Hexbin`], from dark purple to yellow.
+pub const DEFAULT_PALETTE: [Color32; 5] = [
+    Color32::from_rgb(68, 1, 84),
+    Color32::from_rgb(59, 82, 139),
+    Color32::from_rgb(33, 145, 140),
+    Color32::from_rgb(94, 201, 98),
+    Color32::from_rgb(253, 231, 37),
+];
+
+/// The `i`:th color assigned to items without an explicit color.
+pub fn auto_color(i: usize) -> Color32 {
+    let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
+    let h = i as f32 * golden_ratio;
+    Hsva::new(h, 0.85, 0.5, 1.0).into() // TODO(emilk): OkLab or some other perspective color space
+}
+
+/// The colors assigned to items without an explicit color, in order.
+pub fn auto_colors() -> impl Iterator<Item = Color32> {
+    (0..).map(auto_color)
+}
+
+/// The color of the value within the range, interpolated between the colors of the palette.
+pub fn palette_color(palette: &[Color32], value: f64, range: &RangeInclusive<f64>) -> Color32 {
+    let span = range.end() - range.start();
+    let t = if span > 0.0 {
+        ((value - range.start()) / span).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    let position = t * (palette.len() - 1) as f64;
+    let i = (position as usize).min(palette.len() - 1);
+    let (from, to) = (palette[i], palette[(i + 1).min(palette.len() - 1)]);
+    let t = (position - i as f64) as f32;
+    let channel = |from: u8, to: u8| lerp(from as f32..=to as f32, t).round() as u8;
+    Color32::from_rgba_premultiplied(
+        channel(from.r(), to.r()),
+        channel(from.g(), to.g()),
+        channel(from.b(), to.b()),
+        channel(from.a(), to.a()),
+    )
+}
+
+/// Black or white, whichever is easier to read on top of the given background.
+pub fn contrast_text_color(background: Color32) -> Color32 {
+    let rgba = Rgba::from(background);
+    let luminance = 0.2126 * rgba.r() + 0.7152 * rgba.g() + 0.0722 * rgba.b();
+    if luminance > 0.18 {
+        Color32::BLACK
+    } else {
+        Color32::WHITE
+    }
+}
+
+#[test]
+fn test_contrast_text_color() {
+    assert_eq!(contrast_text_color(Color32::WHITE), Color32::BLACK);
+    assert_eq!(contrast_text_color(Color32::BLACK), Color32::WHITE);
+    assert_eq!(contrast_text_color(DEFAULT_PALETTE[0]), Color32::WHITE);
+    assert_eq!(contrast_text_color(DEFAULT_PALETTE[4]), Color32::BLACK);
+}
@@ -0,0 +1,188 @@
+//! The data types and math of the plots, which only depend on `std` and `emath` and not on egui.
+//!
+//! Data pipelines that prepare plot data away from the ui, like on a server,
+//! can use these to share the exact same types and math with the plots.
+//! Turn off the default `egui` feature to compile only this module.
+
+use emath::{Pos2, Vec2};
+
+pub use crate::transform::{Axis, AxisScale, BoundsMargin, PlotBounds, PlotTransform};
+
+/// A point coordinate in the plot.
+///
+/// Uses f64 for improved accuracy to enable plotting
+/// large values (e.g. unix time on x axis).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PlotPoint {
+    /// This is often something monotonically increasing, such as time, but doesn't have to be.
+    /// Goes from left to right.
+    pub x: f64,
+
+    /// Goes from bottom to top (inverse of everything else in egui!).
+    pub y: f64,
+}
+
+impl From<[f64; 2]> for PlotPoint {
+    #[inline]
+    fn from([x, y]: [f64; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl PlotPoint {
+    #[inline(always)]
+    pub fn new(x: impl Into<f64>, y: impl Into<f64>) -> Self {
+        Self {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn to_pos2(self) -> Pos2 {
+        Pos2::new(self.x as f32, self.y as f32)
+    }
+
+    #[inline(always)]
+    pub fn to_vec2(self) -> Vec2 {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    /// The point at the given angle in radians and distance from the origin, see [`crate::Plot::polar`].
+    #[inline]
+    pub fn from_polar(angle: f64, radius: f64) -> Self {
+        Self::new(radius * angle.cos(), radius * angle.sin())
+    }
+
+    /// The angle in radians, between `0` and `TAU`, and the distance from the origin of this point.
+    #[inline]
+    pub fn to_polar(self) -> [f64; 2] {
+        [
+            self.y.atan2(self.x).rem_euclid(std::f64::consts::TAU),
+            self.x.hypot(self.y),
+        ]
+    }
+
+    /// Are both coordinates neither NaN nor infinite? Other points are not shown.
+    #[inline(always)]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+}
+
+/// How to split the samples of a histogram into bins, see [`crate::BarChart::from_samples`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Bins {
+    /// A bin width from the spread of the samples, using the Freedman–Diaconis rule,
+    /// or Sturges' rule for the number of bins if most samples are equal.
+    #[default]
+    Auto,
+
+    /// This many bins of equal width between the smallest and largest sample.
+    Count(usize),
+
+    /// Bins of this width, starting at the smallest sample.
+    Width(f64),
+}
+
+/// The most bins of a histogram, so that tiny bin widths don't allocate without bounds.
+const MAX_BINS: usize = 10_000;
+
+/// Splits the samples into bins of equal width, like for [`crate::BarChart::from_samples`].
+///
+/// Returns the start and width of the bins, and the number of finite samples in each of them.
+pub fn histogram(samples: &[f64], bins: Bins) -> (f64, f64, Vec<usize>) {
+    let mut sorted: Vec<f64> = samples.iter().copied().filter(|s| s.is_finite()).collect();
+    sorted.sort_by(f64::total_cmp);
+    let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+        return (0.0, 1.0, Vec::new());
+    };
+    if min == max {
+        return (min - 0.5, 1.0, vec![sorted.len()]);
+    }
+
+    let n = sorted.len() as f64;
+    let quantile = |q: f64| sorted[((n - 1.0) * q).round() as usize];
+    let sturges = || (max - min) / (n.log2().ceil() + 1.0);
+    let width = match bins {
+        Bins::Auto => {
+            let iqr = quantile(0.75) - quantile(0.25);
+            if iqr > 0.0 {
+                2.0 * iqr / n.cbrt()
+            } else {
+                sturges()
+            }
+        }
+        Bins::Count(count) => (max - min) / count.max(1) as f64,
+        Bins::Width(width) if width > 0.0 && width.is_finite() => width,
+        Bins::Width(_) => sturges(),
+    };
+    let width = width.max((max - min) / MAX_BINS as f64);
+
+    let count = (((max - min) / width).ceil() as usize).clamp(1, MAX_BINS);
+    let mut counts = vec![0; count];
+    for sample in sorted {
+        // The largest sample goes in the last bin, not past it.
+        let bin = (((sample - min) / width) as usize).min(count - 1);
+        counts[bin] += 1;
+    }
+    (min, width, counts)
+}
+
+/// Drops every point closer than `min_distance` to the previously kept one.
+/// The first and last point are always kept.
+pub fn thin_out(points: Vec<Pos2>, min_distance: f32) -> Vec<Pos2> {
+    if min_distance <= 0.0 || points.len() < 3 {
+        return points;
+    }
+    let min_distance_sq = min_distance * min_distance;
+    let last = points[points.len() - 1];
+    let mut thinned = Vec::with_capacity(points.len());
+    for point in points {
+        if thinned.last().map_or(true, |prev: &Pos2| {
+            prev.distance_sq(point) >= min_distance_sq
+        }) {
+            thinned.push(point);
+        }
+    }
+    if thinned.last() != Some(&last) {
+        thinned.push(last);
+    }
+    thinned
+}
+
+#[test]
+fn test_histogram() {
+    assert_eq!(
+        histogram(&[0.0, 1.0, 1.5, 4.0, f64::NAN], Bins::Count(2)),
+        (0.0, 2.0, vec![3, 1])
+    );
+    assert_eq!(
+        histogram(&[0.0, 1.0, 2.5], Bins::Width(1.0)),
+        (0.0, 1.0, vec![1, 1, 1])
+    );
+    assert_eq!(histogram(&[3.0, 3.0], Bins::Auto), (2.5, 1.0, vec![2]));
+
+    let samples: Vec<f64> = (0..1000).map(|i| (i % 100) as f64).collect();
+    let (start, width, counts) = histogram(&samples, Bins::Auto);
+    assert_eq!(start, 0.0);
+    assert_eq!(counts.iter().sum::<usize>(), 1000);
+    // Freedman–Diaconis: twice the interquartile range of 49, over the cube root of 1000.
+    assert!((width - 9.8).abs() < 1e-9, "{width}");
+}
+
+#[test]
+fn test_polar_round_trip() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    for [angle, radius] in [[0.0, 1.0], [FRAC_PI_2, 2.0], [PI, 0.5], [5.0, 3.0]] {
+        let [angle2, radius2] = PlotPoint::from_polar(angle, radius).to_polar();
+        assert!((angle2 - angle).abs() < 1e-12, "{angle} -> {angle2}");
+        assert!((radius2 - radius).abs() < 1e-12, "{radius} -> {radius2}");
+    }
+
+    // Negative angles come back between `0` and `TAU`.
+    let [angle, _] = PlotPoint::from_polar(-FRAC_PI_2, 1.0).to_polar();
+    assert!((angle - 3.0 * FRAC_PI_2).abs() < 1e-12);
+}
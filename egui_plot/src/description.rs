@@ -0,0 +1,69 @@
+use std::ops::RangeInclusive;
+
+use egui::Id;
+
+use crate::{cache::ItemCache, GridMark, PlotBounds, PlotGeometry, PlotItem};
+
+/// A comparable summary of what a plot showed in a frame, for assertions in tests.
+///
+/// See [`crate::PlotResponse::snapshot_description`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotDescription {
+    /// The visible bounds.
+    pub bounds: PlotBounds,
+
+    /// The visible items, in the order they were added.
+    pub items: Vec<ItemDescription>,
+
+    /// The values of the grid marks within the bounds on the x-axis, in ascending order.
+    pub x_ticks: Vec<f64>,
+
+    /// The values of the grid marks within the bounds on the y-axis, in ascending order.
+    pub y_ticks: Vec<f64>,
+
+    /// The names in the legend, in order, and whether the corresponding items are shown.
+    pub legend_entries: Vec<(String, bool)>,
+}
+
+/// A summary of a single plot item, see [`PlotDescription`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ItemDescription {
+    pub name: String,
+    pub id: Option<Id>,
+
+    /// The number of points of items made of points, and zero for all other items.
+    pub point_count: usize,
+
+    /// The bounds of the data of the item.
+    pub bounds: PlotBounds,
+}
+
+impl ItemDescription {
+    pub(crate) fn describe(items: &[Box<dyn PlotItem>], cache: &mut ItemCache) -> Vec<Self> {
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| Self {
+                name: item.name().to_owned(),
+                id: item.id(),
+                point_count: match item.geometry() {
+                    PlotGeometry::Points(points) => points.len(),
+                    PlotGeometry::None | PlotGeometry::Rects => 0,
+                },
+                bounds: cache.bounds(index, item.as_ref()),
+            })
+            .collect()
+    }
+}
+
+/// The values of the given marks within the range, in ascending order.
+pub(crate) fn ticks(marks: &[GridMark], range: &RangeInclusive<f64>) -> Vec<f64> {
+    let mut ticks: Vec<f64> = marks
+        .iter()
+        .map(|mark| mark.value)
+        .filter(|value| range.contains(value))
+        .collect();
+    ticks.sort_by(f64::total_cmp);
+    ticks.dedup();
+    ticks
+}
@@ -0,0 +1,40 @@
+use crate::Axis;
+
+/// A configuration of a [`crate::Plot`] that can't be shown, see [`crate::Plot::try_show`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlotError {
+    /// An aspect ratio that is zero, negative, NaN or infinite.
+    InvalidAspect(f32),
+
+    /// Aspect settings that contradict each other, like a width, height and view aspect
+    /// that don't match, or a data aspect other than one in polar mode.
+    ConflictingAspect(String),
+
+    /// A width or height that is zero, negative, NaN or infinite.
+    EmptySize { width: f32, height: f32 },
+
+    /// A minimum size of the bounds larger than their maximum size.
+    InvalidBoundsSize { axis: Axis, min: f64, max: f64 },
+
+    /// Two custom axes with the same label on the same side of the plot.
+    DuplicateAxis { axis: Axis, label: String },
+}
+
+impl std::fmt::Display for PlotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidAspect(aspect) => write!(f, "Invalid aspect ratio: {aspect}"),
+            Self::ConflictingAspect(reason) => write!(f, "Conflicting aspect settings: {reason}"),
+            Self::EmptySize { width, height } => write!(f, "Empty plot size: {width}x{height}"),
+            Self::InvalidBoundsSize { axis, min, max } => write!(
+                f,
+                "Minimum {axis:?} bounds size {min} is larger than the maximum {max}"
+            ),
+            Self::DuplicateAxis { axis, label } => {
+                write!(f, "Duplicate {axis:?} axis {label:?} on the same side")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlotError {}
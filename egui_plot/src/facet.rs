@@ -0,0 +1,111 @@
+use egui::{Id, Response, RichText, Ui, Vec2b};
+
+use crate::{Figure, PlotUi, Share};
+
+/// A [`Figure`] of small plots, one per group of data, with linked axes and a single legend.
+///
+/// Items with the same name get the same color in all the plots,
+/// and can be hidden in all of them at once by clicking their legend entry.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Facets, Line, PlotPoints};
+///
+/// let groups = (1..=4).map(|k| (format!("k = {k}"), k as f64));
+/// Facets::new("waves").columns(2).show(ui, groups, |plot_ui, k| {
+///     let sin = PlotPoints::from_explicit_callback(move |x| (k * x).sin(), .., 100);
+///     plot_ui.line(Line::new(sin).name("sin"));
+///     let cos = PlotPoints::from_explicit_callback(move |x| (k * x).cos(), .., 100);
+///     plot_ui.line(Line::new(cos).name("cos"));
+/// });
+/// # });
+/// ```
+pub struct Facets {
+    id: Id,
+    columns: usize,
+    plot_height: f32,
+    link_axes: Vec2b,
+    link_cursor: Vec2b,
+    legend: bool,
+}
+
+impl Facets {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            columns: 3,
+            plot_height: 150.0,
+            link_axes: true.into(),
+            link_cursor: Vec2b::new(true, false),
+            legend: true,
+        }
+    }
+
+    /// How many plots to put next to each other. Default: `3`.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// The height of each plot. Default: `150.0`.
+    #[inline]
+    pub fn plot_height(mut self, height: f32) -> Self {
+        self.plot_height = height;
+        self
+    }
+
+    /// Which axes are shared between the plots. Default: both.
+    #[inline]
+    pub fn link_axes(mut self, link: impl Into<Vec2b>) -> Self {
+        self.link_axes = link.into();
+        self
+    }
+
+    /// Which cursors are shown in all plots when hovering one of them. Default: only x.
+    #[inline]
+    pub fn link_cursor(mut self, link: impl Into<Vec2b>) -> Self {
+        self.link_cursor = link.into();
+        self
+    }
+
+    /// Show a legend of all the item names below the plots. Default: `true`.
+    #[inline]
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Show one plot for each `(title, group)`, filled in by `build_fn`.
+    pub fn show<G>(
+        self,
+        ui: &mut Ui,
+        groups: impl IntoIterator<Item = (String, G)>,
+        mut build_fn: impl FnMut(&mut PlotUi, G),
+    ) -> Response {
+        let Self {
+            id,
+            columns,
+            plot_height,
+            link_axes,
+            link_cursor,
+            legend,
+        } = self;
+
+        let share = |linked| if linked { Share::All } else { Share::None };
+        Figure::new(id, columns)
+            .plot_height(plot_height)
+            .share_x(share(link_axes.x))
+            .share_y(share(link_axes.y))
+            .link_cursor(link_cursor)
+            .legend(legend)
+            .show(ui, |fig| {
+                for (title, group) in groups {
+                    fig.plot(RichText::new(title).strong(), |plot_ui| {
+                        build_fn(plot_ui, group);
+                    });
+                }
+            })
+            .response
+    }
+}
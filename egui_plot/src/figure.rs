@@ -0,0 +1,248 @@
+use egui::{Id, InnerResponse, RichText, Ui, Vec2b, WidgetText};
+
+use crate::{Plot, PlotResponse, PlotUi};
+
+/// Which plots of a [`Figure`] share the bounds of an axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Share {
+    /// Every plot has its own bounds.
+    None,
+
+    /// The plots in the same row share their bounds.
+    Row,
+
+    /// The plots in the same column share their bounds.
+    Column,
+
+    /// All plots share their bounds.
+    All,
+}
+
+/// A grid of plots with shared styling, linked axes and a single legend.
+///
+/// Items with the same name get the same color in all the plots,
+/// and can be hidden in all of them at once with the legend below the plots.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Figure, Line, PlotPoints, Share};
+///
+/// Figure::new("my_figure", 2)
+///     .share_y(Share::All)
+///     .style(|plot| plot.show_grid(false))
+///     .show(ui, |fig| {
+///         fig.plot("sin", |plot_ui| {
+///             plot_ui.line(Line::new(PlotPoints::from_explicit_callback(f64::sin, .., 100)));
+///         });
+///         fig.plot("cos", |plot_ui| {
+///             plot_ui.line(Line::new(PlotPoints::from_explicit_callback(f64::cos, .., 100)));
+///         });
+///     });
+/// # });
+/// ```
+pub struct Figure<'a> {
+    id: Id,
+    columns: usize,
+    plot_height: f32,
+    share_x: Share,
+    share_y: Share,
+    link_cursor: Vec2b,
+    legend: bool,
+    style: Option<Box<dyn Fn(Plot<'a>) -> Plot<'a> + 'a>>,
+}
+
+impl<'a> Figure<'a> {
+    /// A figure with the given number of plots next to each other.
+    pub fn new(id_source: impl std::hash::Hash, columns: usize) -> Self {
+        Self {
+            id: Id::new(id_source),
+            columns: columns.max(1),
+            plot_height: 150.0,
+            share_x: Share::Column,
+            share_y: Share::Row,
+            link_cursor: Vec2b::new(true, false),
+            legend: true,
+            style: None,
+        }
+    }
+
+    /// The height of each plot. Default: `150.0`.
+    #[inline]
+    pub fn plot_height(mut self, height: f32) -> Self {
+        self.plot_height = height;
+        self
+    }
+
+    /// Which plots share the bounds of the x-axis. Default: [`Share::Column`].
+    #[inline]
+    pub fn share_x(mut self, share: Share) -> Self {
+        self.share_x = share;
+        self
+    }
+
+    /// Which plots share the bounds of the y-axis. Default: [`Share::Row`].
+    #[inline]
+    pub fn share_y(mut self, share: Share) -> Self {
+        self.share_y = share;
+        self
+    }
+
+    /// Which cursors are shown in all plots when hovering one of them. Default: only x.
+    #[inline]
+    pub fn link_cursor(mut self, link: impl Into<Vec2b>) -> Self {
+        self.link_cursor = link.into();
+        self
+    }
+
+    /// Show a legend of all the item names below the plots. Default: `true`.
+    #[inline]
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Settings applied to every plot of the figure, like axis labels or the grid.
+    #[inline]
+    pub fn style(mut self, style: impl Fn(Plot<'a>) -> Plot<'a> + 'a) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    /// Show the figure. Add the plots with [`FigureUi::plot`].
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut FigureUi<'_, 'a>) -> R,
+    ) -> InnerResponse<R> {
+        let hidden_id = self.id.with("hidden");
+        let mut hidden: ahash::HashSet<String> =
+            ui.data_mut(|d| d.get_temp(hidden_id)).unwrap_or_default();
+
+        let response = ui.vertical(|ui| {
+            let spacing = ui.spacing().item_spacing.x;
+            let plot_width = ((ui.available_width() - spacing * (self.columns - 1) as f32)
+                / self.columns as f32)
+                .max(1.0);
+
+            let inner = egui::Grid::new(self.id.with("grid"))
+                .show(ui, |ui| {
+                    add_contents(&mut FigureUi {
+                        figure: &self,
+                        ui,
+                        index: 0,
+                        plot_width,
+                        hidden: &hidden,
+                    })
+                })
+                .inner;
+
+            if self.legend {
+                let names = PlotUi::color_group_names(ui.ctx(), self.color_group());
+                ui.horizontal_wrapped(|ui| {
+                    for (index, name) in names.into_iter().enumerate() {
+                        let mut visible = !hidden.contains(&name);
+                        let text = RichText::new(&name).color(crate::colors::auto_color(index));
+                        if ui.checkbox(&mut visible, text).changed() {
+                            if visible {
+                                hidden.remove(&name);
+                            } else {
+                                hidden.insert(name);
+                            }
+                        }
+                    }
+                });
+            }
+
+            inner
+        });
+
+        ui.data_mut(|d| d.insert_temp(hidden_id, hidden));
+        response
+    }
+
+    fn color_group(&self) -> Id {
+        self.id.with("colors")
+    }
+
+    /// The link group of the plot at the given row and column, for an axis shared like this.
+    fn link_group(&self, share: Share, axis: &str, row: usize, column: usize) -> Option<Id> {
+        match share {
+            Share::None => None,
+            Share::Row => Some(self.id.with((axis, "row", row))),
+            Share::Column => Some(self.id.with((axis, "column", column))),
+            Share::All => Some(self.id.with(axis)),
+        }
+    }
+}
+
+/// Adds plots to a [`Figure`], see [`Figure::show`].
+pub struct FigureUi<'f, 'a> {
+    figure: &'f Figure<'a>,
+    ui: &'f mut Ui,
+    index: usize,
+    plot_width: f32,
+    hidden: &'f ahash::HashSet<String>,
+}
+
+impl<'a> FigureUi<'_, 'a> {
+    /// Add the next plot of the figure, filling the grid left to right and top to bottom.
+    ///
+    /// The title is shown above the plot, unless it is empty.
+    pub fn plot<R>(
+        &mut self,
+        title: impl Into<WidgetText>,
+        build_fn: impl FnOnce(&mut PlotUi) -> R,
+    ) -> PlotResponse<R> {
+        self.plot_with(title, |plot| plot, build_fn)
+    }
+
+    /// Like [`Self::plot`], with extra settings for just this plot.
+    pub fn plot_with<R>(
+        &mut self,
+        title: impl Into<WidgetText>,
+        customize: impl FnOnce(Plot<'a>) -> Plot<'a>,
+        build_fn: impl FnOnce(&mut PlotUi) -> R,
+    ) -> PlotResponse<R> {
+        let figure = self.figure;
+        let row = self.index / figure.columns;
+        let column = self.index % figure.columns;
+
+        let mut plot = Plot::new(figure.id.with(self.index))
+            .width(self.plot_width)
+            .height(figure.plot_height)
+            .link_cursor(figure.id.with("cursor"), figure.link_cursor)
+            .color_group(figure.color_group());
+        if let Some(group) = figure.link_group(figure.share_x, "x", row, column) {
+            plot = plot.link_axis(group, [true, false]);
+        }
+        if let Some(group) = figure.link_group(figure.share_y, "y", row, column) {
+            plot = plot.link_axis(group, [false, true]);
+        }
+        if let Some(style) = &figure.style {
+            plot = style(plot);
+        }
+        let plot = customize(plot);
+
+        let title = title.into();
+        let hidden = self.hidden;
+        let response = self
+            .ui
+            .vertical(|ui| {
+                if !title.is_empty() {
+                    ui.label(title);
+                }
+                plot.show(ui, |plot_ui| {
+                    let inner = build_fn(plot_ui);
+                    plot_ui.items.retain(|item| !hidden.contains(item.name()));
+                    inner
+                })
+            })
+            .inner;
+
+        self.index += 1;
+        if self.index % figure.columns == 0 {
+            self.ui.end_row();
+        }
+        response
+    }
+}
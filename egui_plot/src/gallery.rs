@@ -0,0 +1,89 @@
+//! Building blocks for a gallery of plots, like the demo of this crate.
+//!
+//! Implement [`PlotExample`] for each plot configuration and show them as tabs with a [`Gallery`].
+//! The same examples can then be rendered in screenshot tests, e.g. with
+//! [`egui_kittest`](https://docs.rs/egui_kittest) like the tests of the demo do.
+//!
+//! ```
+//! use egui_plot::{gallery::{Gallery, PlotExample}, Line, Plot, PlotPoints};
+//!
+//! struct Sine;
+//!
+//! impl PlotExample for Sine {
+//!     fn name(&self) -> &'static str {
+//!         "Sine"
+//!     }
+//!
+//!     fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+//!         Plot::new("sine")
+//!             .show(ui, |plot_ui| {
+//!                 plot_ui.line(Line::new(PlotPoints::from_explicit_callback(f64::sin, .., 100)));
+//!             })
+//!             .response
+//!     }
+//! }
+//!
+//! # egui::__run_test_ui(|ui| {
+//! let mut gallery = Gallery::default();
+//! gallery.ui(ui, &mut [&mut Sine]);
+//! # });
+//! ```
+
+use egui::{Response, Ui};
+
+/// A plot configuration in a [`Gallery`], with its options.
+pub trait PlotExample {
+    /// The name of the example, shown on its tab and used as the name of its snapshot.
+    fn name(&self) -> &'static str;
+
+    /// Shows the example: its options, if any, and its plot.
+    fn ui(&mut self, ui: &mut Ui) -> Response;
+}
+
+/// Tabs to pick one of several [`PlotExample`]s, and the picked one below them.
+///
+/// The gallery only remembers which example is open, so the examples can be kept,
+/// and persisted, however the app likes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gallery {
+    open: Option<String>,
+}
+
+impl Gallery {
+    /// The name of the open example, if one was picked.
+    pub fn open(&self) -> Option<&str> {
+        self.open.as_deref()
+    }
+
+    /// Open the example with this name.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn set_open(&mut self, name: impl ToString) {
+        self.open = Some(name.to_string());
+    }
+
+    /// Shows a tab per example and the open example, which is the first one until another is picked.
+    ///
+    /// Returns the response of the open example, if there are any examples.
+    pub fn ui(&mut self, ui: &mut Ui, examples: &mut [&mut dyn PlotExample]) -> Option<Response> {
+        let index = examples
+            .iter()
+            .position(|example| self.open.as_deref() == Some(example.name()))
+            .unwrap_or(0);
+
+        ui.horizontal_wrapped(|ui| {
+            for (i, example) in examples.iter().enumerate() {
+                if ui.selectable_label(i == index, example.name()).clicked() {
+                    self.open = Some(example.name().to_owned());
+                }
+            }
+        });
+        ui.separator();
+
+        let index = examples
+            .iter()
+            .position(|example| self.open.as_deref() == Some(example.name()))
+            .unwrap_or(index);
+        examples.get_mut(index).map(|example| example.ui(ui))
+    }
+}
@@ -0,0 +1,67 @@
+use egui::{Pos2, Vec2};
+
+use crate::{PlotBounds, PlotTransform};
+
+/// The pans and zooms the user asked for this frame, before the plot applies them,
+/// see [`crate::Plot::input_filter`].
+///
+/// Interactions that are not allowed, like a drag along an axis with
+/// [`crate::Plot::allow_drag`] turned off, are already left out.
+#[derive(Clone, Copy, Debug)]
+pub struct PlotInput {
+    /// The transform of the plot, before the input is applied.
+    pub transform: PlotTransform,
+
+    /// How far the pointer was dragged to pan, in ui points.
+    pub drag_delta: Vec2,
+
+    /// How much to zoom along each axis, where `1.0` is no zoom and larger values zoom in.
+    pub zoom_factor: Vec2,
+
+    /// The screen position to zoom around, which is the pointer.
+    pub zoom_center: Pos2,
+
+    /// How far to pan by scrolling, in ui points.
+    pub scroll_delta: Vec2,
+}
+
+impl PlotInput {
+    pub(crate) fn new(transform: PlotTransform, zoom_center: Pos2) -> Self {
+        Self {
+            transform,
+            drag_delta: Vec2::ZERO,
+            zoom_factor: Vec2::splat(1.0),
+            zoom_center,
+            scroll_delta: Vec2::ZERO,
+        }
+    }
+
+    /// Does this input change the view at all?
+    pub fn is_empty(&self) -> bool {
+        self.drag_delta == Vec2::ZERO
+            && self.zoom_factor == Vec2::splat(1.0)
+            && self.scroll_delta == Vec2::ZERO
+    }
+
+    /// The bounds of the plot once this input is applied.
+    ///
+    /// Use this to check the input against limits, like keeping the y-axis above zero.
+    pub fn bounds_after(&self) -> PlotBounds {
+        let mut transform = self.transform;
+        self.apply(&mut transform);
+        *transform.bounds()
+    }
+
+    /// Pan and zoom the transform according to this input.
+    pub(crate) fn apply(&self, transform: &mut PlotTransform) {
+        if self.drag_delta != Vec2::ZERO {
+            transform.translate_bounds((-self.drag_delta.x as f64, -self.drag_delta.y as f64));
+        }
+        if self.zoom_factor != Vec2::splat(1.0) {
+            transform.zoom(self.zoom_factor, self.zoom_center);
+        }
+        if self.scroll_delta != Vec2::ZERO {
+            transform.translate_bounds((-self.scroll_delta.x as f64, -self.scroll_delta.y as f64));
+        }
+    }
+}
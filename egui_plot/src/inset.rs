@@ -0,0 +1,130 @@
+use egui::{vec2, Align2, Id, Rect, Shape, Ui, Vec2};
+
+use crate::{Plot, PlotPoint, PlotResponse, PlotUi};
+
+/// A small plot shown inside the frame of another plot, like a zoomed-in view of a detail.
+///
+/// The inset is shown on top of the other plot, and gets the pointer input within its frame,
+/// so scrolling over it zooms the inset rather than the plot below.
+/// A rectangle on the other plot marks the bounds of the inset.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Inset, Line, Plot, PlotPoints};
+///
+/// let sin = || PlotPoints::from_explicit_callback(f64::sin, 0.0..=20.0, 1000);
+/// let plot = Plot::new("signal").height(300.0).show(ui, |plot_ui| {
+///     plot_ui.line(Line::new(sin()));
+/// });
+/// Inset::new(Plot::new("detail").include_x(1.0).include_x(2.0)).show(ui, &plot, |plot_ui| {
+///     plot_ui.line(Line::new(sin()));
+/// });
+/// # });
+/// ```
+pub struct Inset<'a> {
+    plot: Plot<'a>,
+    anchor: Align2,
+    size: Vec2,
+    margin: f32,
+    indicator: bool,
+}
+
+impl<'a> Inset<'a> {
+    /// Show the given plot as an inset. Its size is set by the inset.
+    pub fn new(plot: Plot<'a>) -> Self {
+        Self {
+            plot,
+            anchor: Align2::RIGHT_TOP,
+            size: vec2(160.0, 120.0),
+            margin: 8.0,
+            indicator: true,
+        }
+    }
+
+    /// Where in the frame of the other plot to place the inset. Default: `Align2::RIGHT_TOP`.
+    #[inline]
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// The size of the inset, including its axes. Default: `160 x 120`.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// The space between the inset and the frame of the other plot. Default: `8.0`.
+    #[inline]
+    pub fn margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Mark the bounds of the inset with a rectangle on the other plot. Default: `true`.
+    #[inline]
+    pub fn indicator(mut self, indicator: bool) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Show the inset on top of the plot that was just shown with the given response.
+    pub fn show<R, P>(
+        self,
+        ui: &mut Ui,
+        parent: &PlotResponse<P>,
+        add_contents: impl FnOnce(&mut PlotUi) -> R + 'a,
+    ) -> PlotResponse<R> {
+        let frame = *parent.transform.frame();
+        let rect = self
+            .anchor
+            .align_size_within_rect(self.size, frame.shrink(self.margin));
+
+        // Reserve a place for the indicator, so that it is drawn below the inset.
+        let painter = ui.painter().with_clip_rect(frame);
+        let indicator = painter.add(Shape::Noop);
+
+        let mut inset_ui = ui.new_child(egui::UiBuilder::new().max_rect(rect));
+        let response = self
+            .plot
+            .width(rect.width())
+            .height(rect.height())
+            .show(&mut inset_ui, add_contents);
+
+        if self.indicator {
+            let bounds = response.transform.bounds();
+            let bounds_rect = parent.transform.rect_from_values(
+                &PlotPoint::new(bounds.min()[0], bounds.min()[1]),
+                &PlotPoint::new(bounds.max()[0], bounds.max()[1]),
+            );
+            painter.set(
+                indicator,
+                Shape::rect_stroke(
+                    bounds_rect,
+                    0.0,
+                    ui.visuals().widgets.noninteractive.fg_stroke,
+                    egui::StrokeKind::Inside,
+                ),
+            );
+        }
+
+        // Let the other plot leave the pointer input within the inset to it in the next frame.
+        ui.data_mut(|data| {
+            data.get_temp_mut_or_default::<Vec<Rect>>(insets_id(parent.response.id))
+                .push(response.response.rect);
+        });
+
+        response
+    }
+}
+
+fn insets_id(plot_response_id: Id) -> Id {
+    plot_response_id.with("insets")
+}
+
+/// The frames of the insets shown on top of the plot with the given response id in the last frame.
+pub(crate) fn take_inset_rects(ui: &Ui, plot_response_id: Id) -> Vec<Rect> {
+    ui.data_mut(|data| data.remove_temp(insets_id(plot_response_id)))
+        .unwrap_or_default()
+}
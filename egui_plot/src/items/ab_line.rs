@@ -0,0 +1,454 @@
+use egui::{epaint::TextShape, vec2, Align2, Color32, Id, Pos2, Shape, Stroke, TextStyle, Ui};
+
+use super::{BlendMode, ClosestElem, DecorationItem, LineStyle, PlotConfig, PlotLayer, PlotPoint};
+use crate::{AxisScale, Cursor, LabelFormatter, LegendIcon, PlotBounds, PlotTransform};
+
+/// The number of points of the line on axes that aren't linear, where it is curved.
+const CURVED_POINTS: usize = 64;
+
+/// A straight line through a point, extended infinitely in one or both directions.
+///
+/// Useful for reference lines like `y = x`, regression overlays, or support and resistance rays.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{AbLine, Plot};
+///
+/// Plot::new("lines").show(ui, |plot_ui| {
+///     plot_ui.ab_line(AbLine::new([0.0, 0.0], 1.0).label("y = x"));
+///     plot_ui.ab_line(AbLine::through([1.0, 2.0], [3.0, 1.0]).ray(true));
+/// });
+/// # });
+/// ```
+pub struct AbLine {
+    point: PlotPoint,
+    direction: [f64; 2],
+    ray: bool,
+    pub(crate) stroke: Stroke,
+    style: LineStyle,
+    label: Option<String>,
+    pub(crate) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    show_in_legend: bool,
+    include_in_auto_bounds: bool,
+    id: Option<Id>,
+}
+
+impl AbLine {
+    /// The line through the point with the given slope.
+    pub fn new(point: impl Into<PlotPoint>, slope: f64) -> Self {
+        Self::with_direction(point.into(), [1.0, slope])
+    }
+
+    /// The line through both points.
+    pub fn through(from: impl Into<PlotPoint>, to: impl Into<PlotPoint>) -> Self {
+        let (from, to) = (from.into(), to.into());
+        Self::with_direction(from, [to.x - from.x, to.y - from.y])
+    }
+
+    fn with_direction(point: PlotPoint, direction: [f64; 2]) -> Self {
+        Self {
+            point,
+            direction,
+            ray: false,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            style: LineStyle::Solid,
+            label: None,
+            name: String::new(),
+            highlight: false,
+            allow_hover: false,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            show_in_legend: false,
+            include_in_auto_bounds: false,
+            id: None,
+        }
+    }
+
+    /// Only extend the line from its first point, away from the start of the x-axis for
+    /// [`Self::new`] and towards the second point for [`Self::through`]. Default: `false`.
+    #[inline]
+    pub fn ray(mut self, ray: bool) -> Self {
+        self.ray = ray;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the line's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Text drawn along the line, at its right end within the view.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Name of this line.
+    ///
+    /// This name will show up in the plot legend if [`Self::show_in_legend`] is set,
+    /// and in the hover label.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this line in the plot by scaling up the line.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allow hovering this item, see [`DecorationItem::allow_hover`]. Default: `false`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`DecorationItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`DecorationItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`DecorationItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`DecorationItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`DecorationItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Show this item in the legend, see [`DecorationItem::show_in_legend`]. Default: `false`.
+    #[inline]
+    pub fn show_in_legend(mut self, show: bool) -> Self {
+        self.show_in_legend = show;
+        self
+    }
+
+    /// Widen the automatic bounds to include this item, see [`DecorationItem::include_in_auto_bounds`]. Default: `false`.
+    #[inline]
+    pub fn include_in_auto_bounds(mut self, include: bool) -> Self {
+        self.include_in_auto_bounds = include;
+        self
+    }
+
+    /// Set the line's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The visible part of the line on the screen, ordered from left to right.
+    fn screen_points(&self, transform: &PlotTransform) -> Vec<Pos2> {
+        let Some((start, end)) =
+            clip_line(self.point, self.direction, self.ray, transform.bounds())
+        else {
+            return Vec::new();
+        };
+
+        let linear = transform.scales() == [AxisScale::Linear; 2];
+        let count = if linear { 2 } else { CURVED_POINTS };
+        let mut points: Vec<_> = (0..count)
+            .map(|i| {
+                let t = i as f64 / (count - 1) as f64;
+                let point = PlotPoint::new(
+                    start.x + t * (end.x - start.x),
+                    start.y + t * (end.y - start.y),
+                );
+                transform.position_from_point(&point)
+            })
+            .collect();
+        if points[0].x > points[count - 1].x {
+            points.reverse();
+        }
+        points
+    }
+}
+
+/// The part of the line through `point` along `direction` within the bounds,
+/// only ahead of the point for a ray.
+fn clip_line(
+    point: PlotPoint,
+    direction: [f64; 2],
+    ray: bool,
+    bounds: &PlotBounds,
+) -> Option<(PlotPoint, PlotPoint)> {
+    if !point.is_finite() || !direction.iter().all(|d| d.is_finite()) || direction == [0.0; 2] {
+        return None;
+    }
+
+    let (mut t_min, mut t_max) = (if ray { 0.0 } else { f64::NEG_INFINITY }, f64::INFINITY);
+    for (axis, (start, d)) in [point.x, point.y].into_iter().zip(direction).enumerate() {
+        let (min, max) = (bounds.min()[axis], bounds.max()[axis]);
+        if d == 0.0 {
+            if start < min || max < start {
+                return None;
+            }
+        } else {
+            let (t1, t2) = ((min - start) / d, (max - start) / d);
+            t_min = t_min.max(t1.min(t2));
+            t_max = t_max.min(t1.max(t2));
+        }
+    }
+
+    (t_min <= t_max && t_min.is_finite() && t_max.is_finite()).then(|| {
+        let at = |t: f64| PlotPoint::new(point.x + t * direction[0], point.y + t * direction[1]);
+        (at(t_min), at(t_max))
+    })
+}
+
+impl DecorationItem for AbLine {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let points = self.screen_points(transform);
+        let (Some(first), Some(last)) = (points.first().copied(), points.last().copied()) else {
+            return;
+        };
+        let before_last = points[points.len().saturating_sub(2)];
+        self.style
+            .style_line(points, self.stroke, self.highlight, shapes);
+
+        if let Some(label) = &self.label {
+            let dir = (last - before_last).normalized();
+            if !dir.is_finite() || first == last {
+                return;
+            }
+            let font_id = TextStyle::Small.resolve(ui.style());
+            let galley = ui.fonts(|f| f.layout_no_wrap(label.clone(), font_id, self.stroke.color));
+
+            // Above the line and ending just before the edge, rotated around its top left corner.
+            let up = vec2(dir.y, -dir.x);
+            let pos = last - dir * (galley.size().x + 4.0) + up * (galley.size().y + 2.0);
+            let angle = dir.y.atan2(dir.x);
+            shapes.push(
+                TextShape::new(pos, galley, self.stroke.color)
+                    .with_angle(angle)
+                    .into(),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn show_in_legend(&self) -> bool {
+        self.show_in_legend
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            line: Some((self.stroke, self.style)),
+            ..Default::default()
+        }
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.point);
+        bounds
+    }
+
+    fn include_in_auto_bounds(&self) -> bool {
+        self.include_in_auto_bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        !self.point.is_finite() || !self.direction.iter().all(|d| d.is_finite())
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.screen_points(transform)
+            .windows(2)
+            .map(|w| {
+                let (a, b) = (w[0], w[1]);
+                let t = ((point - a).dot(b - a) / (b - a).length_sq()).clamp(0.0, 1.0);
+                a.lerp(b, if t.is_finite() { t } else { 0.0 })
+                    .distance_sq(point)
+            })
+            .min_by(|a, b| a.total_cmp(b))
+            .map(|dist_sq| ClosestElem { index: 0, dist_sq })
+    }
+
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+        let Some(pointer) = plot.ui.input(|i| i.pointer.hover_pos()) else {
+            return;
+        };
+
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        let [dx, dy] = self.direction;
+        if dx == 0.0 {
+            text.push_str(&format!("x = {}", self.point.x));
+        } else {
+            let slope = dy / dx;
+            let intercept = self.point.y - slope * self.point.x;
+            text.push_str(&format!("y = {slope:.4} x + {intercept:.4}"));
+        }
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pointer + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+#[test]
+fn test_clip_line() {
+    let bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]);
+    let origin = PlotPoint::new(0.0, 0.0);
+
+    assert_eq!(
+        clip_line(origin, [1.0, 1.0], false, &bounds),
+        Some((origin, PlotPoint::new(10.0, 10.0)))
+    );
+
+    // A ray pointing away from the bounds is not visible, the full line is.
+    let outside = PlotPoint::new(-5.0, 5.0);
+    assert!(clip_line(outside, [-1.0, 0.0], true, &bounds).is_none());
+    assert_eq!(
+        clip_line(outside, [-1.0, 0.0], false, &bounds),
+        Some((PlotPoint::new(10.0, 5.0), PlotPoint::new(0.0, 5.0)))
+    );
+
+    // A ray starts at its point.
+    assert_eq!(
+        clip_line(PlotPoint::new(5.0, 5.0), [0.0, 1.0], true, &bounds),
+        Some((PlotPoint::new(5.0, 5.0), PlotPoint::new(5.0, 10.0)))
+    );
+
+    assert!(clip_line(PlotPoint::new(0.0, 20.0), [1.0, 0.0], false, &bounds).is_none());
+}
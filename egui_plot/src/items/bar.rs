@@ -1,8 +1,9 @@
 use egui::emath::NumExt;
-use egui::epaint::{Color32, RectShape, Rounding, Shape, Stroke};
+use egui::epaint::{tessellator::path, Color32, Mesh, RectShape, Rounding, Shape, Stroke};
+use egui::{vec2, Rect};
 
 use super::{add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement};
-use crate::{BarChart, Cursor, PlotPoint, PlotTransform};
+use crate::{Axis, BarChart, Cursor, PlotPoint, PlotTransform};
 
 /// One bar in a [`BarChart`]. Potentially floating, allowing stacked bar charts.
 /// Width can be changed to allow variable-width histograms.
@@ -26,6 +27,10 @@ pub struct Bar {
     /// Thickness of the bar
     pub bar_width: f64,
 
+    /// Thickness of the bar in ui points, which stays the same when zooming.
+    /// Overrides [`Self::bar_width`] if set.
+    pub width_px: Option<f32>,
+
     /// Line width and color
     pub stroke: Stroke,
 
@@ -48,6 +53,7 @@ impl Bar {
             name: Default::default(),
             base_offset: None,
             bar_width: 0.5,
+            width_px: None,
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             fill: Color32::TRANSPARENT,
         }
@@ -91,6 +97,24 @@ impl Bar {
         self
     }
 
+    /// Set the bar width in ui points, so that it doesn't change when zooming.
+    ///
+    /// Useful for events and impulses, which would otherwise become hairlines when zooming out.
+    #[inline]
+    pub fn width_px(mut self, width: f32) -> Self {
+        self.width_px = Some(width);
+        self
+    }
+
+    /// The thickness of the bar in plot units, which is zero if it is given in ui points.
+    fn plot_width(&self) -> f64 {
+        if self.width_px.is_some() {
+            0.0
+        } else {
+            self.bar_width
+        }
+    }
+
     /// Set orientation of the element as vertical. Argument axis is X.
     #[inline]
     pub fn vertical(mut self) -> Self {
@@ -123,6 +147,7 @@ impl Bar {
 
     pub(super) fn add_shapes(
         &self,
+        parent: &BarChart,
         transform: &PlotTransform,
         highlighted: bool,
         shapes: &mut Vec<Shape>,
@@ -133,16 +158,127 @@ impl Bar {
             (self.stroke, self.fill)
         };
 
-        let rect = transform.rect_from_values(&self.bounds_min(), &self.bounds_max());
-        let rect = Shape::Rect(RectShape::new(
+        let rect = self.screen_rect(transform);
+
+        // The tip is the end of the bar away from its base, wherever the axes put it on the screen.
+        let base = self.base_offset.unwrap_or(0.0);
+        let tip = transform.position_from_point(&self.point_at(self.argument, base + self.value));
+        let (tip_side, base_side) = match self.orientation {
+            Orientation::Vertical if tip.y < rect.center().y => (rect.top(), rect.bottom()),
+            Orientation::Vertical => (rect.bottom(), rect.top()),
+            Orientation::Horizontal if tip.x < rect.center().x => (rect.left(), rect.right()),
+            Orientation::Horizontal => (rect.right(), rect.left()),
+        };
+
+        let r = Rounding::from(parent.corner_radius);
+        let rounding = match self.orientation {
+            Orientation::Vertical if tip_side == rect.top() => Rounding {
+                nw: r.nw,
+                ne: r.ne,
+                ..Rounding::ZERO
+            },
+            Orientation::Vertical => Rounding {
+                sw: r.sw,
+                se: r.se,
+                ..Rounding::ZERO
+            },
+            Orientation::Horizontal if tip_side == rect.left() => Rounding {
+                nw: r.nw,
+                sw: r.sw,
+                ..Rounding::ZERO
+            },
+            Orientation::Horizontal => Rounding {
+                ne: r.ne,
+                se: r.se,
+                ..Rounding::ZERO
+            },
+        };
+
+        let Some(tip_fill) = parent.gradient else {
+            shapes.push(Shape::Rect(RectShape::new(
+                rect,
+                rounding,
+                fill,
+                stroke,
+                egui::StrokeKind::Inside,
+            )));
+            return;
+        };
+        let tip_fill = if highlighted {
+            highlighted_color(stroke, tip_fill).1
+        } else {
+            tip_fill
+        };
+
+        // The fill blends from the base to the tip, so it is tessellated as a fan of triangles.
+        let mut outline = Vec::new();
+        path::rounded_rectangle(&mut outline, rect, rounding.into());
+        let color_at = |pos: egui::Pos2| {
+            let along = match self.orientation {
+                Orientation::Vertical => pos.y,
+                Orientation::Horizontal => pos.x,
+            };
+            let t = ((along - base_side) / (tip_side - base_side)).clamp(0.0, 1.0);
+            let t = if t.is_finite() { t } else { 1.0 };
+            fill.lerp_to_gamma(tip_fill, t)
+        };
+        let mut mesh = Mesh::default();
+        mesh.colored_vertex(rect.center(), color_at(rect.center()));
+        for pos in &outline {
+            mesh.colored_vertex(*pos, color_at(*pos));
+        }
+        let n = outline.len() as u32;
+        for i in 0..n {
+            mesh.add_triangle(0, 1 + i, 1 + (i + 1) % n);
+        }
+        shapes.push(Shape::mesh(mesh));
+        shapes.push(Shape::Rect(RectShape::new(
             rect,
-            Rounding::ZERO,
-            fill,
+            rounding,
+            Color32::TRANSPARENT,
             stroke,
             egui::StrokeKind::Inside,
-        ));
+        )));
+    }
+
+    /// How many decimals of the value are worth showing at the current zoom level.
+    pub(super) fn value_decimals(&self, transform: &PlotTransform) -> usize {
+        let scale = transform.dvalue_dpos();
+        let scale = match self.orientation {
+            Orientation::Horizontal => scale[0],
+            Orientation::Vertical => scale[1],
+        };
+        ((-scale.abs().log10()).ceil().at_least(0.0) as usize).at_most(6)
+    }
 
-        shapes.push(rect);
+    /// The hover label for [`BarChart::hover_aggregate`]:
+    /// the values of all bars at the given argument, and their total.
+    pub(super) fn aggregate_text(&self, plot: &PlotConfig<'_>, argument: f64) -> String {
+        let decimals = self.value_decimals(plot.transform);
+        let mut text = String::new();
+        let mut total = 0.0;
+        for item in plot.items {
+            for value in item.bar_values_at(argument) {
+                text += &format!(
+                    "{}: {}\n",
+                    item.name(),
+                    self.format_value(plot, value, decimals)
+                );
+                total += value;
+            }
+        }
+        text += &format!("Total: {}", self.format_value(plot, total, decimals));
+        text
+    }
+
+    /// A value of this bar, with the [`crate::Plot::value_formatter`] of its value axis.
+    fn format_value(&self, plot: &PlotConfig<'_>, value: f64, decimals: usize) -> String {
+        let axis = match self.orientation {
+            Orientation::Horizontal => Axis::X,
+            Orientation::Vertical => Axis::Y,
+        };
+        plot.format_value(axis, value)
+            .unwrap_or_else(|| crate::format_number(value, decimals))
     }
 
     pub(super) fn add_rulers_and_text(
@@ -167,11 +303,20 @@ impl RectElement for Bar {
     }
 
     fn bounds_min(&self) -> PlotPoint {
-        self.point_at(self.argument - self.bar_width / 2.0, self.lower())
+        self.point_at(self.argument - self.plot_width() / 2.0, self.lower())
     }
 
     fn bounds_max(&self) -> PlotPoint {
-        self.point_at(self.argument + self.bar_width / 2.0, self.upper())
+        self.point_at(self.argument + self.plot_width() / 2.0, self.upper())
+    }
+
+    fn screen_rect(&self, transform: &PlotTransform) -> Rect {
+        let rect = transform.rect_from_values(&self.bounds_min(), &self.bounds_max());
+        match (self.width_px, self.orientation) {
+            (None, _) => rect,
+            (Some(width), Orientation::Vertical) => rect.expand2(vec2(width / 2.0, 0.0)),
+            (Some(width), Orientation::Horizontal) => rect.expand2(vec2(0.0, width / 2.0)),
+        }
     }
 
     fn values_with_ruler(&self) -> Vec<PlotPoint> {
@@ -191,13 +336,7 @@ impl RectElement for Bar {
         self.orientation
     }
 
-    fn default_values_format(&self, transform: &PlotTransform) -> String {
-        let scale = transform.dvalue_dpos();
-        let scale = match self.orientation {
-            Orientation::Horizontal => scale[0],
-            Orientation::Vertical => scale[1],
-        };
-        let decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
-        crate::format_number(self.value, decimals)
+    fn default_values_format(&self, plot: &PlotConfig<'_>) -> String {
+        self.format_value(plot, self.value, self.value_decimals(plot.transform))
     }
 }
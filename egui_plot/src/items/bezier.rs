@@ -0,0 +1,466 @@
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, Pos2, Shape, Stroke, Ui};
+
+use super::{
+    rulers_at_value, BlendMode, ClosestElem, LineStyle, PlotConfig, PlotGeometry, PlotItem,
+    PlotLayer, PlotPoint, PlotPoints,
+};
+use crate::{Cursor, LabelFormatter, LegendIcon, PlotBounds, PlotTransform};
+
+/// How far a flattened curve may be from the real one, in ui points.
+const FLATNESS_TOLERANCE: f32 = 0.2;
+
+/// How often a curve segment is split in half at most while flattening it.
+const MAX_SUBDIVISIONS: u32 = 12;
+
+/// The four control points of one cubic Bézier segment.
+type Segment = [PlotPoint; 4];
+
+/// How the points of a [`CubicBezier`] define its segments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CurveKind {
+    /// Start, two control points, end, two control points, end, …
+    Bezier,
+
+    /// A Catmull-Rom spline passing through all points.
+    Spline,
+}
+
+/// A smooth curve made of cubic Bézier segments.
+///
+/// The curve is flattened into a line on the screen, with more segments the further
+/// it is zoomed in, so it stays smooth at any zoom level.
+/// Hovering shows the point on the curve closest to the pointer.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{CubicBezier, Plot};
+///
+/// Plot::new("curves").show(ui, |plot_ui| {
+///     plot_ui.cubic_bezier(
+///         CubicBezier::new(vec![[0.0, 0.0], [1.0, 2.0], [2.0, -2.0], [3.0, 0.0]]).name("bezier"),
+///     );
+///     plot_ui.cubic_bezier(
+///         CubicBezier::spline(vec![[0.0, 1.0], [1.0, 3.0], [2.0, 2.0], [3.0, 4.0]]).name("spline"),
+///     );
+/// });
+/// # });
+/// ```
+pub struct CubicBezier {
+    points: PlotPoints,
+    kind: CurveKind,
+    pub(crate) stroke: Stroke,
+    style: LineStyle,
+    pub(crate) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    id: Option<Id>,
+}
+
+impl CubicBezier {
+    /// A curve from the control points of its segments: start, control, control, end,
+    /// then two control points and an end for each following segment.
+    ///
+    /// Points left over after the last complete segment are ignored.
+    pub fn new(control_points: impl Into<PlotPoints>) -> Self {
+        Self::with_kind(control_points.into(), CurveKind::Bezier)
+    }
+
+    /// A smooth curve passing through all the given points, as a Catmull-Rom spline.
+    pub fn spline(points: impl Into<PlotPoints>) -> Self {
+        Self::with_kind(points.into(), CurveKind::Spline)
+    }
+
+    fn with_kind(points: PlotPoints, kind: CurveKind) -> Self {
+        Self {
+            points,
+            kind,
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            style: LineStyle::Solid,
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            id: None,
+        }
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Set the curve's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this curve.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this curve in the plot by scaling up the line.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Set the curve's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The Bézier segments of the curve, in plot coordinates.
+    fn segments(&self) -> Vec<Segment> {
+        let points = self.points.points();
+        match self.kind {
+            CurveKind::Bezier => points
+                .windows(4)
+                .step_by(3)
+                .map(|w| [w[0], w[1], w[2], w[3]])
+                .collect(),
+            CurveKind::Spline => (0..points.len().saturating_sub(1))
+                .map(|i| {
+                    // The tangent at each point is parallel to the line between its neighbors.
+                    let p0 = points[i.saturating_sub(1)];
+                    let [p1, p2] = [points[i], points[i + 1]];
+                    let p3 = points[(i + 2).min(points.len() - 1)];
+                    [
+                        p1,
+                        PlotPoint::new(p1.x + (p2.x - p0.x) / 6.0, p1.y + (p2.y - p0.y) / 6.0),
+                        PlotPoint::new(p2.x - (p3.x - p1.x) / 6.0, p2.y - (p3.y - p1.y) / 6.0),
+                        p2,
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    /// The curve as a line on the screen, split into as many pieces as it takes to look smooth.
+    fn flattened(&self, transform: &PlotTransform) -> Vec<Pos2> {
+        let mut line = Vec::new();
+        for segment in self.segments() {
+            if !segment.iter().all(PlotPoint::is_finite) {
+                continue;
+            }
+            if line.is_empty() {
+                line.push(transform.position_from_point(&segment[0]));
+            }
+            flatten(segment, transform, MAX_SUBDIVISIONS, &mut line);
+        }
+        line
+    }
+}
+
+/// Adds the end points of the pieces of the segment to the line, splitting the segment
+/// in half until its control points are within the tolerance of the line between its ends.
+fn flatten(segment: Segment, transform: &PlotTransform, depth: u32, line: &mut Vec<Pos2>) {
+    let [p0, p1, p2, p3] = segment.map(|point| transform.position_from_point(&point));
+    let chord = p3 - p0;
+    let distance = |p: Pos2| {
+        if chord.length_sq() > 0.0 {
+            (chord.x * (p.y - p0.y) - chord.y * (p.x - p0.x)).abs() / chord.length()
+        } else {
+            p.distance(p0)
+        }
+    };
+    if depth == 0 || distance(p1).max(distance(p2)) <= FLATNESS_TOLERANCE {
+        line.push(p3);
+        return;
+    }
+    let (first, second) = split(segment);
+    flatten(first, transform, depth - 1, line);
+    flatten(second, transform, depth - 1, line);
+}
+
+/// Splits the segment in half with de Casteljau's algorithm.
+fn split([p0, p1, p2, p3]: Segment) -> (Segment, Segment) {
+    let mid = |a: PlotPoint, b: PlotPoint| PlotPoint::new((a.x + b.x) / 2.0, (a.y + b.y) / 2.0);
+    let (a, b, c) = (mid(p0, p1), mid(p1, p2), mid(p2, p3));
+    let (d, e) = (mid(a, b), mid(b, c));
+    let center = mid(d, e);
+    ([p0, a, d, center], [center, e, c, p3])
+}
+
+/// The range of one coordinate of a segment, from its ends and the extremes in between.
+fn segment_range([p0, p1, p2, p3]: [f64; 4]) -> (f64, f64) {
+    let value_at = |t: f64| {
+        let s = 1.0 - t;
+        s * s * s * p0 + 3.0 * s * s * t * p1 + 3.0 * s * t * t * p2 + t * t * t * p3
+    };
+
+    // The derivative is the quadratic a·t² + b·t + c.
+    let a = 3.0 * (-p0 + 3.0 * p1 - 3.0 * p2 + p3);
+    let b = 6.0 * (p0 - 2.0 * p1 + p2);
+    let c = 3.0 * (p1 - p0);
+    let roots = if a.abs() < 1e-12 {
+        [-c / b, f64::NAN]
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        let sqrt = discriminant.sqrt();
+        [(-b + sqrt) / (2.0 * a), (-b - sqrt) / (2.0 * a)]
+    };
+
+    roots
+        .into_iter()
+        .filter(|t| (0.0..=1.0).contains(t))
+        .map(value_at)
+        .fold((p0.min(p3), p0.max(p3)), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+impl PlotItem for CubicBezier {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        self.style.style_line(
+            self.flattened(transform),
+            self.stroke,
+            self.highlight,
+            shapes,
+        );
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.points.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            line: Some((self.stroke, self.style)),
+            ..Default::default()
+        }
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for segment in self.segments() {
+            if !segment.iter().all(PlotPoint::is_finite) {
+                continue;
+            }
+            let (min_x, max_x) = segment_range(segment.map(|point| point.x));
+            let (min_y, max_y) = segment_range(segment.map(|point| point.y));
+            bounds.merge(&PlotBounds::from_min_max([min_x, min_y], [max_x, max_y]));
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        self.points.points().iter().any(|point| !point.is_finite())
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        closest_on_line(&self.flattened(transform), point).map(|closest| ClosestElem {
+            index: 0,
+            dist_sq: closest.distance_sq(point),
+        })
+    }
+
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        // Project the pointer onto the curve, since the curve has no points of its own to snap to.
+        let Some(pointer) = plot.ui.input(|i| i.pointer.hover_pos()) else {
+            return;
+        };
+        let Some(position) = closest_on_line(&self.flattened(plot.transform), pointer) else {
+            return;
+        };
+        let value = plot.transform.value_from_position(position);
+
+        let line_color = if plot.ui.visuals().dark_mode {
+            Color32::from_gray(100).additive()
+        } else {
+            Color32::from_black_alpha(180)
+        };
+        shapes.push(Shape::circle_filled(position, 3.0, line_color));
+
+        rulers_at_value(
+            position,
+            value,
+            None,
+            &self.name,
+            plot,
+            shapes,
+            cursors,
+            label_formatter,
+        );
+    }
+}
+
+/// The point on the line closest to the given point.
+fn closest_on_line(line: &[Pos2], point: Pos2) -> Option<Pos2> {
+    line.windows(2)
+        .map(|w| {
+            let (a, b) = (w[0], w[1]);
+            let t = ((point - a).dot(b - a) / (b - a).length_sq()).clamp(0.0, 1.0);
+            a.lerp(b, if t.is_finite() { t } else { 0.0 })
+        })
+        .chain(line.first().copied().filter(|_| line.len() == 1))
+        .min_by(|a, b| a.distance_sq(point).total_cmp(&b.distance_sq(point)))
+}
+
+#[test]
+fn test_cubic_bezier_bounds() {
+    // Bulges up to y = 0.75 between its ends, beyond neither control point.
+    let curve = CubicBezier::new(vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]);
+    let bounds = curve.bounds();
+    assert_eq!(bounds.min(), [0.0, 0.0]);
+    assert_eq!(bounds.max(), [1.0, 0.75]);
+
+    // A spline passes through all of its points.
+    let spline = CubicBezier::spline(vec![[0.0, 0.0], [1.0, 2.0], [2.0, 1.0]]);
+    let segments = spline.segments();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0][0], PlotPoint::new(0.0, 0.0));
+    assert_eq!(segments[0][3], PlotPoint::new(1.0, 2.0));
+    assert_eq!(segments[1][3], PlotPoint::new(2.0, 1.0));
+}
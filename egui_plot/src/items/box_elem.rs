@@ -1,7 +1,7 @@
 use egui::emath::NumExt as _;
 use egui::epaint::{Color32, RectShape, Rounding, Shape, Stroke};
 
-use crate::{BoxPlot, Cursor, PlotPoint, PlotTransform};
+use crate::{Axis, BoxPlot, Cursor, PlotPoint, PlotTransform};
 
 use super::{add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement};
 
@@ -258,6 +258,28 @@ impl RectElement for BoxElem {
         self.point_at(argument, value)
     }
 
+    fn is_finite(&self) -> bool {
+        let BoxSpread {
+            lower_whisker,
+            quartile1,
+            median,
+            quartile3,
+            upper_whisker,
+        } = self.spread;
+        [
+            self.argument,
+            self.box_width,
+            self.whisker_width,
+            lower_whisker,
+            quartile1,
+            median,
+            quartile3,
+            upper_whisker,
+        ]
+        .iter()
+        .all(|value| value.is_finite())
+    }
+
     fn values_with_ruler(&self) -> Vec<PlotPoint> {
         let median = self.point_at(self.argument, self.spread.median);
         let q1 = self.point_at(self.argument, self.spread.quartile1);
@@ -276,27 +298,26 @@ impl RectElement for BoxElem {
         self.point_at(self.argument, self.spread.upper_whisker)
     }
 
-    fn default_values_format(&self, transform: &PlotTransform) -> String {
-        let scale = transform.dvalue_dpos();
-        let scale = match self.orientation {
-            Orientation::Horizontal => scale[0],
-            Orientation::Vertical => scale[1],
+    fn default_values_format(&self, plot: &PlotConfig<'_>) -> String {
+        let scale = plot.transform.dvalue_dpos();
+        let (scale, axis) = match self.orientation {
+            Orientation::Horizontal => (scale[0], Axis::X),
+            Orientation::Vertical => (scale[1], Axis::Y),
         };
         let y_decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize)
             .at_most(6)
             .at_least(1);
+        let format = |value: f64| {
+            plot.format_value(axis, value)
+                .unwrap_or_else(|| format!("{value:.y_decimals$}"))
+        };
         format!(
-            "Max = {max:.decimals$}\
-             \nQuartile 3 = {q3:.decimals$}\
-             \nMedian = {med:.decimals$}\
-             \nQuartile 1 = {q1:.decimals$}\
-             \nMin = {min:.decimals$}",
-            max = self.spread.upper_whisker,
-            q3 = self.spread.quartile3,
-            med = self.spread.median,
-            q1 = self.spread.quartile1,
-            min = self.spread.lower_whisker,
-            decimals = y_decimals
+            "Max = {}\nQuartile 3 = {}\nMedian = {}\nQuartile 1 = {}\nMin = {}",
+            format(self.spread.upper_whisker),
+            format(self.spread.quartile3),
+            format(self.spread.median),
+            format(self.spread.quartile1),
+            format(self.spread.lower_whisker),
         )
     }
 }
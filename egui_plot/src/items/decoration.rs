@@ -0,0 +1,231 @@
+use std::{ops::RangeInclusive, sync::Arc};
+
+use egui::{Color32, Galley, Id, Pos2, Shape, Ui};
+
+use super::{BlendMode, ClosestElem, PlotConfig, PlotGeometry, PlotItem, PlotLayer};
+use crate::{Cursor, LabelFormatter, LegendIcon, PlotBounds, PlotPoint, PlotTransform};
+
+/// Something drawn in a plot to annotate the data rather than to show it,
+/// like a shaded span, a reference line, a text or an image.
+///
+/// Implement this instead of [`PlotItem`] for such items, and add them with [`crate::PlotUi::decoration`].
+/// The built-in annotations ([`crate::HLine`], [`crate::VLine`], [`crate::AbLine`], [`crate::Region`],
+/// [`crate::RecurringLines`], [`crate::Text`] and [`crate::PlotImage`]) are decorations too.
+///
+/// Unlike the data items, a decoration is by default left out of the legend, can't be hovered,
+/// and doesn't widen the automatic bounds. Each of these can be opted into with
+/// [`Self::show_in_legend`], [`Self::allow_hover`] and [`Self::include_in_auto_bounds`].
+pub trait DecorationItem {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>);
+
+    /// The name of the decoration, shown in the legend if [`Self::show_in_legend`] is `true`,
+    /// and in its hover label.
+    fn name(&self) -> &str {
+        ""
+    }
+
+    /// The color of the legend entry.
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    /// Show the decoration in the legend, so the user can hide it.
+    ///
+    /// Only decorations with a [`Self::name`] get an entry.
+    fn show_in_legend(&self) -> bool {
+        false
+    }
+
+    /// The collapsible group of the legend to put the entry in, see [`PlotItem::legend_group`].
+    fn legend_group(&self) -> Option<&str> {
+        None
+    }
+
+    /// The sample of the decoration shown as its icon in the legend.
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon::default()
+    }
+
+    /// Highlight the decoration, because its legend entry is hovered.
+    fn highlight(&mut self) {}
+
+    fn highlighted(&self) -> bool {
+        false
+    }
+
+    /// Is the decoration highlighted when its legend entry is hovered?
+    fn allow_highlight(&self) -> bool {
+        true
+    }
+
+    /// Can the user hover the decoration, showing its hover label?
+    ///
+    /// Decorations that can be hovered implement [`Self::find_closest`] and [`Self::on_hover`].
+    fn allow_hover(&self) -> bool {
+        false
+    }
+
+    /// The priority of the decoration when hovering, see [`PlotItem::hover_priority`].
+    fn hover_priority(&self) -> i32 {
+        0
+    }
+
+    /// How close the pointer is to the decoration, if it can be hovered there.
+    fn find_closest(&self, _point: Pos2, _transform: &PlotTransform) -> Option<ClosestElem> {
+        None
+    }
+
+    /// Shows the hover label of the element found by [`Self::find_closest`].
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        _shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        _plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+    }
+
+    /// The area covered by the decoration, used if [`Self::include_in_auto_bounds`] is `true`.
+    fn bounds(&self) -> PlotBounds {
+        PlotBounds::NOTHING
+    }
+
+    /// Widen the automatic bounds of the plot to include [`Self::bounds`].
+    fn include_in_auto_bounds(&self) -> bool {
+        false
+    }
+
+    /// Does the decoration have a NaN or infinite position, see [`PlotItem::has_non_finite_values`]?
+    fn has_non_finite_values(&self) -> bool {
+        false
+    }
+
+    /// A label to be placed by the plot, see [`PlotItem::auto_placed_label`].
+    fn auto_placed_label(&self, _ui: &Ui) -> Option<(PlotPoint, Arc<Galley>, Color32)> {
+        None
+    }
+
+    /// The layer to draw the decoration on.
+    fn layer(&self) -> PlotLayer {
+        PlotLayer::Normal
+    }
+
+    /// Clip the decoration to the plot frame.
+    fn clip(&self) -> bool {
+        true
+    }
+
+    /// How the colors of the decoration are blended with what is drawn below it.
+    fn blend_mode(&self) -> BlendMode {
+        BlendMode::Normal
+    }
+
+    fn id(&self) -> Option<Id> {
+        None
+    }
+}
+
+/// Adds a [`DecorationItem`] to a plot as a [`PlotItem`],
+/// leaving out what the decoration didn't opt into.
+pub(crate) struct Decoration<T>(pub T);
+
+impl<T: DecorationItem> PlotItem for Decoration<T> {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        self.0.shapes(ui, transform, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        if self.0.show_in_legend() {
+            self.0.name()
+        } else {
+            ""
+        }
+    }
+
+    fn color(&self) -> Color32 {
+        self.0.color()
+    }
+
+    fn highlight(&mut self) {
+        self.0.highlight();
+    }
+
+    fn highlighted(&self) -> bool {
+        self.0.highlighted()
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.0.allow_hover()
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.0.show_in_legend() && self.0.allow_highlight()
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.0.hover_priority()
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.0.legend_group()
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        self.0.legend_icon()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.0.layer()
+    }
+
+    fn clip(&self) -> bool {
+        self.0.clip()
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.0.blend_mode()
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        if self.0.include_in_auto_bounds() {
+            self.0.bounds()
+        } else {
+            PlotBounds::NOTHING
+        }
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.0.id()
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        self.0.has_non_finite_values()
+    }
+
+    fn auto_placed_label(&self, ui: &Ui) -> Option<(PlotPoint, Arc<Galley>, Color32)> {
+        self.0.auto_placed_label(ui)
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.0.find_closest(point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        self.0
+            .on_hover(elem, shapes, cursors, plot, label_formatter);
+    }
+}
@@ -0,0 +1,398 @@
+use std::ops::RangeInclusive;
+
+use egui::{vec2, Align2, Color32, Id, Pos2, Shape, Stroke, TextStyle, Ui};
+
+use super::{
+    BlendMode, ClosestElem, LineStyle, PlotConfig, PlotGeometry, PlotItem, PlotLayer, PlotPoint,
+    DEFAULT_FILL_ALPHA,
+};
+use crate::{Cursor, LabelFormatter, LegendIcon, PlotBounds, PlotTransform};
+
+/// The number of segments of the outline used for hovering.
+const HOVER_SEGMENTS: usize = 64;
+
+/// An ellipse or circle with its center and radii in plot coordinates.
+///
+/// Unlike a [`crate::Points`] marker, it keeps its size in plot coordinates,
+/// so a circle becomes an ellipse when the axes are zoomed differently.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Ellipse, Plot};
+///
+/// Plot::new("ellipses").data_aspect(1.0).show(ui, |plot_ui| {
+///     plot_ui.ellipse(Ellipse::circle([0.0, 0.0], 1.0).name("unit circle"));
+///     plot_ui.ellipse(Ellipse::new([2.0, 1.0], [1.5, 0.5]).rotation(0.5).name("confidence"));
+/// });
+/// # });
+/// ```
+pub struct Ellipse {
+    center: PlotPoint,
+    radii: [f64; 2],
+    rotation: f64,
+    pub(crate) stroke: Stroke,
+    fill_color: Option<Color32>,
+    style: LineStyle,
+    pub(crate) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    id: Option<Id>,
+}
+
+impl Ellipse {
+    /// An ellipse with the given center and radii along the x- and y-axis, before any rotation.
+    pub fn new(center: impl Into<PlotPoint>, radii: [f64; 2]) -> Self {
+        Self {
+            center: center.into(),
+            radii,
+            rotation: 0.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            fill_color: None,
+            style: LineStyle::Solid,
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            id: None,
+        }
+    }
+
+    /// A circle with the given center and radius.
+    pub fn circle(center: impl Into<PlotPoint>, radius: f64) -> Self {
+        Self::new(center, [radius, radius])
+    }
+
+    /// Rotate the ellipse counter-clockwise by this angle in radians, in plot coordinates. Default: `0.0`.
+    #[inline]
+    pub fn rotation(mut self, angle: f64) -> Self {
+        self.rotation = angle;
+        self
+    }
+
+    /// Color of the outline. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Add a custom stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Set the stroke width.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Fill color. Defaults to the stroke color with added transparency.
+    #[inline]
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.fill_color = Some(color.into());
+        self
+    }
+
+    /// Set the outline's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this ellipse.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this ellipse in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Set the ellipse's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Points on the outline, in plot coordinates.
+    fn outline(&self, segments: usize) -> Vec<PlotPoint> {
+        let [rx, ry] = self.radii;
+        let (sin, cos) = self.rotation.sin_cos();
+        (0..segments)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * i as f64 / segments as f64;
+                let (x, y) = (rx * angle.cos(), ry * angle.sin());
+                PlotPoint::new(
+                    self.center.x + x * cos - y * sin,
+                    self.center.y + x * sin + y * cos,
+                )
+            })
+            .collect()
+    }
+
+    /// The outline on the screen, closed, with enough segments to look smooth at its size.
+    fn screen_outline(&self, transform: &PlotTransform, segments: usize) -> Vec<Pos2> {
+        let mut outline = Vec::new();
+        transform.positions_from_points(&self.outline(segments), &mut outline);
+        if let Some(first) = outline.first() {
+            outline.push(*first);
+        }
+        outline
+    }
+
+    /// A segment for about every four points of the outline on the screen.
+    fn segments(&self, transform: &PlotTransform) -> usize {
+        let [dx, dy] = transform.dpos_dvalue();
+        let screen_radius = (dx * self.radii[0]).abs().max((dy * self.radii[1]).abs())
+            + (dx * self.radii[1]).abs().max((dy * self.radii[0]).abs());
+        let perimeter = std::f64::consts::PI * screen_radius;
+        ((perimeter / 4.0) as usize).clamp(16, 1024)
+    }
+}
+
+impl PlotItem for Ellipse {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.has_non_finite_values() {
+            return;
+        }
+
+        let outline = self.screen_outline(transform, self.segments(transform));
+        let fill_color = self
+            .fill_color
+            .unwrap_or(self.stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
+        let open_outline = outline[..outline.len().saturating_sub(1)].to_vec();
+        shapes.push(Shape::convex_polygon(
+            open_outline,
+            fill_color,
+            Stroke::NONE,
+        ));
+        self.style
+            .style_line(outline, self.stroke, self.highlight, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        let fill = self
+            .fill_color
+            .unwrap_or(self.stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
+        LegendIcon {
+            fill: Some(fill),
+            ..Default::default()
+        }
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        // The extent of the rotated ellipse along each axis.
+        let [rx, ry] = self.radii;
+        let (sin, cos) = self.rotation.sin_cos();
+        let half_width = (rx * cos).hypot(ry * sin);
+        let half_height = (rx * sin).hypot(ry * cos);
+        PlotBounds::from_min_max(
+            [self.center.x - half_width, self.center.y - half_height],
+            [self.center.x + half_width, self.center.y + half_height],
+        )
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        !self.center.is_finite()
+            || !self.radii.iter().all(|radius| radius.is_finite())
+            || !self.rotation.is_finite()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        // Hovering the outline, since the inside is usually covered by other items.
+        self.screen_outline(transform, HOVER_SEGMENTS)
+            .windows(2)
+            .map(|segment| {
+                let [a, b] = [segment[0], segment[1]];
+                let t = ((point - a).dot(b - a) / (b - a).length_sq()).clamp(0.0, 1.0);
+                a.lerp(b, if t.is_finite() { t } else { 0.0 })
+                    .distance_sq(point)
+            })
+            .min_by(|a, b| a.total_cmp(b))
+            .map(|dist_sq| ClosestElem { index: 0, dist_sq })
+    }
+
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+        let outline = self.screen_outline(plot.transform, self.segments(plot.transform));
+        shapes.push(Shape::line(
+            outline,
+            Stroke::new(2.0, plot.ui.visuals().strong_text_color()),
+        ));
+
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "center: {}, {}\nradii: {}, {}",
+            self.center.x, self.center.y, self.radii[0], self.radii[1]
+        ));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        let pos = plot
+            .ui
+            .input(|i| i.pointer.hover_pos())
+            .unwrap_or_else(|| plot.transform.position_from_point(&self.center));
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+#[test]
+fn test_ellipse_bounds() {
+    let ellipse = Ellipse::new([1.0, 2.0], [2.0, 1.0]).rotation(std::f64::consts::FRAC_PI_2);
+    let bounds = ellipse.bounds();
+    assert!((bounds.min()[0] - 0.0).abs() < 1e-9);
+    assert!((bounds.max()[1] - 4.0).abs() < 1e-9);
+    for point in ellipse.outline(32) {
+        assert!(bounds.min()[0] - 1e-9 <= point.x && point.x <= bounds.max()[0] + 1e-9);
+        assert!(bounds.min()[1] - 1e-9 <= point.y && point.y <= bounds.max()[1] + 1e-9);
+    }
+}
@@ -0,0 +1,397 @@
+use std::ops::RangeInclusive;
+
+use egui::{epaint::Vertex, pos2, Color32, Id, Mesh, Rect, Shape, Stroke, Ui};
+
+use super::{BlendMode, PlotGeometry, PlotItem, PlotLayer, PlotPoint, PlotPoints};
+use crate::{LegendIcon, LegendSwatch, LineStyle, PlotBounds, PlotTransform};
+
+/// Many runs of the same series, like those of a Monte-Carlo simulation, drawn faintly
+/// in a shared style with a single legend entry.
+///
+/// Optionally, the median of the runs is drawn on top as a solid line, and the range between
+/// two quantiles as a band. These are computed point by point, assuming that the runs share
+/// their x-coordinates: the first point of every run, then the second, and so on.
+/// Only the median can be hovered.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Ensemble, Plot};
+///
+/// let runs: Vec<Vec<[f64; 2]>> = (0..100)
+///     .map(|run| (0..50).map(|i| [i as f64, (i * run % 17) as f64]).collect())
+///     .collect();
+/// Plot::new("simulation").show(ui, |plot_ui| {
+///     plot_ui.ensemble(Ensemble::new(runs).name("runs").median(true).quantiles(0.1, 0.9));
+/// });
+/// # });
+/// ```
+pub struct Ensemble {
+    runs: Vec<PlotPoints>,
+    pub(crate) stroke: Stroke,
+    run_alpha: f32,
+    median: Option<Vec<PlotPoint>>,
+    band: Option<(Vec<PlotPoint>, Vec<PlotPoint>)>,
+    pub(crate) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    id: Option<Id>,
+}
+
+impl Ensemble {
+    pub fn new(runs: impl IntoIterator<Item = impl Into<PlotPoints>>) -> Self {
+        Self {
+            runs: runs.into_iter().map(Into::into).collect(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            run_alpha: 0.1,
+            median: None,
+            band: None,
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            id: None,
+        }
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Stroke width of the runs, and half that of the median. Default: `1.0`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// The opacity of each run, between `0.0` and `1.0`. Default: `0.1`.
+    ///
+    /// Where many runs overlap they add up to a more opaque color.
+    #[inline]
+    pub fn run_alpha(mut self, alpha: f32) -> Self {
+        self.run_alpha = alpha.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Draw the median of the runs as a solid line on top of them. Default: `false`.
+    #[inline]
+    pub fn median(mut self, median: bool) -> Self {
+        self.median = median.then(|| self.quantile(0.5));
+        self
+    }
+
+    /// Shade the band between the `lower` and `upper` quantile of the runs, like `0.1` and `0.9`.
+    #[inline]
+    pub fn quantiles(mut self, lower: f64, upper: f64) -> Self {
+        self.band = Some((self.quantile(lower), self.quantile(upper)));
+        self
+    }
+
+    /// Name of this item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this item in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Set the item's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.runs.iter().all(PlotPoints::is_empty)
+    }
+
+    /// The given quantile of the runs at each point index, at the median x-coordinate.
+    ///
+    /// Runs that are generated by a function have no points yet, and are left out.
+    fn quantile(&self, q: f64) -> Vec<PlotPoint> {
+        let len = self
+            .runs
+            .iter()
+            .map(|run| run.points().len())
+            .max()
+            .unwrap_or(0);
+        (0..len)
+            .filter_map(|index| {
+                let points = self.runs.iter().filter_map(|run| run.points().get(index));
+                let (mut xs, mut ys): (Vec<f64>, Vec<f64>) = points
+                    .filter(|point| point.is_finite())
+                    .map(|point| (point.x, point.y))
+                    .unzip();
+                Some(PlotPoint::new(
+                    quantile_of(&mut xs, 0.5)?,
+                    quantile_of(&mut ys, q)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// The `q` quantile of the values, interpolating linearly between them.
+fn quantile_of(values: &mut [f64], q: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let rank = q.clamp(0.0, 1.0) * (values.len() - 1) as f64;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    Some(emath::lerp(values[below]..=values[above], rank.fract()))
+}
+
+impl PlotItem for Ensemble {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let color = self.stroke.color;
+        let to_screen = |points: &[PlotPoint]| -> Vec<_> {
+            points
+                .iter()
+                .filter(|point| point.is_finite())
+                .map(|point| transform.position_from_point(point))
+                .collect()
+        };
+
+        if let Some((lower, upper)) = &self.band {
+            let fill = color.gamma_multiply(if self.highlight { 0.4 } else { 0.2 });
+            let mut mesh = Mesh::default();
+            for (lower, upper) in to_screen(lower).into_iter().zip(to_screen(upper)) {
+                let index = mesh.vertices.len() as u32;
+                if 0 < index {
+                    mesh.add_triangle(index - 2, index - 1, index);
+                    mesh.add_triangle(index - 1, index, index + 1);
+                }
+                mesh.vertices.push(Vertex {
+                    pos: lower,
+                    uv: egui::epaint::WHITE_UV,
+                    color: fill,
+                });
+                mesh.vertices.push(Vertex {
+                    pos: upper,
+                    uv: egui::epaint::WHITE_UV,
+                    color: fill,
+                });
+            }
+            shapes.push(Shape::mesh(mesh));
+        }
+
+        let run_alpha = if self.highlight {
+            (2.0 * self.run_alpha).min(1.0)
+        } else {
+            self.run_alpha
+        };
+        let run_stroke = Stroke::new(self.stroke.width, color.gamma_multiply(run_alpha));
+        for run in &self.runs {
+            let points = to_screen(run.points());
+            if 2 <= points.len() {
+                shapes.push(Shape::line(points, run_stroke));
+            }
+        }
+
+        if let Some(median) = &self.median {
+            let width = 2.0 * self.stroke.width * if self.highlight { 2.0 } else { 1.0 };
+            shapes.push(Shape::line(to_screen(median), Stroke::new(width, color)));
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        for run in &mut self.runs {
+            run.generate_points(x_range.clone());
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            line: Some((self.stroke, LineStyle::Solid)),
+            ..Default::default()
+        }
+    }
+
+    fn legend_swatch(&self) -> Option<LegendSwatch> {
+        // A few faint runs, with the band and the median if they are shown.
+        let color = self.stroke.color;
+        let run_stroke = Stroke::new(1.0, color.gamma_multiply(self.run_alpha.max(0.3)));
+        let (band, median) = (self.band.is_some(), self.median.is_some());
+        Some(LegendSwatch::new(move |painter, rect| {
+            let area = rect.shrink(rect.height() * 0.15);
+            if band {
+                let band = Rect::from_x_y_ranges(
+                    area.x_range(),
+                    area.center().y - area.height() / 4.0..=area.center().y + area.height() / 4.0,
+                );
+                painter.rect_filled(band, 0.0, color.gamma_multiply(0.2));
+            }
+            for t in [0.1, 0.5, 0.9] {
+                let y = egui::lerp(area.y_range(), t);
+                painter.line_segment(
+                    [
+                        pos2(area.left(), y),
+                        pos2(area.right(), 1.5 * area.center().y - 0.5 * y),
+                    ],
+                    run_stroke,
+                );
+            }
+            if median {
+                painter.line_segment(
+                    [area.left_center(), area.right_center()],
+                    Stroke::new(2.0, color),
+                );
+            }
+        }))
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        match &self.median {
+            Some(median) => PlotGeometry::Points(median),
+            None => PlotGeometry::None,
+        }
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for run in &self.runs {
+            bounds.merge(&run.bounds());
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+#[test]
+fn test_ensemble_quantiles() {
+    let runs = (0..5).map(|run| vec![[0.0, run as f64], [1.0, 10.0 * run as f64]]);
+    let ensemble = Ensemble::new(runs);
+    assert_eq!(
+        ensemble.quantile(0.5),
+        vec![PlotPoint::new(0.0, 2.0), PlotPoint::new(1.0, 20.0)]
+    );
+    assert_eq!(
+        ensemble.quantile(0.125),
+        vec![PlotPoint::new(0.0, 0.5), PlotPoint::new(1.0, 5.0)]
+    );
+}
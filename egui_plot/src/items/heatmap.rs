@@ -0,0 +1,581 @@
+use std::{
+    ops::{Range, RangeInclusive},
+    sync::Arc,
+};
+
+use egui::{pos2, vec2, Align2, Color32, Id, Mesh, Rect, Shape, Stroke, TextStyle, Ui};
+
+use super::{
+    BlendMode, ClosestElem, PlotConfig, PlotGeometry, PlotItem, PlotLayer, PlotPoint,
+    MAX_MESH_VERTICES,
+};
+use crate::{
+    colors::{palette_color, DEFAULT_PALETTE},
+    Cursor, LabelFormatter, LegendSwatch, PlotBounds, PlotTransform,
+};
+
+/// The order of the values given to a [`Heatmap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapLayout {
+    /// One row after the other, like most image data.
+    #[default]
+    RowMajor,
+
+    /// One column after the other, like the arrays of Fortran or Matlab.
+    ColumnMajor,
+}
+
+/// Where the first row of the values given to a [`Heatmap`] is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapOrigin {
+    /// The first row is at the bottom, so that the row index grows with y like in the plot.
+    #[default]
+    BottomLeft,
+
+    /// The first row is at the top, like in images.
+    TopLeft,
+}
+
+/// A block of cells of a [`Heatmap`] selected by the user, see [`Heatmap::selectable`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeatmapSelection {
+    /// The id of the heatmap.
+    ///
+    /// For heatmaps without an id this is `Id::new(name)`.
+    pub item_id: Id,
+
+    /// The selected columns.
+    pub cols: Range<usize>,
+
+    /// The selected rows, counting from the bottom.
+    pub rows: Range<usize>,
+
+    /// The region covered by the selected cells, in plot coordinates.
+    pub bounds: PlotBounds,
+}
+
+/// A grid of cells colored by their value.
+///
+/// Cells with a NaN or infinite value are not shown.
+pub struct Heatmap {
+    pub(super) values: Vec<f64>,
+    cols: usize,
+    rows: usize,
+    layout: HeatmapLayout,
+    origin: HeatmapOrigin,
+    position: PlotPoint,
+    cell_size: [f64; 2],
+    palette: Vec<Color32>,
+    range: Option<RangeInclusive<f64>>,
+    pub(super) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    selectable: bool,
+    id: Option<Id>,
+    data_version: Option<u64>,
+}
+
+impl Heatmap {
+    /// A heatmap with the given number of columns, and as many full rows as there are values for.
+    ///
+    /// By default the values are given row by row, starting with the bottom row,
+    /// see [`Self::layout`] and [`Self::origin`] for other conventions.
+    pub fn new(values: Vec<f64>, cols: usize) -> Self {
+        let cols = cols.max(1);
+        Self {
+            rows: values.len() / cols,
+            values,
+            cols,
+            layout: HeatmapLayout::default(),
+            origin: HeatmapOrigin::default(),
+            position: PlotPoint::new(0.0, 0.0),
+            cell_size: [1.0, 1.0],
+            palette: DEFAULT_PALETTE.to_vec(),
+            range: None,
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            selectable: false,
+            id: None,
+            data_version: None,
+        }
+    }
+
+    /// The order of the values, so that data doesn't need to be transposed. Default: [`HeatmapLayout::RowMajor`].
+    #[inline]
+    pub fn layout(mut self, layout: HeatmapLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Where the first row of values is shown, so that image data doesn't need to be flipped.
+    /// Default: [`HeatmapOrigin::BottomLeft`].
+    #[inline]
+    pub fn origin(mut self, origin: HeatmapOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// The position of the bottom left corner of the heatmap. Default: the origin.
+    #[inline]
+    pub fn position(mut self, position: PlotPoint) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// The width and height of each cell, in plot coordinates. Default: `1.0` by `1.0`.
+    #[inline]
+    pub fn cell_size(mut self, width: f64, height: f64) -> Self {
+        self.cell_size = [width, height];
+        self
+    }
+
+    /// The colors for the values from the lowest to the highest, interpolated in between.
+    #[inline]
+    pub fn palette(mut self, palette: Vec<Color32>) -> Self {
+        if !palette.is_empty() {
+            self.palette = palette;
+        }
+        self
+    }
+
+    /// The values that get the first and last color of the palette.
+    ///
+    /// Default: the lowest and highest value.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Name of this heatmap.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this heatmap in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Allow the user to select a cell by clicking it, or a block of cells by dragging over them.
+    ///
+    /// Dragging over the heatmap then selects cells instead of panning the plot.
+    /// The selection is reported in [`crate::PlotResponse::heatmap_selection`]. Default: `false`.
+    #[inline]
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Set the heatmap's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
+
+    /// The number of columns.
+    #[inline]
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The number of rows.
+    #[inline]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The value of the cell in the given column and row, counting rows from the bottom.
+    pub fn value_at(&self, col: usize, row: usize) -> Option<f64> {
+        (col < self.cols && row < self.rows).then(|| self.values[self.index(col, row)])
+    }
+
+    /// The column and row of the cell containing the given point, counting rows from the bottom.
+    pub fn cell_at(&self, point: PlotPoint) -> Option<(usize, usize)> {
+        let col = (point.x - self.position.x) / self.cell_size[0];
+        let row = (point.y - self.position.y) / self.cell_size[1];
+        let inside =
+            (0.0..self.cols as f64).contains(&col) && (0.0..self.rows as f64).contains(&row);
+        inside.then_some((col as usize, row as usize))
+    }
+
+    /// The cell containing the given point, or the closest one if the point is outside.
+    fn closest_cell(&self, point: PlotPoint) -> (usize, usize) {
+        let col = (point.x - self.position.x) / self.cell_size[0];
+        let row = (point.y - self.position.y) / self.cell_size[1];
+        (
+            (col.max(0.0) as usize).min(self.cols - 1),
+            (row.max(0.0) as usize).min(self.rows.max(1) - 1),
+        )
+    }
+
+    /// The index into the values of the cell in the given column and row, counting rows from the bottom.
+    fn index(&self, col: usize, row: usize) -> usize {
+        let row = match self.origin {
+            HeatmapOrigin::BottomLeft => row,
+            HeatmapOrigin::TopLeft => self.rows - 1 - row,
+        };
+        match self.layout {
+            HeatmapLayout::RowMajor => row * self.cols + col,
+            HeatmapLayout::ColumnMajor => col * self.rows + row,
+        }
+    }
+
+    /// The column and row of the cell with the given index into the values.
+    fn cell(&self, index: usize) -> (usize, usize) {
+        let (col, row) = match self.layout {
+            HeatmapLayout::RowMajor => (index % self.cols, index / self.cols),
+            HeatmapLayout::ColumnMajor => (index / self.rows, index % self.rows),
+        };
+        let row = match self.origin {
+            HeatmapOrigin::BottomLeft => row,
+            HeatmapOrigin::TopLeft => self.rows - 1 - row,
+        };
+        (col, row)
+    }
+
+    fn cell_rect(&self, transform: &PlotTransform, col: usize, row: usize) -> Rect {
+        let min = PlotPoint::new(
+            self.position.x + col as f64 * self.cell_size[0],
+            self.position.y + row as f64 * self.cell_size[1],
+        );
+        let max = PlotPoint::new(min.x + self.cell_size[0], min.y + self.cell_size[1]);
+        transform.rect_from_values(&min, &max)
+    }
+
+    fn value_range(&self) -> RangeInclusive<f64> {
+        self.range.clone().unwrap_or_else(|| {
+            let (min, max) = self
+                .values
+                .iter()
+                .filter(|value| value.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(*value), max.max(*value))
+                });
+            min..=max
+        })
+    }
+
+    fn color_of(&self, value: f64, range: &RangeInclusive<f64>) -> Color32 {
+        palette_color(&self.palette, value, range)
+    }
+}
+
+impl PlotItem for Heatmap {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let range = self.value_range();
+        let mut mesh = Mesh::default();
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                let value = self.values[self.index(col, row)];
+                if !value.is_finite() {
+                    continue;
+                }
+                if mesh.vertices.len() + 4 > MAX_MESH_VERTICES {
+                    shapes.push(Shape::Mesh(Arc::new(std::mem::take(&mut mesh))));
+                }
+                let rect = self.cell_rect(transform, col, row);
+                mesh.add_colored_rect(rect, self.color_of(value, &range));
+            }
+        }
+        shapes.push(Shape::Mesh(Arc::new(mesh)));
+
+        if self.highlight {
+            let rect = self.cell_rect(transform, 0, 0).union(self.cell_rect(
+                transform,
+                self.cols - 1,
+                self.rows.max(1) - 1,
+            ));
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(1.0, self.color()),
+                egui::StrokeKind::Outside,
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.palette[self.palette.len() / 2]
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn legend_swatch(&self) -> Option<LegendSwatch> {
+        // A small gradient of the palette.
+        let palette = self.palette.clone();
+        Some(LegendSwatch::new(move |painter, rect| {
+            let area = rect.shrink(rect.height() * 0.15);
+            let mut mesh = Mesh::default();
+            let last = palette.len().saturating_sub(1).max(1) as f32;
+            for (i, color) in palette.iter().enumerate() {
+                let x = egui::lerp(area.x_range(), i as f32 / last);
+                let index = mesh.vertices.len() as u32;
+                mesh.colored_vertex(pos2(x, area.top()), *color);
+                mesh.colored_vertex(pos2(x, area.bottom()), *color);
+                if 0 < i {
+                    mesh.add_triangle(index - 2, index - 1, index);
+                    mesh.add_triangle(index - 1, index, index + 1);
+                }
+            }
+            painter.add(mesh);
+        }))
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        if self.rows > 0 {
+            bounds.extend_with(&self.position);
+            bounds.extend_with(&PlotPoint::new(
+                self.position.x + self.cols as f64 * self.cell_size[0],
+                self.position.y + self.rows as f64 * self.cell_size[1],
+            ));
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn data_version(&self) -> Option<u64> {
+        self.data_version
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        self.values.iter().any(|value| !value.is_finite())
+    }
+
+    fn select_cells(&self, from: PlotPoint, to: PlotPoint) -> Option<HeatmapSelection> {
+        if !self.selectable {
+            return None;
+        }
+        let (from_col, from_row) = self.cell_at(from)?;
+        let (to_col, to_row) = self.closest_cell(to);
+        let cols = from_col.min(to_col)..from_col.max(to_col) + 1;
+        let rows = from_row.min(to_row)..from_row.max(to_row) + 1;
+        let bounds = PlotBounds::from_min_max(
+            [
+                self.position.x + cols.start as f64 * self.cell_size[0],
+                self.position.y + rows.start as f64 * self.cell_size[1],
+            ],
+            [
+                self.position.x + cols.end as f64 * self.cell_size[0],
+                self.position.y + rows.end as f64 * self.cell_size[1],
+            ],
+        );
+        Some(HeatmapSelection {
+            item_id: self.id.unwrap_or_else(|| Id::new(&self.name)),
+            cols,
+            rows,
+            bounds,
+        })
+    }
+
+    fn find_closest(&self, point: egui::Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let (col, row) = self.cell_at(transform.value_from_position(point))?;
+        let index = self.index(col, row);
+        self.values[index].is_finite().then_some(ClosestElem {
+            index,
+            dist_sq: 0.0,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+        let (col, row) = self.cell(elem.index);
+        let rect = self.cell_rect(plot.transform, col, row);
+        shapes.push(Shape::rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(2.0, plot.ui.visuals().strong_text_color()),
+            egui::StrokeKind::Outside,
+        ));
+
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!("[{col}, {row}] = {}", self.values[elem.index]));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos2(rect.right(), rect.top()) + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+#[test]
+fn test_heatmap_layout() {
+    // Two rows of three columns: the bottom row is 0, 1, 2 and the top row 3, 4, 5.
+    let heatmap = Heatmap::new(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0], 3);
+    assert_eq!((heatmap.cols(), heatmap.rows()), (3, 2));
+    assert_eq!(heatmap.value_at(1, 0), Some(1.0));
+    assert_eq!(heatmap.value_at(1, 1), Some(4.0));
+    assert_eq!(heatmap.value_at(3, 0), None);
+
+    let image = Heatmap::new(vec![3.0, 4.0, 5.0, 0.0, 1.0, 2.0], 3).origin(HeatmapOrigin::TopLeft);
+    let columns =
+        Heatmap::new(vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0], 3).layout(HeatmapLayout::ColumnMajor);
+    for other in [image, columns] {
+        for index in 0..6 {
+            let (col, row) = other.cell(index);
+            assert_eq!(other.index(col, row), index);
+            assert_eq!(other.value_at(col, row), heatmap.value_at(col, row));
+        }
+    }
+
+    assert_eq!(heatmap.cell_at(PlotPoint::new(2.5, 0.5)), Some((2, 0)));
+    assert_eq!(heatmap.cell_at(PlotPoint::new(-0.5, 0.5)), None);
+
+    // Selections start on a cell and are clamped to the heatmap.
+    assert!(heatmap
+        .select_cells(PlotPoint::new(0.5, 0.5), PlotPoint::new(0.5, 0.5))
+        .is_none());
+    let heatmap = heatmap.selectable(true);
+    let selection = heatmap
+        .select_cells(PlotPoint::new(1.5, 0.5), PlotPoint::new(-4.0, 9.0))
+        .expect("selection");
+    assert_eq!((selection.cols, selection.rows), (0..2, 0..2));
+    assert!(heatmap
+        .select_cells(PlotPoint::new(-0.5, 0.5), PlotPoint::new(0.5, 0.5))
+        .is_none());
+}
@@ -0,0 +1,477 @@
+use std::{cell::OnceCell, ops::RangeInclusive, sync::Arc};
+
+use ahash::HashMap;
+use egui::{pos2, vec2, Align2, Color32, Id, Mesh, Pos2, Shape, Stroke, TextStyle, Ui};
+
+use super::{
+    BlendMode, ClosestElem, PlotConfig, PlotGeometry, PlotItem, PlotLayer, PlotPoint, PlotPoints,
+    MAX_MESH_VERTICES,
+};
+use crate::{
+    colors::{palette_color, DEFAULT_PALETTE},
+    Cursor, LabelFormatter, PlotBounds, PlotTransform,
+};
+
+/// The function computing the value of a bin from the values of its points, see [`Hexbin::aggregate`].
+type AggregateFn = dyn Fn(&[f64]) -> f64;
+
+/// A point cloud aggregated into hexagonal bins, colored by the number of points in each.
+///
+/// The bins have a fixed size on the screen, so they are recomputed when zooming,
+/// which makes this useful for scatter plots with too many points to show as markers.
+/// Points with a NaN or infinite coordinate are skipped.
+pub struct Hexbin {
+    pub(crate) points: PlotPoints,
+    aggregate: Option<(Vec<f64>, Box<AggregateFn>)>,
+    radius: f32,
+    min_count: usize,
+    palette: Vec<Color32>,
+    range: Option<RangeInclusive<f64>>,
+    name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    id: Option<Id>,
+    data_version: Option<u64>,
+
+    /// The bins for the current view, computed when first needed.
+    bins: OnceCell<Vec<HexBin>>,
+}
+
+/// A bin of a [`Hexbin`], in axial coordinates.
+struct HexBin {
+    q: i64,
+    r: i64,
+    count: usize,
+    value: f64,
+}
+
+impl Hexbin {
+    pub fn new(points: impl Into<PlotPoints>) -> Self {
+        Self {
+            points: points.into(),
+            aggregate: None,
+            radius: 10.0,
+            min_count: 1,
+            palette: DEFAULT_PALETTE.to_vec(),
+            range: None,
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            id: None,
+            data_version: None,
+            bins: OnceCell::new(),
+        }
+    }
+
+    /// Color the bins by the aggregate of the values of their points, like their mean or maximum,
+    /// instead of by the number of points.
+    ///
+    /// There is one value for each point, in the same order.
+    #[inline]
+    pub fn aggregate(
+        mut self,
+        values: Vec<f64>,
+        aggregate: impl Fn(&[f64]) -> f64 + 'static,
+    ) -> Self {
+        self.aggregate = Some((values, Box::new(aggregate)));
+        self
+    }
+
+    /// The distance from the center of a bin to its corners, in ui points. Default: `10.0`.
+    #[inline]
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius.max(1.0);
+        self
+    }
+
+    /// Hide bins with fewer points than this. Default: `1`.
+    #[inline]
+    pub fn min_count(mut self, min_count: usize) -> Self {
+        self.min_count = min_count.max(1);
+        self
+    }
+
+    /// The colors for the values from the lowest to the highest, interpolated in between.
+    #[inline]
+    pub fn palette(mut self, palette: Vec<Color32>) -> Self {
+        if !palette.is_empty() {
+            self.palette = palette;
+        }
+        self
+    }
+
+    /// The values that get the first and last color of the palette.
+    ///
+    /// Default: the lowest and highest value of the bins in view.
+    #[inline]
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Name of this item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this item in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Set the item's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
+
+    /// The position of the point in the hexagonal grid, in units of the bin radius.
+    ///
+    /// The grid is anchored at the origin of the plot, so bins stay in place while panning.
+    fn grid_position(&self, transform: &PlotTransform, point: &PlotPoint) -> [f64; 2] {
+        let [x_scale, y_scale] = transform.scales();
+        let dpos_dvalue = transform.dpos_dvalue();
+        let radius = self.radius as f64;
+        [
+            x_scale.apply(point.x) * dpos_dvalue[0] / radius,
+            y_scale.apply(point.y) * dpos_dvalue[1] / radius,
+        ]
+    }
+
+    /// The screen position of the center of the bin.
+    fn center(&self, transform: &PlotTransform, q: i64, r: i64) -> Pos2 {
+        let [x_scale, y_scale] = transform.scales();
+        let dpos_dvalue = transform.dpos_dvalue();
+        let radius = self.radius as f64;
+        let u = 3.0_f64.sqrt() * (q as f64 + r as f64 / 2.0);
+        let v = 1.5 * r as f64;
+        transform.position_from_point(&PlotPoint::new(
+            x_scale.invert(u * radius / dpos_dvalue[0]),
+            y_scale.invert(v * radius / dpos_dvalue[1]),
+        ))
+    }
+
+    /// The corners of the bin with the given center, with a pointy top.
+    fn corners(&self, center: Pos2) -> [Pos2; 6] {
+        std::array::from_fn(|i| {
+            let angle = (30.0 + 60.0 * i as f32).to_radians();
+            center + self.radius * vec2(angle.cos(), angle.sin())
+        })
+    }
+
+    /// The bins of the points within the current view.
+    fn bins(&self, transform: &PlotTransform) -> &[HexBin] {
+        self.bins.get_or_init(|| {
+            let bounds = transform.bounds();
+            let mut index_of: HashMap<(i64, i64), usize> = HashMap::default();
+            let mut bins = Vec::new();
+            let mut values: Vec<Vec<f64>> = Vec::new();
+
+            for (i, point) in self.points.points().iter().enumerate() {
+                if !point.is_finite()
+                    || !bounds.range_x().contains(&point.x)
+                    || !bounds.range_y().contains(&point.y)
+                {
+                    continue;
+                }
+                let [u, v] = self.grid_position(transform, point);
+                let (q, r) = hex_round(u * 3.0_f64.sqrt() / 3.0 - v / 3.0, v * 2.0 / 3.0);
+                let index = *index_of.entry((q, r)).or_insert_with(|| {
+                    bins.push(HexBin {
+                        q,
+                        r,
+                        count: 0,
+                        value: 0.0,
+                    });
+                    values.push(Vec::new());
+                    bins.len() - 1
+                });
+                bins[index].count += 1;
+                if let Some((point_values, _)) = &self.aggregate {
+                    if let Some(value) = point_values.get(i).filter(|value| value.is_finite()) {
+                        values[index].push(*value);
+                    }
+                }
+            }
+
+            for (bin, values) in bins.iter_mut().zip(&values) {
+                bin.value = match &self.aggregate {
+                    Some((_, aggregate)) => aggregate(values),
+                    None => bin.count as f64,
+                };
+            }
+            bins.retain(|bin| bin.count >= self.min_count && bin.value.is_finite());
+            bins
+        })
+    }
+
+    fn value_range(&self, bins: &[HexBin]) -> RangeInclusive<f64> {
+        self.range.clone().unwrap_or_else(|| {
+            let (min, max) = bins
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), bin| {
+                    (min.min(bin.value), max.max(bin.value))
+                });
+            min..=max
+        })
+    }
+}
+
+/// The hexagon containing the fractional axial coordinates.
+fn hex_round(q: f64, r: f64) -> (i64, i64) {
+    let s = -q - r;
+    let (mut q_round, mut r_round, s_round) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = (
+        (q_round - q).abs(),
+        (r_round - r).abs(),
+        (s_round - s).abs(),
+    );
+    if dq > dr && dq > ds {
+        q_round = -r_round - s_round;
+    } else if dr > ds {
+        r_round = -q_round - s_round;
+    }
+    (q_round as i64, r_round as i64)
+}
+
+impl PlotItem for Hexbin {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let bins = self.bins(transform);
+        let range = self.value_range(bins);
+
+        let mut mesh = Mesh::default();
+        for bin in bins {
+            if mesh.vertices.len() + 6 > MAX_MESH_VERTICES {
+                shapes.push(Shape::Mesh(Arc::new(std::mem::take(&mut mesh))));
+            }
+            let color = palette_color(&self.palette, bin.value, &range);
+            let index = mesh.vertices.len() as u32;
+            for corner in self.corners(self.center(transform, bin.q, bin.r)) {
+                mesh.colored_vertex(corner, color);
+            }
+            for i in 1..5 {
+                mesh.add_triangle(index, index + i, index + i + 1);
+            }
+        }
+        shapes.push(Shape::Mesh(Arc::new(mesh)));
+
+        if self.highlight {
+            let stroke = Stroke::new(1.0, self.color());
+            for bin in bins {
+                let corners = self.corners(self.center(transform, bin.q, bin.r));
+                shapes.push(Shape::closed_line(corners.to_vec(), stroke));
+            }
+        }
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.points.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.palette[self.palette.len() / 2]
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.points.bounds()
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn data_version(&self) -> Option<u64> {
+        matches!(self.points, PlotPoints::Owned(_))
+            .then_some(self.data_version)
+            .flatten()
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        self.points.points().iter().any(|point| !point.is_finite())
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let [u, v] = self.grid_position(transform, &transform.value_from_position(point));
+        let (q, r) = hex_round(u * 3.0_f64.sqrt() / 3.0 - v / 3.0, v * 2.0 / 3.0);
+        let index = self
+            .bins(transform)
+            .iter()
+            .position(|bin| bin.q == q && bin.r == r)?;
+        Some(ClosestElem {
+            index,
+            dist_sq: 0.0,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+        let Some(bin) = self.bins(plot.transform).get(elem.index) else {
+            return;
+        };
+        let center = self.center(plot.transform, bin.q, bin.r);
+        shapes.push(Shape::closed_line(
+            self.corners(center).to_vec(),
+            Stroke::new(2.0, plot.ui.visuals().strong_text_color()),
+        ));
+
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        if self.aggregate.is_some() {
+            text.push_str(&format!("{} ({} points)", bin.value, bin.count));
+        } else {
+            text.push_str(&format!("{} points", bin.count));
+        }
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos2(center.x + self.radius, center.y - self.radius),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
+
+#[test]
+fn test_hex_round() {
+    assert_eq!(hex_round(0.1, -0.1), (0, 0));
+    assert_eq!(hex_round(0.9, 0.05), (1, 0));
+    // Closer to the neighbor in the third cube direction than to either rounded coordinate.
+    assert_eq!(hex_round(0.45, 0.45), (0, 1));
+}
@@ -1,42 +1,102 @@
 //! Contains items that can be added to a plot.
+//!
+//! There are two kinds of items:
+//! * Data items, implementing [`PlotItem`], show the data of the plot: [`Line`], [`Points`],
+//!   [`Ensemble`], [`BarChart`], [`BoxPlot`], [`Heatmap`], [`Hexbin`], [`Polygon`], [`Arrows`], [`VectorField`] and [`RadarSeries`].
+//!   They can be hovered, get a legend entry and are included in the automatic bounds.
+//! * Decorations, implementing [`DecorationItem`], annotate the data: [`HLine`], [`VLine`], [`AbLine`],
+//!   [`Region`], [`RecurringLines`], [`Text`] and [`PlotImage`]. By default they are left out of the legend
+//!   and the automatic bounds, and can't be hovered, but each of these can be turned on per item.
 #![allow(clippy::type_complexity)] // TODO(emilk): simplify some of the callback types with type aliases
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use egui::{
     emath::Rot2,
     epaint::{CircleShape, TextShape},
-    pos2, vec2, Align2, Color32, Id, ImageOptions, Mesh, NumExt as _, Pos2, Rect, Rgba, Rounding,
-    Shape, Stroke, TextStyle, TextureId, Ui, Vec2, WidgetText,
+    pos2, vec2, Align2, Color32, Galley, Id, ImageOptions, Mesh, NumExt as _, Pos2, Rect, Rgba,
+    Rounding, Shape, Stroke, TextStyle, TextureId, Ui, Vec2, WidgetText,
 };
 
 use emath::Float as _;
 use rect_elem::{highlighted_color, RectElement};
 
-use super::{Cursor, LabelFormatter, PlotBounds, PlotTransform};
+use super::{
+    Axis, Cursor, LabelFormatter, LegendIcon, LegendSwatch, PlotBounds, PlotTransform,
+    RenderQuality,
+};
+pub use crate::data::PlotPoint;
+use crate::data::{histogram, thin_out, Bins};
 
+pub use ab_line::AbLine;
 pub use bar::Bar;
+pub use bezier::CubicBezier;
 pub use box_elem::{BoxElem, BoxSpread};
+pub(crate) use decoration::Decoration;
+pub use decoration::DecorationItem;
+pub use ellipse::Ellipse;
+pub use ensemble::Ensemble;
+pub use heatmap::{Heatmap, HeatmapLayout, HeatmapOrigin, HeatmapSelection};
+pub use hexbin::Hexbin;
+pub use radar::{Radar, RadarSeries};
+pub use recurring::RecurringLines;
+pub use region::Region;
 pub use values::{
-    ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints,
+    BlendMode, ClosestElem, Comparison, InlineLabel, LineCap, LineJoin, LineStyle, MarkerShape,
+    Orientation, PlotGeometry, PlotLayer, PlotPoints,
 };
+pub use vector_field::VectorField;
 
+mod ab_line;
 mod bar;
+mod bezier;
 mod box_elem;
+mod decoration;
+mod ellipse;
+mod ensemble;
+mod heatmap;
+mod hexbin;
+mod radar;
 mod rect_elem;
+mod recurring;
+mod region;
 mod values;
+mod vector_field;
 
 const DEFAULT_FILL_ALPHA: f32 = 0.05;
 
+/// Meshes built by the items are split before they reach this many vertices,
+/// since some backends (like WebGL 1) only support 16 bit indices.
+const MAX_MESH_VERTICES: usize = u16::MAX as usize;
+
 /// Container to pass-through several parameters related to plot visualization
 pub struct PlotConfig<'a> {
     pub ui: &'a Ui,
     pub transform: &'a PlotTransform,
     pub show_x: bool,
     pub show_y: bool,
+
+    /// Show the change from the previous point when hovering, see [`crate::Plot::show_delta`].
+    pub show_delta: bool,
+
+    /// Formats the values of hover labels, see [`crate::Plot::value_formatter`].
+    pub value_formatter: Option<&'a crate::ValueFormatterFn<'a>>,
+
+    /// All the visible items in the plot.
+    pub items: &'a [Box<dyn PlotItem>],
+}
+
+impl PlotConfig<'_> {
+    /// The value formatted with the [`crate::Plot::value_formatter`], if there is one.
+    pub fn format_value(&self, axis: Axis, value: f64) -> Option<String> {
+        self.value_formatter.map(|format| format(axis, value))
+    }
 }
 
 /// Trait shared by things that can be drawn in the plot.
+///
+/// Implement this for items that show data. Things that only annotate the data are easier
+/// to implement as a [`DecorationItem`].
 pub trait PlotItem {
     fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>);
 
@@ -51,15 +111,133 @@ pub trait PlotItem {
 
     fn highlighted(&self) -> bool;
 
-    /// Can the user hover this item?
+    /// Can the user hover this item, showing its hover label?
+    ///
+    /// Items that can't be hovered are also skipped when looking for the closest item to the pointer.
     fn allow_hover(&self) -> bool;
 
+    /// Is this item highlighted when its legend entry is hovered?
+    ///
+    /// This is independent of [`Self::allow_hover`], so decorations can still be highlighted
+    /// without ever showing a hover label.
+    fn allow_highlight(&self) -> bool {
+        true
+    }
+
+    /// Items with a higher priority win the hover over closer items with a lower one.
+    ///
+    /// When several items are within reach of the pointer, the one with the highest priority
+    /// is hovered, even if another one is closer.
+    fn hover_priority(&self) -> i32 {
+        0
+    }
+
+    /// The collapsible group of the legend to put the entry of this item in, if any.
+    ///
+    /// Items with the same group are listed together under a collapsible header,
+    /// and the checkbox of the group shows or hides all of its items at once.
+    fn legend_group(&self) -> Option<&str> {
+        None
+    }
+
+    /// The layer to draw this item on.
+    fn layer(&self) -> PlotLayer {
+        PlotLayer::Normal
+    }
+
+    /// Is this item clipped to the plot frame?
+    ///
+    /// Turn this off to let e.g. a label at the end of a line reach slightly outside the frame.
+    fn clip(&self) -> bool {
+        true
+    }
+
+    /// How the colors of this item are blended with what is drawn below it.
+    ///
+    /// Use [`BlendMode::Additive`] for many faint, overlapping items, like the runs of a simulation.
+    fn blend_mode(&self) -> BlendMode {
+        BlendMode::Normal
+    }
+
     fn geometry(&self) -> PlotGeometry<'_>;
 
     fn bounds(&self) -> PlotBounds;
 
     fn id(&self) -> Option<Id>;
 
+    /// A version of the data of this item, if known.
+    ///
+    /// When set, the bounds of the item, and of all items together, are reused between frames
+    /// for as long as the version stays the same.
+    /// Change the version whenever the data changes. Without a version,
+    /// the bounds are recomputed each frame.
+    ///
+    /// The shapes are always rebuilt, since they depend on the view as well as on the data.
+    fn data_version(&self) -> Option<u64> {
+        None
+    }
+
+    /// Set the level of detail to draw this item with, see [`crate::Plot::frame_budget`].
+    fn set_render_quality(&mut self, _quality: RenderQuality) {}
+
+    /// The values of the bars of this item at the given argument.
+    ///
+    /// Used for the hover label of [`BarChart::hover_aggregate`].
+    fn bar_values_at(&self, _argument: f64) -> Vec<f64> {
+        Vec::new()
+    }
+
+    /// Can the user drag the individual points of this item?
+    ///
+    /// See [`crate::PlotResponse::dragged_point`].
+    fn draggable(&self) -> bool {
+        false
+    }
+
+    /// A label to be placed by the plot next to the given point, avoiding other labels and the data.
+    ///
+    /// Returns the point, the laid out text, and the fallback color of the text.
+    /// See [`Line::inline_label`] and [`Text::auto_place`].
+    fn auto_placed_label(&self, _ui: &Ui) -> Option<(PlotPoint, Arc<Galley>, Color32)> {
+        None
+    }
+
+    /// The cells of this item between the two points, if it has selectable cells and `from` is on one of them.
+    ///
+    /// See [`Heatmap::selectable`].
+    fn select_cells(&self, _from: PlotPoint, _to: PlotPoint) -> Option<HeatmapSelection> {
+        None
+    }
+
+    /// The outline of the markers of this item, shown around its legend icon too.
+    ///
+    /// See [`Points::stroke`].
+    fn marker_outline(&self) -> Option<Stroke> {
+        None
+    }
+
+    /// The sample of this item shown as its icon in the legend.
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon::default()
+    }
+
+    /// A custom icon of this item in the legend, painted instead of the [`Self::legend_icon`],
+    /// like a small gradient for a heatmap.
+    fn legend_swatch(&self) -> Option<LegendSwatch> {
+        None
+    }
+
+    /// Does this item have NaN or infinite values that can't be shown?
+    ///
+    /// Such values are skipped when drawing the item and computing its bounds,
+    /// and are reported with [`crate::Plot::assert_finite`].
+    fn has_non_finite_values(&self) -> bool {
+        match self.geometry() {
+            PlotGeometry::Points(points) => points.iter().any(|point| !point.is_finite()),
+            PlotGeometry::None | PlotGeometry::Rects => false,
+        }
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
@@ -67,6 +245,7 @@ pub trait PlotItem {
             PlotGeometry::Points(points) => points
                 .iter()
                 .enumerate()
+                .filter(|(_, value)| value.is_finite())
                 .map(|(index, value)| {
                     let pos = transform.position_from_point(value);
                     let dist_sq = point.distance_sq(pos);
@@ -109,9 +288,16 @@ pub trait PlotItem {
         let pointer = plot.transform.position_from_point(&value);
         shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
 
+        let previous = elem
+            .index
+            .checked_sub(1)
+            .and_then(|index| points.get(index).copied())
+            .filter(|_| plot.show_delta);
+
         rulers_at_value(
             pointer,
             value,
+            previous,
             self.name(),
             plot,
             shapes,
@@ -124,6 +310,8 @@ pub trait PlotItem {
 // ----------------------------------------------------------------------------
 
 /// A horizontal line in a plot, filling the full width
+///
+/// The line is not shown if its value is NaN or infinite.
 #[derive(Clone, Debug, PartialEq)]
 pub struct HLine {
     pub(super) y: f64,
@@ -131,7 +319,15 @@ pub struct HLine {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
     pub(super) style: LineStyle,
+    pub(super) show_in_legend: bool,
+    pub(super) include_in_auto_bounds: bool,
     id: Option<Id>,
 }
 
@@ -142,8 +338,16 @@ impl HLine {
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             name: String::default(),
             highlight: false,
-            allow_hover: true,
+            allow_hover: false,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
             style: LineStyle::Solid,
+            show_in_legend: false,
+            include_in_auto_bounds: false,
             id: None,
         }
     }
@@ -155,13 +359,56 @@ impl HLine {
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Allow hovering this item, see [`DecorationItem::allow_hover`]. Default: `false`.
     #[inline]
     pub fn allow_hover(mut self, hovering: bool) -> Self {
         self.allow_hover = hovering;
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`DecorationItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`DecorationItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`DecorationItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`DecorationItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`DecorationItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -192,7 +439,7 @@ impl HLine {
 
     /// Name of this horizontal line.
     ///
-    /// This name will show up in the plot legend, if legends are turned on.
+    /// This name will show up in the plot legend if [`Self::show_in_legend`] is set.
     ///
     /// Multiple plot items may share the same name, in which case they will also share an entry in
     /// the legend.
@@ -203,6 +450,20 @@ impl HLine {
         self
     }
 
+    /// Show this item in the legend, see [`DecorationItem::show_in_legend`]. Default: `false`.
+    #[inline]
+    pub fn show_in_legend(mut self, show: bool) -> Self {
+        self.show_in_legend = show;
+        self
+    }
+
+    /// Widen the automatic bounds to include this item, see [`DecorationItem::include_in_auto_bounds`]. Default: `false`.
+    #[inline]
+    pub fn include_in_auto_bounds(mut self, include: bool) -> Self {
+        self.include_in_auto_bounds = include;
+        self
+    }
+
     /// Set the line's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
@@ -211,7 +472,7 @@ impl HLine {
     }
 }
 
-impl PlotItem for HLine {
+impl DecorationItem for HLine {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             y,
@@ -220,6 +481,9 @@ impl PlotItem for HLine {
             style,
             ..
         } = self;
+        if !y.is_finite() {
+            return;
+        }
 
         let points = vec![
             transform.position_from_point(&PlotPoint::new(transform.bounds().min[0], *y)),
@@ -228,12 +492,14 @@ impl PlotItem for HLine {
         style.style_line(points, *stroke, *highlight, shapes);
     }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
-
     fn name(&self) -> &str {
         &self.name
     }
 
+    fn show_in_legend(&self) -> bool {
+        self.show_in_legend
+    }
+
     fn color(&self) -> Color32 {
         self.stroke.color
     }
@@ -250,23 +516,59 @@ impl PlotItem for HLine {
         self.allow_hover
     }
 
-    fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            line: Some((self.stroke, self.style)),
+            ..Default::default()
+        }
     }
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        bounds.min[1] = self.y;
-        bounds.max[1] = self.y;
+        bounds.extend_with_y(self.y);
         bounds
     }
 
+    fn include_in_auto_bounds(&self) -> bool {
+        self.include_in_auto_bounds
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        !self.y.is_finite()
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
 }
 
 /// A vertical line in a plot, filling the full width
+///
+/// The line is not shown if its value is NaN or infinite.
 #[derive(Clone, Debug, PartialEq)]
 pub struct VLine {
     pub(super) x: f64,
@@ -274,7 +576,15 @@ pub struct VLine {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
     pub(super) style: LineStyle,
+    pub(super) show_in_legend: bool,
+    pub(super) include_in_auto_bounds: bool,
     id: Option<Id>,
 }
 
@@ -285,8 +595,16 @@ impl VLine {
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             name: String::default(),
             highlight: false,
-            allow_hover: true,
+            allow_hover: false,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
             style: LineStyle::Solid,
+            show_in_legend: false,
+            include_in_auto_bounds: false,
             id: None,
         }
     }
@@ -298,13 +616,56 @@ impl VLine {
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Allow hovering this item, see [`DecorationItem::allow_hover`]. Default: `false`.
     #[inline]
     pub fn allow_hover(mut self, hovering: bool) -> Self {
         self.allow_hover = hovering;
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`DecorationItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`DecorationItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`DecorationItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`DecorationItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`DecorationItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -335,7 +696,7 @@ impl VLine {
 
     /// Name of this vertical line.
     ///
-    /// This name will show up in the plot legend, if legends are turned on.
+    /// This name will show up in the plot legend if [`Self::show_in_legend`] is set.
     ///
     /// Multiple plot items may share the same name, in which case they will also share an entry in
     /// the legend.
@@ -346,6 +707,20 @@ impl VLine {
         self
     }
 
+    /// Show this item in the legend, see [`DecorationItem::show_in_legend`]. Default: `false`.
+    #[inline]
+    pub fn show_in_legend(mut self, show: bool) -> Self {
+        self.show_in_legend = show;
+        self
+    }
+
+    /// Widen the automatic bounds to include this item, see [`DecorationItem::include_in_auto_bounds`]. Default: `false`.
+    #[inline]
+    pub fn include_in_auto_bounds(mut self, include: bool) -> Self {
+        self.include_in_auto_bounds = include;
+        self
+    }
+
     /// Set the line's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
@@ -354,7 +729,7 @@ impl VLine {
     }
 }
 
-impl PlotItem for VLine {
+impl DecorationItem for VLine {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             x,
@@ -371,12 +746,14 @@ impl PlotItem for VLine {
         style.style_line(points, *stroke, *highlight, shapes);
     }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
-
     fn name(&self) -> &str {
         &self.name
     }
 
+    fn show_in_legend(&self) -> bool {
+        self.show_in_legend
+    }
+
     fn color(&self) -> Color32 {
         self.stroke.color
     }
@@ -393,33 +770,83 @@ impl PlotItem for VLine {
         self.allow_hover
     }
 
-    fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            line: Some((self.stroke, self.style)),
+            ..Default::default()
+        }
     }
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        bounds.min[0] = self.x;
-        bounds.max[0] = self.x;
+        bounds.extend_with_x(self.x);
         bounds
     }
 
+    fn include_in_auto_bounds(&self) -> bool {
+        self.include_in_auto_bounds
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        !self.x.is_finite()
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
 }
 
 /// A series of values forming a path.
+///
+/// Points with an infinite coordinate are skipped,
+/// and points with a NaN coordinate either break the line (see [`Self::gap_on_nan`]) or are skipped.
 pub struct Line {
     pub(super) series: PlotPoints,
     pub(super) stroke: Stroke,
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
+    pub(super) draggable: bool,
     pub(super) fill: Option<f32>,
     pub(super) fill_alpha: f32,
     pub(super) style: LineStyle,
+    pub(super) join: LineJoin,
+    pub(super) cap: LineCap,
+    pub(super) gap_on_nan: bool,
+    pub(super) inline_label: Option<InlineLabel>,
     id: Option<Id>,
+    data_version: Option<u64>,
+    pub(super) render_quality: RenderQuality,
 }
 
 impl Line {
@@ -430,10 +857,23 @@ impl Line {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            draggable: false,
             fill: None,
             fill_alpha: DEFAULT_FILL_ALPHA,
             style: LineStyle::Solid,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+            gap_on_nan: true,
+            inline_label: None,
             id: None,
+            data_version: None,
+            render_quality: RenderQuality::Full,
         }
     }
 
@@ -451,6 +891,60 @@ impl Line {
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Allow the user to drag the control points of this line with the primary mouse button. Default: `false`.
+    ///
+    /// The plot does not move the points itself: the dragged point and its new position are reported
+    /// in [`crate::PlotResponse::dragged_point`], and it is up to you to update your data.
+    /// While dragging a point, the plot is not panned.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -458,6 +952,16 @@ impl Line {
         self
     }
 
+    /// Draw the name of this line next to it inside the plot, as an alternative to a legend.
+    ///
+    /// The label is put on whichever side of the point covers the fewest other labels and data points.
+    /// Default: `None`.
+    #[inline]
+    pub fn inline_label(mut self, inline_label: InlineLabel) -> Self {
+        self.inline_label = Some(inline_label);
+        self
+    }
+
     /// Stroke width. A high value means the plot thickens.
     #[inline]
     pub fn width(mut self, width: impl Into<f32>) -> Self {
@@ -493,6 +997,34 @@ impl Line {
         self
     }
 
+    /// How the segments of a solid line are joined. Default is `LineJoin::Miter`.
+    ///
+    /// This matters for thick lines with sharp turns.
+    #[inline]
+    pub fn join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// How the ends of a solid line look. Default is `LineCap::Butt`.
+    #[inline]
+    pub fn cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// Break the line at points with a NaN coordinate, instead of connecting across them.
+    ///
+    /// The points around a gap are not connected, filled or hoverable,
+    /// and the gap doesn't count towards the bounds of the line.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn gap_on_nan(mut self, gap_on_nan: bool) -> Self {
+        self.gap_on_nan = gap_on_nan;
+        self
+    }
+
     /// Name of this line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -512,6 +1044,58 @@ impl Line {
         self.id = Some(id);
         self
     }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
+
+    /// The parts of the line between the gaps.
+    fn segments<'p>(&self, points: &'p [PlotPoint]) -> Vec<&'p [PlotPoint]> {
+        if self.gap_on_nan {
+            points.split(is_gap).filter(|s| !s.is_empty()).collect()
+        } else {
+            vec![points]
+        }
+    }
+
+    /// The point the inline label is drawn next to.
+    fn inline_label_anchor(&self) -> Option<PlotPoint> {
+        let segments = self.segments(self.series.points());
+        match self.inline_label? {
+            InlineLabel::AtEnd => segments
+                .last()?
+                .iter()
+                .rev()
+                .find(|point| point.is_finite())
+                .copied(),
+            InlineLabel::AtMax => segments
+                .iter()
+                .flat_map(|segment| segment.iter())
+                .filter(|point| point.is_finite())
+                .max_by(|a, b| a.y.total_cmp(&b.y))
+                .copied(),
+            InlineLabel::AtX(x) => segments
+                .iter()
+                .flat_map(|segment| segment.windows(2))
+                .find(|w| (w[0].x <= x && x <= w[1].x) || (w[1].x <= x && x <= w[0].x))
+                .map(|w| {
+                    let t = if w[0].x == w[1].x {
+                        0.0
+                    } else {
+                        (x - w[0].x) / (w[1].x - w[0].x)
+                    };
+                    PlotPoint::new(x, w[0].y + t * (w[1].y - w[0].y))
+                }),
+        }
+    }
+}
+
+/// Is this point a break in a [`Line`] with [`Line::gap_on_nan`]?
+fn is_gap(point: &PlotPoint) -> bool {
+    point.x.is_nan() || point.y.is_nan()
 }
 
 /// Returns the x-coordinate of a possible intersection between a line segment from `p1` to `p2` and
@@ -521,64 +1105,153 @@ fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
         .then_some(((y * (p1.x - p2.x)) - (p1.x * p2.y - p1.y * p2.x)) / (p1.y - p2.y))
 }
 
+/// Moves every point of the polyline to the closest point within the bounds.
+///
+/// Segments are split where they cross the edges of the bounds, so that the part of the line
+/// within the bounds is unchanged and the rest runs along the edges.
+/// The area between the line and a horizontal reference stays the same within the bounds too.
+fn clamp_polyline(
+    points: impl IntoIterator<Item = PlotPoint>,
+    bounds: &PlotBounds,
+) -> Vec<PlotPoint> {
+    let clamp = |point: PlotPoint| {
+        PlotPoint::new(
+            point.x.clamp(bounds.min[0], bounds.max[0]),
+            point.y.clamp(bounds.min[1], bounds.max[1]),
+        )
+    };
+
+    let mut clamped: Vec<PlotPoint> = Vec::new();
+    let mut push = |point: PlotPoint| {
+        if clamped.last() != Some(&point) {
+            clamped.push(point);
+        }
+    };
+    let mut previous: Option<PlotPoint> = None;
+    for point in points {
+        if let Some(from) = previous {
+            let mut crossings: Vec<f64> = [
+                (from.x, point.x, bounds.min[0]),
+                (from.x, point.x, bounds.max[0]),
+                (from.y, point.y, bounds.min[1]),
+                (from.y, point.y, bounds.max[1]),
+            ]
+            .into_iter()
+            .map(|(a, b, edge)| (edge - a) / (b - a))
+            .filter(|t| 0.0 < *t && *t < 1.0)
+            .collect();
+            crossings.sort_by(f64::total_cmp);
+            for t in crossings {
+                push(clamp(PlotPoint::new(
+                    from.x + t * (point.x - from.x),
+                    from.y + t * (point.y - from.y),
+                )));
+            }
+        }
+        push(clamp(point));
+        previous = Some(point);
+    }
+    clamped
+}
+
+/// Fills a convex polygon without the feathering used for anti-aliasing.
+///
+/// The fill is split into several meshes if it has too many vertices for a single one.
+fn add_convex_fill_meshes(points: &[Pos2], color: Color32, shapes: &mut Vec<Shape>) {
+    let Some((&first, rest)) = points.split_first() else {
+        return;
+    };
+
+    // Each mesh is a triangle fan around the first point. Consecutive fans share an edge.
+    let mut start = 0;
+    while start + 1 < rest.len() {
+        let end = (start + MAX_MESH_VERTICES - 1).at_most(rest.len());
+        let fan = &rest[start..end];
+
+        let mut mesh = Mesh::default();
+        mesh.reserve_vertices(fan.len() + 1);
+        mesh.reserve_triangles(fan.len() - 1);
+        mesh.colored_vertex(first, color);
+        for point in fan {
+            mesh.colored_vertex(*point, color);
+        }
+        for i in 1..fan.len() as u32 {
+            mesh.add_triangle(0, i, i + 1);
+        }
+        shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
+
+        start = end - 1;
+    }
+}
+
 impl PlotItem for Line {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             series,
             stroke,
             highlight,
-            mut fill,
+            fill,
             style,
             ..
         } = self;
 
-        let values_tf: Vec<_> = series
-            .points()
-            .iter()
-            .map(|v| transform.position_from_point(v))
-            .collect();
-        let n_values = values_tf.len();
-
-        // Fill the area between the line and a reference line, if required.
-        if n_values < 2 {
-            fill = None;
-        }
-        if let Some(y_reference) = fill {
-            let mut fill_alpha = self.fill_alpha;
-            if *highlight {
-                fill_alpha = (2.0 * fill_alpha).at_most(1.0);
-            }
-            let y = transform
-                .position_from_point(&PlotPoint::new(0.0, y_reference))
-                .y;
-            let fill_color = Rgba::from(stroke.color)
-                .to_opaque()
-                .multiply(fill_alpha)
-                .into();
-            let mut mesh = Mesh::default();
-            let expected_intersections = 20;
-            mesh.reserve_triangles((n_values - 1) * 2);
-            mesh.reserve_vertices(n_values * 2 + expected_intersections);
-            values_tf.windows(2).for_each(|w| {
-                let i = mesh.vertices.len() as u32;
-                mesh.colored_vertex(w[0], fill_color);
-                mesh.colored_vertex(pos2(w[0].x, y), fill_color);
-                if let Some(x) = y_intersection(&w[0], &w[1], y) {
-                    let point = pos2(x, y);
-                    mesh.colored_vertex(point, fill_color);
-                    mesh.add_triangle(i, i + 1, i + 2);
-                    mesh.add_triangle(i + 2, i + 3, i + 4);
-                } else {
-                    mesh.add_triangle(i, i + 1, i + 2);
-                    mesh.add_triangle(i + 1, i + 2, i + 3);
+        let clip_bounds = transform.clip_bounds();
+        for segment in self.segments(series.points()) {
+            let finite = segment.iter().filter(|v| v.is_finite()).copied();
+            let mut values_tf = Vec::new();
+            transform.positions_from_points(&clamp_polyline(finite, &clip_bounds), &mut values_tf);
+            let values_tf = thin_out(values_tf, self.render_quality.min_point_distance());
+            let n_values = values_tf.len();
+
+            // Fill the area between the line and a reference line, if required.
+            let fill = fill.filter(|_| n_values >= 2);
+            if let Some(y_reference) = fill {
+                let mut fill_alpha = self.fill_alpha;
+                if *highlight {
+                    fill_alpha = (2.0 * fill_alpha).at_most(1.0);
                 }
-            });
-            let last = values_tf[n_values - 1];
-            mesh.colored_vertex(last, fill_color);
-            mesh.colored_vertex(pos2(last.x, y), fill_color);
-            shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
+                let y_reference =
+                    (y_reference as f64).clamp(clip_bounds.min[1], clip_bounds.max[1]);
+                let y = transform
+                    .position_from_point(&PlotPoint::new(0.0, y_reference))
+                    .y;
+                let fill_color = Rgba::from(stroke.color)
+                    .to_opaque()
+                    .multiply(fill_alpha)
+                    .into();
+                let mut mesh = Mesh::default();
+                let expected_intersections = 20;
+                mesh.reserve_triangles((n_values - 1) * 2);
+                mesh.reserve_vertices(
+                    (n_values * 2 + expected_intersections).at_most(MAX_MESH_VERTICES),
+                );
+                values_tf.windows(2).for_each(|w| {
+                    // Each step adds at most three vertices, and refers to two more added by the next.
+                    if mesh.vertices.len() + 5 > MAX_MESH_VERTICES {
+                        mesh.colored_vertex(w[0], fill_color);
+                        mesh.colored_vertex(pos2(w[0].x, y), fill_color);
+                        shapes.push(Shape::Mesh(std::sync::Arc::new(std::mem::take(&mut mesh))));
+                    }
+                    let i = mesh.vertices.len() as u32;
+                    mesh.colored_vertex(w[0], fill_color);
+                    mesh.colored_vertex(pos2(w[0].x, y), fill_color);
+                    if let Some(x) = y_intersection(&w[0], &w[1], y) {
+                        let point = pos2(x, y);
+                        mesh.colored_vertex(point, fill_color);
+                        mesh.add_triangle(i, i + 1, i + 2);
+                        mesh.add_triangle(i + 2, i + 3, i + 4);
+                    } else {
+                        mesh.add_triangle(i, i + 1, i + 2);
+                        mesh.add_triangle(i + 1, i + 2, i + 3);
+                    }
+                });
+                let last = values_tf[n_values - 1];
+                mesh.colored_vertex(last, fill_color);
+                mesh.colored_vertex(pos2(last.x, y), fill_color);
+                shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
+            }
+            style.style_line_with(values_tf, *stroke, *highlight, self.join, self.cap, shapes);
         }
-        style.style_line(values_tf, *stroke, *highlight, shapes);
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
@@ -605,6 +1278,58 @@ impl PlotItem for Line {
         self.allow_hover
     }
 
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        let fill = self.fill.map(|_| {
+            Rgba::from(self.stroke.color)
+                .to_opaque()
+                .multiply(self.fill_alpha)
+                .into()
+        });
+        LegendIcon {
+            line: Some((self.stroke, self.style)),
+            fill,
+            ..Default::default()
+        }
+    }
+
+    fn draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn auto_placed_label(&self, ui: &Ui) -> Option<(PlotPoint, Arc<Galley>, Color32)> {
+        if self.name.is_empty() {
+            return None;
+        }
+        let anchor = self.inline_label_anchor()?;
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let galley = ui.fonts(|f| f.layout_no_wrap(self.name.clone(), font_id, self.stroke.color));
+        Some((anchor, galley, self.stroke.color))
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
@@ -613,21 +1338,64 @@ impl PlotItem for Line {
         self.series.bounds()
     }
 
+    fn has_non_finite_values(&self) -> bool {
+        self.series
+            .points()
+            .iter()
+            .filter(|point| !(self.gap_on_nan && is_gap(point)))
+            .any(|point| !point.is_finite())
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn data_version(&self) -> Option<u64> {
+        // Generated values depend on the visible range, so they can't be reused.
+        matches!(self.series, PlotPoints::Owned(_))
+            .then_some(self.data_version)
+            .flatten()
+    }
+
+    fn set_render_quality(&mut self, quality: RenderQuality) {
+        self.render_quality = quality;
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.series
+            .points()
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| value.is_finite())
+            .map(|(index, value)| {
+                let pos = transform.position_from_point(value);
+                let dist_sq = point.distance_sq(pos);
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
 }
 
 /// A convex polygon.
+///
+/// Points with a NaN or infinite coordinate are skipped.
 pub struct Polygon {
     pub(super) series: PlotPoints,
     pub(super) stroke: Stroke,
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
     pub(super) fill_color: Option<Color32>,
     pub(super) style: LineStyle,
     id: Option<Id>,
+    data_version: Option<u64>,
+    pub(super) render_quality: RenderQuality,
 }
 
 impl Polygon {
@@ -638,9 +1406,17 @@ impl Polygon {
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
             fill_color: None,
             style: LineStyle::Solid,
             id: None,
+            data_version: None,
+            render_quality: RenderQuality::Full,
         }
     }
 
@@ -659,6 +1435,49 @@ impl Polygon {
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
     /// Add a custom stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -706,6 +1525,13 @@ impl Polygon {
         self.id = Some(id);
         self
     }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
 }
 
 impl PlotItem for Polygon {
@@ -722,13 +1548,18 @@ impl PlotItem for Polygon {
         let mut values_tf: Vec<_> = series
             .points()
             .iter()
+            .filter(|v| v.is_finite())
             .map(|v| transform.position_from_point(v))
             .collect();
 
         let fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
 
-        let shape = Shape::convex_polygon(values_tf.clone(), fill_color, Stroke::NONE);
-        shapes.push(shape);
+        if self.render_quality == RenderQuality::Low {
+            add_convex_fill_meshes(&values_tf, fill_color, shapes);
+        } else {
+            let shape = Shape::convex_polygon(values_tf.clone(), fill_color, Stroke::NONE);
+            shapes.push(shape);
+        }
 
         if let Some(first) = values_tf.first() {
             values_tf.push(*first); // close the polygon
@@ -761,6 +1592,40 @@ impl PlotItem for Polygon {
         self.allow_hover
     }
 
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        let fill = self
+            .fill_color
+            .unwrap_or(self.stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
+        LegendIcon {
+            fill: Some(fill),
+            ..Default::default()
+        }
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
@@ -772,9 +1637,22 @@ impl PlotItem for Polygon {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn data_version(&self) -> Option<u64> {
+        // Generated values depend on the visible range, so they can't be reused.
+        matches!(self.series, PlotPoints::Owned(_))
+            .then_some(self.data_version)
+            .flatten()
+    }
+
+    fn set_render_quality(&mut self, quality: RenderQuality) {
+        self.render_quality = quality;
+    }
 }
 
 /// Text inside the plot.
+///
+/// The text is not shown if its position is NaN or infinite.
 #[derive(Clone)]
 pub struct Text {
     pub(super) text: WidgetText,
@@ -782,8 +1660,17 @@ pub struct Text {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
     pub(super) color: Color32,
     pub(super) anchor: Align2,
+    pub(super) auto_place: bool,
+    pub(super) show_in_legend: bool,
+    pub(super) include_in_auto_bounds: bool,
     id: Option<Id>,
 }
 
@@ -794,9 +1681,18 @@ impl Text {
             position,
             name: Default::default(),
             highlight: false,
-            allow_hover: true,
+            allow_hover: false,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
             color: Color32::TRANSPARENT,
             anchor: Align2::CENTER_CENTER,
+            auto_place: false,
+            show_in_legend: false,
+            include_in_auto_bounds: false,
             id: None,
         }
     }
@@ -808,13 +1704,56 @@ impl Text {
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Allow hovering this item, see [`DecorationItem::allow_hover`]. Default: `false`.
     #[inline]
     pub fn allow_hover(mut self, hovering: bool) -> Self {
         self.allow_hover = hovering;
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`DecorationItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`DecorationItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`DecorationItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`DecorationItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`DecorationItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
     /// Text color.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
@@ -829,9 +1768,17 @@ impl Text {
         self
     }
 
+    /// Let the plot put the text next to its position, on whichever side covers the fewest
+    /// other labels and data points, instead of using [`Self::anchor`]. Default: `false`.
+    #[inline]
+    pub fn auto_place(mut self, auto_place: bool) -> Self {
+        self.auto_place = auto_place;
+        self
+    }
+
     /// Name of this text.
     ///
-    /// This name will show up in the plot legend, if legends are turned on.
+    /// This name will show up in the plot legend if [`Self::show_in_legend`] is set.
     ///
     /// Multiple plot items may share the same name, in which case they will also share an entry in
     /// the legend.
@@ -842,6 +1789,20 @@ impl Text {
         self
     }
 
+    /// Show this item in the legend, see [`DecorationItem::show_in_legend`]. Default: `false`.
+    #[inline]
+    pub fn show_in_legend(mut self, show: bool) -> Self {
+        self.show_in_legend = show;
+        self
+    }
+
+    /// Widen the automatic bounds to include this item, see [`DecorationItem::include_in_auto_bounds`]. Default: `false`.
+    #[inline]
+    pub fn include_in_auto_bounds(mut self, include: bool) -> Self {
+        self.include_in_auto_bounds = include;
+        self
+    }
+
     /// Set the text's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
@@ -850,20 +1811,38 @@ impl Text {
     }
 }
 
-impl PlotItem for Text {
-    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let color = if self.color == Color32::TRANSPARENT {
+impl Text {
+    fn text_color(&self, ui: &Ui) -> Color32 {
+        if self.color == Color32::TRANSPARENT {
             ui.style().visuals.text_color()
         } else {
             self.color
-        };
+        }
+    }
 
-        let galley = self.text.clone().into_galley(
+    fn galley(&self, ui: &Ui) -> Arc<Galley> {
+        self.text.clone().into_galley(
             ui,
             Some(egui::TextWrapMode::Extend),
             f32::INFINITY,
             TextStyle::Small,
-        );
+        )
+    }
+}
+
+impl DecorationItem for Text {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.auto_place {
+            // Drawn by the plot, see `auto_placed_label`.
+            return;
+        }
+
+        if !self.position.is_finite() {
+            return;
+        }
+
+        let color = self.text_color(ui);
+        let galley = self.galley(ui);
 
         let pos = transform.position_from_point(&self.position);
         let rect = self.anchor.anchor_size(pos, galley.size());
@@ -880,12 +1859,14 @@ impl PlotItem for Text {
         }
     }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
-
     fn name(&self) -> &str {
         self.name.as_str()
     }
 
+    fn show_in_legend(&self) -> bool {
+        self.show_in_legend
+    }
+
     fn color(&self) -> Color32 {
         self.color
     }
@@ -902,8 +1883,33 @@ impl PlotItem for Text {
         self.allow_hover
     }
 
-    fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn auto_placed_label(&self, ui: &Ui) -> Option<(PlotPoint, Arc<Galley>, Color32)> {
+        self.auto_place
+            .then(|| (self.position, self.galley(ui), self.text_color(ui)))
     }
 
     fn bounds(&self) -> PlotBounds {
@@ -912,12 +1918,22 @@ impl PlotItem for Text {
         bounds
     }
 
+    fn include_in_auto_bounds(&self) -> bool {
+        self.include_in_auto_bounds
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        !self.position.is_finite()
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
 }
 
 /// A set of points.
+///
+/// Points with a NaN or infinite coordinate are skipped.
 pub struct Points {
     pub(super) series: PlotPoints,
 
@@ -929,17 +1945,36 @@ pub struct Points {
     /// Whether to fill the marker. Does not apply to all types.
     pub(super) filled: bool,
 
+    /// An outline around the markers, separate from their fill.
+    pub(super) outline: Option<Stroke>,
+
     /// The maximum extent of the marker from its center.
     pub(super) radius: f32,
 
+    /// Optional per-point radii, overriding `radius` (e.g. for bubble charts).
+    pub(super) radii: Option<Vec<f32>>,
+
     pub(super) name: String,
 
     pub(super) highlight: bool,
 
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
+    pub(super) draggable: bool,
 
     pub(super) stems: Option<f32>,
+
+    /// Skip the markers when there are more points than this per pixel of width.
+    pub(super) max_markers_per_pixel: Option<f32>,
+
     id: Option<Id>,
+    data_version: Option<u64>,
+    pub(super) render_quality: RenderQuality,
 }
 
 impl Points {
@@ -949,12 +1984,24 @@ impl Points {
             shape: MarkerShape::Circle,
             color: Color32::TRANSPARENT,
             filled: true,
+            outline: None,
             radius: 1.0,
+            radii: None,
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            draggable: false,
             stems: None,
+            max_markers_per_pixel: None,
             id: None,
+            data_version: None,
+            render_quality: RenderQuality::Full,
         }
     }
 
@@ -979,6 +2026,60 @@ impl Points {
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Allow the user to drag the individual points with the primary mouse button. Default: `false`.
+    ///
+    /// The plot does not move the points itself: the dragged point and its new position are reported
+    /// in [`crate::PlotResponse::dragged_point`], and it is up to you to update your data.
+    /// While dragging a point, the plot is not panned.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
     /// Set the marker's color.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
@@ -993,6 +2094,17 @@ impl Points {
         self
     }
 
+    /// Draw an outline around the markers, separate from their fill,
+    /// like a white halo that keeps dark markers visible on a busy background.
+    ///
+    /// Markers made of lines, like [`MarkerShape::Cross`], and unfilled markers get the outline
+    /// on both sides of their lines. The outline is shown in the legend too.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.outline = Some(stroke.into());
+        self
+    }
+
     /// Whether to add stems between the markers and a horizontal reference line.
     #[inline]
     pub fn stems(mut self, y_reference: impl Into<f32>) -> Self {
@@ -1000,6 +2112,18 @@ impl Points {
         self
     }
 
+    /// Skip drawing the markers while zoomed out so far that there are more than this many
+    /// visible points per physical pixel of width, or that the markers are smaller than a pixel.
+    ///
+    /// The markers would only overlap into a blur anyway, so this saves a lot of tessellation
+    /// for large series. The stems are still drawn, and the markers come back when zooming in.
+    /// Default: `None`, the markers are always drawn.
+    #[inline]
+    pub fn max_markers_per_pixel(mut self, max: f32) -> Self {
+        self.max_markers_per_pixel = Some(max);
+        self
+    }
+
     /// Set the maximum extent of the marker around its position, in ui points.
     #[inline]
     pub fn radius(mut self, radius: impl Into<f32>) -> Self {
@@ -1007,6 +2131,24 @@ impl Points {
         self
     }
 
+    /// Set the extent of each marker individually, in ui points, e.g. to create a bubble chart.
+    ///
+    /// The radii are matched to the points by index. Points without a corresponding radius
+    /// use the one set with [`Self::radius`]. Hovering respects the drawn size of each marker.
+    #[inline]
+    pub fn radii(mut self, radii: impl Into<Vec<f32>>) -> Self {
+        self.radii = Some(radii.into());
+        self
+    }
+
+    /// The radius of the marker at the given index, in ui points, ignoring highlighting.
+    fn radius_at(&self, index: usize) -> f32 {
+        self.radii
+            .as_ref()
+            .and_then(|radii| radii.get(index).copied())
+            .unwrap_or(self.radius)
+    }
+
     /// Name of this set of points.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -1026,135 +2168,212 @@ impl Points {
         self.id = Some(id);
         self
     }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
 }
 
-impl PlotItem for Points {
-    #[allow(clippy::too_many_lines)] // TODO(emilk): shorten this function
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let sqrt_3 = 3_f32.sqrt();
-        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
-        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+/// Adds the shapes of a marker, see [`Points`].
+///
+/// `line_width` is the width of the lines of markers made of lines, and of unfilled markers.
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)] // TODO(emilk): shorten this function
+pub(crate) fn add_marker(
+    shapes: &mut Vec<Shape>,
+    shape: MarkerShape,
+    center: Pos2,
+    radius: f32,
+    line_width: f32,
+    color: Color32,
+    filled: bool,
+    outline: Option<Stroke>,
+) {
+    let sqrt_3 = 3_f32.sqrt();
+    let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
+    let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+
+    let default_stroke = Stroke::new(line_width, color);
+    let (fill, stroke) = if filled {
+        (color, outline.unwrap_or(Stroke::NONE))
+    } else {
+        (Color32::TRANSPARENT, default_stroke)
+    };
+
+    // Lines get their outline from a wider line of the outline color below them.
+    let halo = outline
+        .map(|outline| Stroke::new(default_stroke.width + 2.0 * outline.width, outline.color));
+    let line_halo = halo.filter(|_| !filled);
+    let add_segments = |shapes: &mut Vec<Shape>, segments: &[[Pos2; 2]]| {
+        if let Some(halo) = halo {
+            shapes.extend(segments.iter().map(|s| Shape::line_segment(*s, halo)));
+        }
+        shapes.extend(
+            segments
+                .iter()
+                .map(|s| Shape::line_segment(*s, default_stroke)),
+        );
+    };
+    let add_polygon = |shapes: &mut Vec<Shape>, points: Vec<Pos2>| {
+        if let Some(halo) = line_halo {
+            shapes.push(Shape::convex_polygon(
+                points.clone(),
+                Color32::TRANSPARENT,
+                halo,
+            ));
+        }
+        shapes.push(Shape::convex_polygon(points, fill, stroke));
+    };
 
+    let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+
+    match shape {
+        MarkerShape::Circle => {
+            if let Some(halo) = line_halo {
+                shapes.push(Shape::circle_stroke(center, radius, halo));
+            }
+            shapes.push(Shape::Circle(CircleShape {
+                center,
+                radius,
+                fill,
+                stroke,
+            }));
+        }
+        MarkerShape::Diamond => {
+            let points = vec![
+                tf(0.0, 1.0),  // bottom
+                tf(-1.0, 0.0), // left
+                tf(0.0, -1.0), // top
+                tf(1.0, 0.0),  // right
+            ];
+            add_polygon(shapes, points);
+        }
+        MarkerShape::Square => {
+            let points = vec![
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            add_polygon(shapes, points);
+        }
+        MarkerShape::Cross => {
+            let diagonal1 = [
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            let diagonal2 = [
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            add_segments(shapes, &[diagonal1, diagonal2]);
+        }
+        MarkerShape::Plus => {
+            let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            add_segments(shapes, &[horizontal, vertical]);
+        }
+        MarkerShape::Up => {
+            let points = vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
+            add_polygon(shapes, points);
+        }
+        MarkerShape::Down => {
+            let points = vec![
+                tf(0.0, 1.0),
+                tf(-0.5 * sqrt_3, -0.5),
+                tf(0.5 * sqrt_3, -0.5),
+            ];
+            add_polygon(shapes, points);
+        }
+        MarkerShape::Left => {
+            let points = vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
+            add_polygon(shapes, points);
+        }
+        MarkerShape::Right => {
+            let points = vec![
+                tf(1.0, 0.0),
+                tf(-0.5, 0.5 * sqrt_3),
+                tf(-0.5, -0.5 * sqrt_3),
+            ];
+            add_polygon(shapes, points);
+        }
+        MarkerShape::Asterisk => {
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
+            let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
+            add_segments(shapes, &[vertical, diagonal1, diagonal2]);
+        }
+    }
+}
+
+impl PlotItem for Points {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             series,
             shape,
             color,
             filled,
-            mut radius,
+            outline,
             highlight,
             stems,
             ..
         } = self;
 
-        let stroke_size = radius / 5.0;
-
-        let default_stroke = Stroke::new(stroke_size, *color);
-        let mut stem_stroke = default_stroke;
-        let (fill, stroke) = if *filled {
-            (*color, Stroke::NONE)
-        } else {
-            (Color32::TRANSPARENT, default_stroke)
-        };
-
+        let mut stem_stroke = Stroke::new(self.radius / 5.0, *color);
         if *highlight {
-            radius *= 2f32.sqrt();
             stem_stroke.width *= 2.0;
         }
 
         let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
+        // At reduced quality, or when dense markers are skipped anyway,
+        // markers smaller than a pixel are not worth drawing.
+        let pixels_per_point = ui.ctx().pixels_per_point();
+        let min_radius =
+            if self.render_quality == RenderQuality::Full && self.max_markers_per_pixel.is_none() {
+                0.0
+            } else {
+                0.5 / pixels_per_point
+            };
+
+        let mut centers = Vec::new();
+        transform.positions_from_points(series.points(), &mut centers);
+
+        let too_dense = self.max_markers_per_pixel.is_some_and(|max| {
+            let frame = transform.frame();
+            let visible = centers.iter().filter(|pos| frame.contains(**pos)).count();
+            visible as f32 > max * frame.width() * pixels_per_point
+        });
+        let min_radius = if too_dense { f32::INFINITY } else { min_radius };
+
         series
             .points()
             .iter()
-            .map(|value| transform.position_from_point(value))
-            .for_each(|center| {
-                let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+            .zip(centers)
+            .enumerate()
+            .filter(|(_, (value, _))| value.is_finite())
+            .for_each(|(index, (_, center))| {
+                let mut radius = self.radius_at(index);
+
+                let line_width = radius / 5.0;
+                if *highlight {
+                    radius *= 2f32.sqrt();
+                }
 
                 if let Some(y) = y_reference {
                     let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
                     shapes.push(stem);
                 }
 
-                match shape {
-                    MarkerShape::Circle => {
-                        shapes.push(Shape::Circle(CircleShape {
-                            center,
-                            radius,
-                            fill,
-                            stroke,
-                        }));
-                    }
-                    MarkerShape::Diamond => {
-                        let points = vec![
-                            tf(0.0, 1.0),  // bottom
-                            tf(-1.0, 0.0), // left
-                            tf(0.0, -1.0), // top
-                            tf(1.0, 0.0),  // right
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Square => {
-                        let points = vec![
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Cross => {
-                        let diagonal1 = [
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        let diagonal2 = [
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                    MarkerShape::Plus => {
-                        let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        shapes.push(Shape::line_segment(horizontal, default_stroke));
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                    }
-                    MarkerShape::Up => {
-                        let points =
-                            vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Down => {
-                        let points = vec![
-                            tf(0.0, 1.0),
-                            tf(-0.5 * sqrt_3, -0.5),
-                            tf(0.5 * sqrt_3, -0.5),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Left => {
-                        let points =
-                            vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Right => {
-                        let points = vec![
-                            tf(1.0, 0.0),
-                            tf(-0.5, 0.5 * sqrt_3),
-                            tf(-0.5, -0.5 * sqrt_3),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Asterisk => {
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
-                        let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
+                if radius < min_radius {
+                    return;
                 }
+
+                add_marker(
+                    shapes, *shape, center, radius, line_width, *color, *filled, *outline,
+                );
             });
     }
 
@@ -1182,6 +2401,45 @@ impl PlotItem for Points {
         self.allow_hover
     }
 
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            marker: Some((self.shape, self.filled)),
+            ..Default::default()
+        }
+    }
+
+    fn draggable(&self) -> bool {
+        self.draggable
+    }
+
+    fn marker_outline(&self) -> Option<Stroke> {
+        self.outline
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
@@ -1190,12 +2448,46 @@ impl PlotItem for Points {
         self.series.bounds()
     }
 
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let highlight_factor = if self.highlight { 2f32.sqrt() } else { 1.0 };
+
+        // Measure the distance to the edge of the drawn marker, so that large markers
+        // (e.g. bubbles) can be hovered anywhere inside them.
+        self.series
+            .points()
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let pos = transform.position_from_point(value);
+                let radius = self.radius_at(index) * highlight_factor;
+                let dist = (point.distance(pos) - radius).at_least(0.0);
+                ClosestElem {
+                    index,
+                    dist_sq: dist * dist,
+                }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn data_version(&self) -> Option<u64> {
+        // Generated values depend on the visible range, so they can't be reused.
+        matches!(self.series, PlotPoints::Owned(_))
+            .then_some(self.data_version)
+            .flatten()
+    }
+
+    fn set_render_quality(&mut self, quality: RenderQuality) {
+        self.render_quality = quality;
+    }
 }
 
 /// A set of arrows.
+///
+/// Arrows with a NaN or infinite coordinate are skipped.
 pub struct Arrows {
     pub(super) origins: PlotPoints,
     pub(super) tips: PlotPoints,
@@ -1204,34 +2496,91 @@ pub struct Arrows {
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
     id: Option<Id>,
+    data_version: Option<u64>,
 }
 
-impl Arrows {
-    pub fn new(origins: impl Into<PlotPoints>, tips: impl Into<PlotPoints>) -> Self {
-        Self {
-            origins: origins.into(),
-            tips: tips.into(),
-            tip_length: None,
-            color: Color32::TRANSPARENT,
-            name: Default::default(),
-            highlight: false,
-            allow_hover: true,
-            id: None,
-        }
+impl Arrows {
+    pub fn new(origins: impl Into<PlotPoints>, tips: impl Into<PlotPoints>) -> Self {
+        Self {
+            origins: origins.into(),
+            tips: tips.into(),
+            tip_length: None,
+            color: Color32::TRANSPARENT,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            id: None,
+            data_version: None,
+        }
+    }
+
+    /// Highlight these arrows in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
     }
 
-    /// Highlight these arrows in the plot.
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
     #[inline]
-    pub fn highlight(mut self, highlight: bool) -> Self {
-        self.highlight = highlight;
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
     #[inline]
-    pub fn allow_hover(mut self, hovering: bool) -> Self {
-        self.allow_hover = hovering;
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
         self
     }
 
@@ -1268,6 +2617,13 @@ impl Arrows {
         self.id = Some(id);
         self
     }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
 }
 
 impl PlotItem for Arrows {
@@ -1285,6 +2641,7 @@ impl PlotItem for Arrows {
             .points()
             .iter()
             .zip(tips.points().iter())
+            .filter(|(origin, tip)| origin.is_finite() && tip.is_finite())
             .map(|(origin, tip)| {
                 (
                     transform.position_from_point(origin),
@@ -1339,6 +2696,30 @@ impl PlotItem for Arrows {
         self.allow_hover
     }
 
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.origins.points())
     }
@@ -1347,12 +2728,24 @@ impl PlotItem for Arrows {
         self.origins.bounds()
     }
 
+    fn has_non_finite_values(&self) -> bool {
+        (self.origins.points().iter())
+            .chain(self.tips.points())
+            .any(|point| !point.is_finite())
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn data_version(&self) -> Option<u64> {
+        self.data_version
+    }
 }
 
 /// An image in the plot.
+///
+/// The image is not shown if its position is NaN or infinite.
 #[derive(Clone)]
 pub struct PlotImage {
     pub(super) position: PlotPoint,
@@ -1362,9 +2755,18 @@ pub struct PlotImage {
     pub(crate) rotation: f64,
     pub(super) bg_fill: Color32,
     pub(super) tint: Color32,
+    pub(super) draw_oversized: bool,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) allow_highlight: bool,
+    pub(super) hover_priority: i32,
+    pub(super) legend_group: Option<String>,
+    pub(super) layer: PlotLayer,
+    pub(super) clip: bool,
+    pub(super) blend_mode: BlendMode,
     pub(super) name: String,
+    pub(super) show_in_legend: bool,
+    pub(super) include_in_auto_bounds: bool,
     id: Option<Id>,
 }
 
@@ -1379,13 +2781,22 @@ impl PlotImage {
             position: center_position,
             name: Default::default(),
             highlight: false,
-            allow_hover: true,
+            allow_hover: false,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
             texture_id: texture_id.into(),
             uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
             size: size.into(),
             rotation: 0.0,
             bg_fill: Default::default(),
             tint: Color32::WHITE,
+            draw_oversized: false,
+            show_in_legend: false,
+            include_in_auto_bounds: false,
             id: None,
         }
     }
@@ -1397,13 +2808,70 @@ impl PlotImage {
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Allow hovering this item, see [`DecorationItem::allow_hover`]. Default: `false`.
     #[inline]
     pub fn allow_hover(mut self, hovering: bool) -> Self {
         self.allow_hover = hovering;
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`DecorationItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`DecorationItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`DecorationItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`DecorationItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`DecorationItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Show this item in the legend, see [`DecorationItem::show_in_legend`]. Default: `false`.
+    #[inline]
+    pub fn show_in_legend(mut self, show: bool) -> Self {
+        self.show_in_legend = show;
+        self
+    }
+
+    /// Widen the automatic bounds to include this item, see [`DecorationItem::include_in_auto_bounds`]. Default: `false`.
+    #[inline]
+    pub fn include_in_auto_bounds(mut self, include: bool) -> Self {
+        self.include_in_auto_bounds = include;
+        self
+    }
+
     /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
     #[inline]
     pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
@@ -1425,9 +2893,19 @@ impl PlotImage {
         self
     }
 
+    /// Draw the image even if its texture is larger than the backend supports. Default: `false`.
+    ///
+    /// Some backends (like WebGL) support much smaller textures than others.
+    /// Unless this is set, such images are skipped, and a warning is logged once per texture.
+    #[inline]
+    pub fn draw_oversized(mut self, draw_oversized: bool) -> Self {
+        self.draw_oversized = draw_oversized;
+        self
+    }
+
     /// Name of this image.
     ///
-    /// This name will show up in the plot legend, if legends are turned on.
+    /// This name will show up in the plot legend if [`Self::show_in_legend`] is set.
     ///
     /// Multiple plot items may share the same name, in which case they will also share an entry in
     /// the legend.
@@ -1446,7 +2924,7 @@ impl PlotImage {
     }
 }
 
-impl PlotItem for PlotImage {
+impl DecorationItem for PlotImage {
     fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             position,
@@ -1456,9 +2934,13 @@ impl PlotItem for PlotImage {
             size,
             bg_fill,
             tint,
+            draw_oversized,
             highlight,
             ..
         } = self;
+        if !position.is_finite() {
+            return;
+        }
         let image_screen_rect = {
             let left_top = PlotPoint::new(
                 position.x - 0.5 * size.x as f64,
@@ -1474,6 +2956,32 @@ impl PlotItem for PlotImage {
         };
         let screen_rotation = -*rotation as f32;
 
+        // Some backends (like WebGL) support much smaller textures than others,
+        // so skip the image with a warning instead of silently drawing nothing.
+        if !*draw_oversized {
+            let max_texture_side = ui.input(|i| i.max_texture_side);
+            let texture_size = ui
+                .ctx()
+                .tex_manager()
+                .read()
+                .meta(*texture_id)
+                .map(|meta| meta.size);
+            if let Some([width, height]) =
+                texture_size.filter(|s| s[0].max(s[1]) > max_texture_side)
+            {
+                let warned_id = Id::new("egui_plot_oversized_image").with(texture_id);
+                let warned =
+                    ui.data_mut(|d| std::mem::replace(d.get_temp_mut_or_default(warned_id), true));
+                if !warned {
+                    log::warn!(
+                        "Skipping plot image {:?}: {width}x{height} is larger than the maximum texture side {max_texture_side}",
+                        self.name
+                    );
+                }
+                return;
+            }
+        }
+
         egui::paint_texture_at(
             ui.painter(),
             image_screen_rect,
@@ -1505,12 +3013,14 @@ impl PlotItem for PlotImage {
         }
     }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
-
     fn name(&self) -> &str {
         self.name.as_str()
     }
 
+    fn show_in_legend(&self) -> bool {
+        self.show_in_legend
+    }
+
     fn color(&self) -> Color32 {
         Color32::TRANSPARENT
     }
@@ -1527,8 +3037,28 @@ impl PlotItem for PlotImage {
         self.allow_hover
     }
 
-    fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
     }
 
     fn bounds(&self) -> PlotBounds {
@@ -1546,6 +3076,14 @@ impl PlotItem for PlotImage {
         bounds
     }
 
+    fn include_in_auto_bounds(&self) -> bool {
+        self.include_in_auto_bounds
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        !self.position.is_finite()
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
@@ -1554,6 +3092,8 @@ impl PlotItem for PlotImage {
 // ----------------------------------------------------------------------------
 
 /// A bar chart.
+///
+/// Bars with a NaN or infinite value are skipped.
 pub struct BarChart {
     pub(super) bars: Vec<Bar>,
     pub(super) default_color: Color32,
@@ -1564,7 +3104,21 @@ pub struct BarChart {
 
     highlight: bool,
     allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    hover_aggregate: bool,
+    corner_radius: f32,
+    gradient: Option<Color32>,
+
+    /// The slot of this chart and the number of slots of each category, see [`Self::grouped`].
+    dodge: Option<(usize, usize)>,
+
     id: Option<Id>,
+    data_version: Option<u64>,
 }
 
 impl BarChart {
@@ -1577,8 +3131,46 @@ impl BarChart {
             element_formatter: None,
             highlight: false,
             allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            hover_aggregate: false,
+            corner_radius: 0.0,
+            gradient: None,
+            dodge: None,
             id: None,
+            data_version: None,
+        }
+    }
+
+    /// A histogram of the samples, with a bar for the number of samples in each bin.
+    ///
+    /// Samples that are NaN or infinite are skipped.
+    /// See [`Self::density`] for normalizing the histogram.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn from_samples(name: impl ToString, samples: &[f64], bins: Bins) -> Self {
+        let (start, width, counts) = histogram(samples, bins);
+        let bars = counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| Bar::new(start + (i as f64 + 0.5) * width, count as f64).width(width))
+            .collect();
+        Self::new(bars).name(name)
+    }
+
+    /// Scale the values so that the bars have a total area of one, like a probability density.
+    #[inline]
+    pub fn density(mut self) -> Self {
+        let area: f64 = self.bars.iter().map(|bar| bar.value * bar.bar_width).sum();
+        if area != 0.0 && area.is_finite() {
+            for bar in &mut self.bars {
+                bar.value /= area;
+            }
         }
+        self
     }
 
     /// Set the default color. It is set on all elements that do not already have a specific color.
@@ -1638,6 +3230,33 @@ impl BarChart {
         self
     }
 
+    /// Set the width of all its elements in ui points, see [`Bar::width_px`].
+    #[inline]
+    pub fn width_px(mut self, width: f32) -> Self {
+        for b in &mut self.bars {
+            b.width_px = Some(width);
+        }
+        self
+    }
+
+    /// Round the corners at the tips of the bars, away from their base, with this radius in ui points.
+    ///
+    /// Default: `0.0`.
+    #[inline]
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = radius.max(0.0);
+        self
+    }
+
+    /// Fill the bars with a gradient, from their own fill color at the base to this color at the tip.
+    ///
+    /// Default: `None`, a solid fill.
+    #[inline]
+    pub fn gradient(mut self, tip_color: impl Into<Color32>) -> Self {
+        self.gradient = Some(tip_color.into());
+        self
+    }
+
     /// Highlight all plot elements.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
@@ -1652,6 +3271,49 @@ impl BarChart {
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
     /// Add a custom way to format an element.
     /// Can be used to display a set number of decimals or custom labels.
     #[inline]
@@ -1660,6 +3322,19 @@ impl BarChart {
         self
     }
 
+    /// When hovering a bar, list the values of all bar charts at the same argument, and their total.
+    ///
+    /// This is useful for stacked bar charts (see [`Self::stack_on`]),
+    /// where the hovered bar alone doesn't tell the whole story.
+    /// Replaces the [`Self::element_formatter`] in the hover label.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn hover_aggregate(mut self, hover_aggregate: bool) -> Self {
+        self.hover_aggregate = hover_aggregate;
+        self
+    }
+
     /// Stacks the bars on top of another chart.
     /// Positive values are stacked on top of other positive values.
     /// Negative values are stacked below other negative values.
@@ -1685,18 +3360,58 @@ impl BarChart {
         self
     }
 
+    /// Places the bars of the charts side by side within each category, in the order of the charts.
+    ///
+    /// The charts are expected to have their bars at the same arguments, like one bar per category.
+    /// Each category keeps the width of its bars, split evenly between the charts.
+    /// The hover label of [`Self::hover_aggregate`] shows the values of the whole category.
+    pub fn grouped(charts: Vec<Self>) -> Vec<Self> {
+        let count = charts.len();
+        charts
+            .into_iter()
+            .enumerate()
+            .map(|(slot, mut chart)| {
+                for bar in &mut chart.bars {
+                    let width = bar.bar_width / count as f64;
+                    bar.argument += (slot as f64 + 0.5) * width - bar.bar_width / 2.0;
+                    bar.bar_width = width;
+                }
+                chart.dodge = Some((slot, count));
+                chart
+            })
+            .collect()
+    }
+
+    /// The argument of the category of the bar, before it was moved by [`Self::grouped`].
+    fn category(&self, bar: &Bar) -> f64 {
+        match self.dodge {
+            Some((slot, count)) => {
+                bar.argument - (slot as f64 + 0.5) * bar.bar_width
+                    + bar.bar_width * count as f64 / 2.0
+            }
+            None => bar.argument,
+        }
+    }
+
     /// Set the bar chart's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
 }
 
 impl PlotItem for BarChart {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.bars {
-            b.add_shapes(transform, self.highlight, shapes);
+        for b in self.bars.iter().filter(|b| b.is_finite()) {
+            b.add_shapes(self, transform, self.highlight, shapes);
         }
     }
 
@@ -1724,18 +3439,53 @@ impl PlotItem for BarChart {
         self.allow_hover
     }
 
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            fill: self.bars.first().map(|bar| bar.fill),
+            ..Default::default()
+        }
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Rects
     }
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        for b in &self.bars {
+        for b in self.bars.iter().filter(|b| b.is_finite()) {
             bounds.merge(&b.bounds());
         }
         bounds
     }
 
+    fn has_non_finite_values(&self) -> bool {
+        self.bars.iter().any(|b| !b.is_finite())
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         find_closest_rect(&self.bars, point, transform)
     }
@@ -1750,16 +3500,36 @@ impl PlotItem for BarChart {
     ) {
         let bar = &self.bars[elem.index];
 
-        bar.add_shapes(plot.transform, true, shapes);
-        bar.add_rulers_and_text(self, plot, shapes, cursors);
+        bar.add_shapes(self, plot.transform, true, shapes);
+        if self.hover_aggregate {
+            let text = bar.aggregate_text(plot, self.category(bar));
+            add_rulers_and_text(bar, plot, Some(text), shapes, cursors);
+        } else {
+            bar.add_rulers_and_text(self, plot, shapes, cursors);
+        }
+    }
+
+    fn bar_values_at(&self, argument: f64) -> Vec<f64> {
+        let slots = self.dodge.map_or(1, |(_, count)| count) as f64;
+        self.bars
+            .iter()
+            .filter(|bar| (self.category(bar) - argument).abs() < slots * bar.bar_width / 2.0)
+            .map(|bar| bar.value)
+            .collect()
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn data_version(&self) -> Option<u64> {
+        self.data_version
+    }
 }
 
 /// A diagram containing a series of [`BoxElem`] elements.
+///
+/// Boxes with a NaN or infinite value are skipped.
 pub struct BoxPlot {
     pub(super) boxes: Vec<BoxElem>,
     pub(super) default_color: Color32,
@@ -1770,7 +3540,14 @@ pub struct BoxPlot {
 
     highlight: bool,
     allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
     id: Option<Id>,
+    data_version: Option<u64>,
 }
 
 impl BoxPlot {
@@ -1783,7 +3560,14 @@ impl BoxPlot {
             element_formatter: None,
             highlight: false,
             allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
             id: None,
+            data_version: None,
         }
     }
 
@@ -1851,6 +3635,49 @@ impl BoxPlot {
         self
     }
 
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
     /// Add a custom way to format an element.
     /// Can be used to display a set number of decimals or custom labels.
     #[inline]
@@ -1865,11 +3692,18 @@ impl BoxPlot {
         self.id = Some(id);
         self
     }
+
+    /// Set a version of the data, see [`PlotItem::data_version`].
+    #[inline]
+    pub fn data_version(mut self, version: u64) -> Self {
+        self.data_version = Some(version);
+        self
+    }
 }
 
 impl PlotItem for BoxPlot {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.boxes {
+        for b in self.boxes.iter().filter(|b| b.is_finite()) {
             b.add_shapes(transform, self.highlight, shapes);
         }
     }
@@ -1898,18 +3732,46 @@ impl PlotItem for BoxPlot {
         self.allow_hover
     }
 
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Rects
     }
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        for b in &self.boxes {
+        for b in self.boxes.iter().filter(|b| b.is_finite()) {
             bounds.merge(&b.bounds());
         }
         bounds
     }
 
+    fn has_non_finite_values(&self) -> bool {
+        self.boxes.iter().any(|b| !b.is_finite())
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         find_closest_rect(&self.boxes, point, transform)
     }
@@ -1931,6 +3793,10 @@ impl PlotItem for BoxPlot {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn data_version(&self) -> Option<u64> {
+        self.data_version
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -2013,7 +3879,7 @@ fn add_rulers_and_text(
 
         if show_values {
             text.push('\n');
-            text.push_str(&elem.default_values_format(plot.transform));
+            text.push_str(&elem.default_values_format(plot));
         }
 
         text
@@ -2040,6 +3906,7 @@ fn add_rulers_and_text(
 pub(super) fn rulers_at_value(
     pointer: Pos2,
     value: PlotPoint,
+    previous: Option<PlotPoint>,
     name: &str,
     plot: &PlotConfig<'_>,
     shapes: &mut Vec<Shape>,
@@ -2063,17 +3930,32 @@ pub(super) fn rulers_at_value(
         let scale = plot.transform.dvalue_dpos();
         let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
         let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+        let delta = previous
+            .filter(|previous| plot.show_y && previous.y.is_finite())
+            .map(|previous| {
+                let delta = value.y - previous.y;
+                if previous.y == 0.0 {
+                    format!("\nΔy = {delta:+.y_decimals$}")
+                } else {
+                    let percent = 100.0 * delta / previous.y.abs();
+                    format!("\nΔy = {delta:+.y_decimals$} ({percent:+.1}%)")
+                }
+            })
+            .unwrap_or_default();
+        let x = plot
+            .format_value(Axis::X, value.x)
+            .unwrap_or_else(|| format!("{:.*}", x_decimals, value.x));
+        let y = plot
+            .format_value(Axis::Y, value.y)
+            .unwrap_or_else(|| format!("{:.*}", y_decimals, value.y));
         if let Some(custom_label) = label_formatter {
             custom_label(name, &value)
         } else if plot.show_x && plot.show_y {
-            format!(
-                "{}x = {:.*}\ny = {:.*}",
-                prefix, x_decimals, value.x, y_decimals, value.y
-            )
+            format!("{prefix}x = {x}\ny = {y}{delta}")
         } else if plot.show_x {
-            format!("{}x = {:.*}", prefix, x_decimals, value.x)
+            format!("{prefix}x = {x}")
         } else if plot.show_y {
-            format!("{}y = {:.*}", prefix, y_decimals, value.y)
+            format!("{prefix}y = {y}{delta}")
         } else {
             unreachable!()
         }
@@ -2103,11 +3985,51 @@ where
     rects
         .into_iter()
         .enumerate()
+        .filter(|(_, bar)| bar.is_finite())
         .map(|(index, bar)| {
-            let bar_rect = transform.rect_from_values(&bar.bounds_min(), &bar.bounds_max());
+            let bar_rect = bar.screen_rect(transform);
             let dist_sq = bar_rect.distance_sq_to_pos(point);
 
             ClosestElem { index, dist_sq }
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[test]
+fn test_clamp_polyline() {
+    let bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]);
+    let points = [
+        PlotPoint::new(0.0, 5.0),
+        PlotPoint::new(1.0, 1e12),
+        PlotPoint::new(2.0, 5.0),
+    ];
+    let clamped = clamp_polyline(points, &bounds);
+
+    // The spike is cut off along the top edge, from where the line crosses it.
+    assert_eq!(clamped.len(), 5);
+    assert_eq!(clamped[0], points[0]);
+    assert!(clamped[1].y == 10.0 && clamped[1].x < 1e-9);
+    assert_eq!(clamped[2], PlotPoint::new(1.0, 10.0));
+    assert!(clamped[3].y == 10.0 && clamped[3].x > 2.0 - 1e-9);
+    assert_eq!(clamped[4], points[2]);
+}
+
+#[test]
+fn test_convex_fill_meshes() {
+    let points: Vec<Pos2> = (0..100_000)
+        .map(|i| Pos2::ZERO + Vec2::angled(i as f32 / 100_000.0 * std::f32::consts::TAU))
+        .collect();
+    let mut shapes = Vec::new();
+    add_convex_fill_meshes(&points, Color32::RED, &mut shapes);
+
+    assert_eq!(shapes.len(), 2);
+    let mut triangles = 0;
+    for shape in &shapes {
+        let Shape::Mesh(mesh) = shape else {
+            panic!("expected a mesh");
+        };
+        assert!(mesh.vertices.len() <= MAX_MESH_VERTICES);
+        triangles += mesh.indices.len() / 3;
+    }
+    assert_eq!(triangles, points.len() - 2);
+}
@@ -0,0 +1,501 @@
+use std::{f64::consts::TAU, ops::RangeInclusive, sync::Arc};
+
+use egui::{vec2, Align2, Color32, Id, Mesh, Shape, Stroke, TextStyle, Ui};
+
+use super::{
+    BlendMode, ClosestElem, PlotConfig, PlotGeometry, PlotItem, PlotLayer, PlotPoint,
+    DEFAULT_FILL_ALPHA, MAX_MESH_VERTICES,
+};
+use crate::{color_from_strength, Cursor, LabelFormatter, PlotBounds, PlotTransform};
+
+/// A radar (or spider) chart: named axes radiating from the origin,
+/// and one closed polygon per series through the values on the axes.
+///
+/// The axes go from zero at the origin to [`Self::max`] at the outer ring, clockwise from the top.
+/// Each series has its own legend entry, and hovering a corner shows the axis and value.
+/// Values that are NaN or infinite are skipped.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, Radar, RadarSeries};
+///
+/// Plot::new("radar").data_aspect(1.0).show(ui, |plot_ui| {
+///     let radar = Radar::new(["speed", "power", "range", "comfort", "price"])
+///         .series(RadarSeries::new("A", vec![4.0, 3.0, 5.0, 2.0, 3.0]))
+///         .series(RadarSeries::new("B", vec![2.0, 5.0, 3.0, 4.0, 4.0]));
+///     plot_ui.radar(radar);
+/// });
+/// # });
+/// ```
+pub struct Radar {
+    pub(crate) axes: Arc<Vec<String>>,
+    series: Vec<RadarSeries>,
+    max: Option<f64>,
+    rings: usize,
+}
+
+impl Radar {
+    pub fn new(axes: impl IntoIterator<Item = impl ToString>) -> Self {
+        Self {
+            axes: Arc::new(axes.into_iter().map(|axis| axis.to_string()).collect()),
+            series: Vec::new(),
+            max: None,
+            rings: 4,
+        }
+    }
+
+    /// Add a series, with one value per axis.
+    #[inline]
+    pub fn series(mut self, series: RadarSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// The value at the outer ring. Default: the largest value of all series.
+    #[inline]
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// The number of rings, evenly spaced between the origin and [`Self::max`]. Default: `4`.
+    #[inline]
+    pub fn rings(mut self, rings: usize) -> Self {
+        self.rings = rings.max(1);
+        self
+    }
+
+    /// The grid, and one item for each series.
+    pub(crate) fn into_items(self) -> (RadarGrid, Vec<RadarArea>) {
+        let Self {
+            axes,
+            series,
+            max,
+            rings,
+        } = self;
+
+        let max = max
+            .unwrap_or_else(|| {
+                series
+                    .iter()
+                    .flat_map(|series| series.values.iter().copied())
+                    .filter(|value| value.is_finite())
+                    .fold(0.0, f64::max)
+            })
+            .abs();
+        let max = if max > 0.0 && max.is_finite() {
+            max
+        } else {
+            1.0
+        };
+
+        let areas = series
+            .into_iter()
+            .map(|series| {
+                let points = (0..axes.len())
+                    .map(|i| {
+                        let value = series.values.get(i).copied().unwrap_or(f64::NAN);
+                        let radius = (value / max).max(0.0);
+                        if value.is_finite() {
+                            corner(i, axes.len(), radius)
+                        } else {
+                            PlotPoint::new(f64::NAN, f64::NAN)
+                        }
+                    })
+                    .collect();
+                RadarArea {
+                    axes: axes.clone(),
+                    points,
+                    series,
+                }
+            })
+            .collect();
+
+        (RadarGrid { axes, max, rings }, areas)
+    }
+}
+
+/// One series of a [`Radar`] chart.
+pub struct RadarSeries {
+    pub(crate) name: String,
+    values: Vec<f64>,
+    pub(crate) color: Color32,
+    width: f32,
+    fill: bool,
+    fill_alpha: f32,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    id: Option<Id>,
+}
+
+impl RadarSeries {
+    /// A series with one value for each axis of the chart, in the same order.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: impl ToString, values: Vec<f64>) -> Self {
+        Self {
+            name: name.to_string(),
+            values,
+            color: Color32::TRANSPARENT,
+            width: 1.5,
+            fill: true,
+            fill_alpha: DEFAULT_FILL_ALPHA,
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            id: None,
+        }
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Stroke width. Default: `1.5`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Fill the area of the series with its color. Default: `true`.
+    #[inline]
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Set the fill area's alpha channel. Default: `0.05`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Highlight this series in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this series in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this series when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Set the series' id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+/// The point at the given relative radius on axis `i` of `n`, clockwise from the top.
+fn corner(i: usize, n: usize, radius: f64) -> PlotPoint {
+    let angle = TAU / 4.0 - i as f64 * TAU / n as f64;
+    PlotPoint::new(radius * angle.cos(), radius * angle.sin())
+}
+
+/// The axes, rings and labels of a [`Radar`] chart.
+pub(crate) struct RadarGrid {
+    axes: Arc<Vec<String>>,
+    max: f64,
+    rings: usize,
+}
+
+impl PlotItem for RadarGrid {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let n = self.axes.len();
+        let center = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+        let stroke = Stroke::new(1.0, color_from_strength(ui, 0.5));
+        let font_id = TextStyle::Small.resolve(ui.style());
+
+        for ring in 1..=self.rings {
+            let radius = ring as f64 / self.rings as f64;
+            let points = (0..n)
+                .map(|i| transform.position_from_point(&corner(i, n, radius)))
+                .collect();
+            shapes.push(Shape::closed_line(points, stroke));
+
+            let text = emath::format_with_decimals_in_range(radius * self.max, 0..=3);
+            let pos = transform.position_from_point(&corner(0, n, radius));
+            shapes.push(ui.fonts(|f| {
+                Shape::text(
+                    f,
+                    pos + vec2(3.0, 0.0),
+                    Align2::LEFT_BOTTOM,
+                    text,
+                    font_id.clone(),
+                    ui.visuals().weak_text_color(),
+                )
+            }));
+        }
+
+        for (i, axis) in self.axes.iter().enumerate() {
+            let end = corner(i, n, 1.0);
+            let end_pos = transform.position_from_point(&end);
+            shapes.push(Shape::line_segment([center, end_pos], stroke));
+
+            // Put the label outside of the chart, next to the end of the axis.
+            let align = |d: f64| match d {
+                d if d > 0.3 => egui::Align::Min,
+                d if d < -0.3 => egui::Align::Max,
+                _ => egui::Align::Center,
+            };
+            let anchor = Align2([align(end.x), align(-end.y)]);
+            let offset = vec2(end.x as f32, -end.y as f32) * 6.0;
+            shapes.push(ui.fonts(|f| {
+                Shape::text(
+                    f,
+                    end_pos + offset,
+                    anchor,
+                    axis,
+                    font_id.clone(),
+                    ui.visuals().text_color(),
+                )
+            }));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        ""
+    }
+
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    fn highlight(&mut self) {}
+
+    fn highlighted(&self) -> bool {
+        false
+    }
+
+    fn allow_hover(&self) -> bool {
+        false
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        // Leave some room for the axis labels.
+        PlotBounds::from_min_max([-1.2, -1.2], [1.2, 1.2])
+    }
+
+    fn id(&self) -> Option<Id> {
+        None
+    }
+}
+
+/// A series of a [`Radar`] chart, placed on its axes.
+pub(crate) struct RadarArea {
+    axes: Arc<Vec<String>>,
+    points: Vec<PlotPoint>,
+    pub(crate) series: RadarSeries,
+}
+
+impl PlotItem for RadarArea {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let RadarSeries {
+            color,
+            width,
+            fill,
+            fill_alpha,
+            highlight,
+            ..
+        } = self.series;
+
+        let points: Vec<_> = self
+            .points
+            .iter()
+            .filter(|point| point.is_finite())
+            .map(|point| transform.position_from_point(point))
+            .collect();
+
+        if fill {
+            // The area is star-shaped around the origin, so it can be filled as a fan from there.
+            let fill_alpha = if highlight {
+                2.0 * fill_alpha
+            } else {
+                fill_alpha
+            };
+            let fill_color = color.linear_multiply(fill_alpha);
+            let center = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+            let mut mesh = Mesh::default();
+            for (i, point) in points.iter().enumerate() {
+                if mesh.vertices.len() + 3 > MAX_MESH_VERTICES {
+                    shapes.push(Shape::mesh(std::mem::take(&mut mesh)));
+                }
+                let next = points[(i + 1) % points.len()];
+                let index = mesh.vertices.len() as u32;
+                mesh.colored_vertex(center, fill_color);
+                mesh.colored_vertex(*point, fill_color);
+                mesh.colored_vertex(next, fill_color);
+                mesh.add_triangle(index, index + 1, index + 2);
+            }
+            shapes.push(Shape::mesh(mesh));
+        }
+
+        let width = if highlight { 2.0 * width } else { width };
+        shapes.push(Shape::closed_line(points, Stroke::new(width, color)));
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.series.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.series.color
+    }
+
+    fn highlight(&mut self) {
+        self.series.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.series.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.series.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.series.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.series.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.series.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.series.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.series.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.series.blend_mode
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.points)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in &self.points {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.series.id
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+        let pos = plot.transform.position_from_point(&self.points[elem.index]);
+        shapes.push(Shape::circle_filled(pos, 3.0, self.series.color));
+
+        let mut text = self.series.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "{}: {}",
+            self.axes[elem.index], self.series.values[elem.index]
+        ));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
@@ -1,9 +1,9 @@
 use egui::emath::NumExt as _;
-use egui::epaint::{Color32, Rgba, Stroke};
+use egui::epaint::{Color32, Rect, Rgba, Stroke};
 
 use crate::transform::{PlotBounds, PlotTransform};
 
-use super::{Orientation, PlotPoint};
+use super::{Orientation, PlotConfig, PlotPoint};
 
 /// Trait that abstracts from rectangular 'Value'-like elements, such as bars or boxes
 pub(super) trait RectElement {
@@ -13,6 +13,11 @@ pub(super) trait RectElement {
 
     fn bounds_max(&self) -> PlotPoint;
 
+    /// The rectangle of the element on the screen.
+    fn screen_rect(&self, transform: &PlotTransform) -> Rect {
+        transform.rect_from_values(&self.bounds_min(), &self.bounds_max())
+    }
+
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
         bounds.extend_with(&self.bounds_min());
@@ -20,6 +25,11 @@ pub(super) trait RectElement {
         bounds
     }
 
+    /// Elements with a NaN or infinite value are not shown.
+    fn is_finite(&self) -> bool {
+        self.bounds_min().is_finite() && self.bounds_max().is_finite()
+    }
+
     /// At which argument (input; usually X) there is a ruler (usually vertical)
     fn arguments_with_ruler(&self) -> Vec<PlotPoint> {
         // Default: one at center
@@ -50,7 +60,7 @@ pub(super) trait RectElement {
     }
 
     /// Debug formatting for hovered-over value, if none is specified by the user
-    fn default_values_format(&self, transform: &PlotTransform) -> String;
+    fn default_values_format(&self, plot: &PlotConfig<'_>) -> String;
 }
 
 // ----------------------------------------------------------------------------
@@ -0,0 +1,283 @@
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Id, Rect, Shape, Stroke, Ui};
+
+use super::{DecorationItem, LineStyle, PlotPoint};
+use crate::{Axis, LegendIcon, PlotBounds, PlotTransform};
+
+/// Lines or bands repeating at a fixed period, like every midnight, every weekend or every
+/// 100th sample.
+///
+/// Only the occurrences within the view are computed each frame, so there is no need to
+/// add a [`crate::VLine`] for each of them. Nothing is drawn when more than
+/// [`Self::max_count`] of them would be in view.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, RecurringLines};
+///
+/// const DAY: f64 = 24.0 * 60.0 * 60.0;
+/// Plot::new("schedule").show(ui, |plot_ui| {
+///     // Shade the weekends, given that the time starts on a Monday.
+///     plot_ui.recurring_lines(RecurringLines::vertical(7.0 * DAY).offset(5.0 * DAY).span(2.0 * DAY));
+///     plot_ui.recurring_lines(RecurringLines::vertical(DAY).name("midnight"));
+/// });
+/// # });
+/// ```
+pub struct RecurringLines {
+    axis: Axis,
+    period: f64,
+    offset: f64,
+    span: f64,
+    max_count: usize,
+    pub(crate) stroke: Stroke,
+    style: LineStyle,
+    fill_alpha: f32,
+    pub(crate) name: String,
+    highlight: bool,
+    show_in_legend: bool,
+    id: Option<Id>,
+}
+
+impl RecurringLines {
+    /// Vertical lines at every multiple of the period along the x-axis.
+    pub fn vertical(period: f64) -> Self {
+        Self::new(Axis::X, period)
+    }
+
+    /// Horizontal lines at every multiple of the period along the y-axis.
+    pub fn horizontal(period: f64) -> Self {
+        Self::new(Axis::Y, period)
+    }
+
+    fn new(axis: Axis, period: f64) -> Self {
+        Self {
+            axis,
+            period: period.abs(),
+            offset: 0.0,
+            span: 0.0,
+            max_count: 1000,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            style: LineStyle::Solid,
+            fill_alpha: 0.15,
+            name: String::new(),
+            highlight: false,
+            show_in_legend: false,
+            id: None,
+        }
+    }
+
+    /// Shift the lines by this much from the multiples of the period. Default: `0.0`.
+    #[inline]
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Draw bands of this width starting at each occurrence instead of lines. Default: `0.0`.
+    #[inline]
+    pub fn span(mut self, span: f64) -> Self {
+        self.span = span.abs();
+        self
+    }
+
+    /// Draw nothing when more occurrences than this are in view. Default: `1000`.
+    #[inline]
+    pub fn max_count(mut self, max_count: usize) -> Self {
+        self.max_count = max_count;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the line's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the alpha channel of the bands, see [`Self::span`]. Default: `0.15`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Name of these lines.
+    ///
+    /// This name will show up in the plot legend if [`Self::show_in_legend`] is set.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight these lines in the plot by scaling up the lines.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Show this item in the legend, see [`DecorationItem::show_in_legend`]. Default: `false`.
+    #[inline]
+    pub fn show_in_legend(mut self, show: bool) -> Self {
+        self.show_in_legend = show;
+        self
+    }
+
+    /// Set the item's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+/// The starts of the occurrences that overlap the range, or `None` if there are more than `max_count`.
+fn occurrences(
+    period: f64,
+    offset: f64,
+    span: f64,
+    range: RangeInclusive<f64>,
+    max_count: usize,
+) -> Option<Vec<f64>> {
+    if period <= 0.0 || !period.is_finite() || !offset.is_finite() {
+        return Some(Vec::new());
+    }
+    let first = ((range.start() - span - offset) / period).ceil();
+    let last = ((range.end() - offset) / period).floor();
+    if !first.is_finite() || !last.is_finite() {
+        return Some(Vec::new());
+    }
+    if last - first + 1.0 > max_count as f64 {
+        return None;
+    }
+    Some(
+        (first as i64..=last as i64)
+            .map(|i| offset + i as f64 * period)
+            .collect(),
+    )
+}
+
+impl DecorationItem for RecurringLines {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let bounds = transform.bounds();
+        let (range, cross) = match self.axis {
+            Axis::X => (bounds.range_x(), bounds.range_y()),
+            Axis::Y => (bounds.range_y(), bounds.range_x()),
+        };
+        let Some(starts) = occurrences(self.period, self.offset, self.span, range, self.max_count)
+        else {
+            return;
+        };
+
+        let point = |along: f64, across: f64| match self.axis {
+            Axis::X => PlotPoint::new(along, across),
+            Axis::Y => PlotPoint::new(across, along),
+        };
+        let fill_alpha = if self.highlight {
+            2.0 * self.fill_alpha
+        } else {
+            self.fill_alpha
+        };
+        let fill = self.stroke.color.linear_multiply(fill_alpha);
+
+        for start in starts {
+            if self.span > 0.0 {
+                let rect = Rect::from_two_pos(
+                    transform.position_from_point(&point(start, *cross.start())),
+                    transform.position_from_point(&point(start + self.span, *cross.end())),
+                );
+                shapes.push(Shape::rect_filled(rect, 0.0, fill));
+            }
+            let line = vec![
+                transform.position_from_point(&point(start, *cross.start())),
+                transform.position_from_point(&point(start, *cross.end())),
+            ];
+            self.style
+                .style_line(line, self.stroke, self.highlight, shapes);
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn show_in_legend(&self) -> bool {
+        self.show_in_legend
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        false
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            line: Some((self.stroke, self.style)),
+            ..Default::default()
+        }
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        // The lines repeat forever, so they don't take part in the automatic bounds.
+        PlotBounds::NOTHING
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+}
+
+#[test]
+fn test_occurrences() {
+    assert_eq!(
+        occurrences(2.0, 1.0, 0.0, 0.0..=6.0, 100),
+        Some(vec![1.0, 3.0, 5.0])
+    );
+
+    // Bands that start before the range but reach into it are included.
+    assert_eq!(
+        occurrences(7.0, 5.0, 2.0, 13.0..=20.0, 100),
+        Some(vec![12.0, 19.0])
+    );
+
+    assert_eq!(occurrences(1.0, 0.0, 0.0, 0.0..=1e6, 100), None);
+    assert_eq!(occurrences(0.0, 0.0, 0.0, 0.0..=1.0, 100), Some(vec![]));
+}
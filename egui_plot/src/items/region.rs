@@ -0,0 +1,438 @@
+use std::ops::RangeInclusive;
+
+use egui::{vec2, Align2, Color32, Id, Pos2, Rect, Shape, Stroke, TextStyle, Ui};
+
+use super::{BlendMode, ClosestElem, DecorationItem, LineStyle, PlotConfig, PlotLayer, PlotPoint};
+use crate::{Cursor, LabelFormatter, LegendIcon, PlotBounds, PlotTransform};
+
+/// A shaded rectangle spanning an x-range and a y-range, like a region of interest.
+///
+/// Either end of either range may be infinite, so a region can span the full width
+/// or height of the plot, or cover everything right of some x-value.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, Region};
+///
+/// Plot::new("regions").show(ui, |plot_ui| {
+///     plot_ui.region(
+///         Region::new(1.0..=2.0, 0.0..=f64::INFINITY)
+///             .label("peak")
+///             .hover_text("Where the signal peaks"),
+///     );
+///     plot_ui.region(Region::new(f64::NEG_INFINITY..=f64::INFINITY, -1.0..=1.0).name("normal"));
+/// });
+/// # });
+/// ```
+pub struct Region {
+    x_range: RangeInclusive<f64>,
+    y_range: RangeInclusive<f64>,
+    pub(crate) stroke: Stroke,
+    style: LineStyle,
+    fill_alpha: f32,
+    label: Option<String>,
+    hover_text: Option<String>,
+    pub(crate) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    show_in_legend: bool,
+    include_in_auto_bounds: bool,
+    id: Option<Id>,
+}
+
+impl Region {
+    pub fn new(x_range: RangeInclusive<f64>, y_range: RangeInclusive<f64>) -> Self {
+        Self {
+            x_range,
+            y_range,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            style: LineStyle::Solid,
+            fill_alpha: 0.15,
+            label: None,
+            hover_text: None,
+            name: String::new(),
+            highlight: false,
+            allow_hover: false,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            show_in_legend: false,
+            include_in_auto_bounds: false,
+            id: None,
+        }
+    }
+
+    /// Color of the border and the fill. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// The stroke of the border. A width of zero hides the border.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Set the border's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the fill's alpha channel. Default: `0.15`.
+    #[inline]
+    pub fn fill_alpha(mut self, alpha: impl Into<f32>) -> Self {
+        self.fill_alpha = alpha.into();
+        self
+    }
+
+    /// Text shown in the top left corner of the region.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Text shown when the border of the region is hovered, like a description of what it means.
+    /// This also allows hovering the region, see [`Self::allow_hover`].
+    ///
+    /// Default: the name of the region and its ranges.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn hover_text(mut self, text: impl ToString) -> Self {
+        self.hover_text = Some(text.to_string());
+        self.allow_hover = true;
+        self
+    }
+
+    /// Name of this region.
+    ///
+    /// This name will show up in the plot legend if [`Self::show_in_legend`] is set,
+    /// and in the hover label.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this region in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allow hovering this item, see [`DecorationItem::allow_hover`]. Default: `false`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`DecorationItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`DecorationItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`DecorationItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`DecorationItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`DecorationItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Show this item in the legend, see [`DecorationItem::show_in_legend`]. Default: `false`.
+    #[inline]
+    pub fn show_in_legend(mut self, show: bool) -> Self {
+        self.show_in_legend = show;
+        self
+    }
+
+    /// Widen the automatic bounds to include this item, see [`DecorationItem::include_in_auto_bounds`]. Default: `false`.
+    #[inline]
+    pub fn include_in_auto_bounds(mut self, include: bool) -> Self {
+        self.include_in_auto_bounds = include;
+        self
+    }
+
+    /// Set the region's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The region on the screen, with infinite sides at the edges of the view.
+    fn rect(&self, transform: &PlotTransform) -> Rect {
+        let clip = transform.clip_bounds();
+        let clamp = |value: f64, axis: usize| value.clamp(clip.min()[axis], clip.max()[axis]);
+        let min = PlotPoint::new(
+            clamp(*self.x_range.start(), 0),
+            clamp(*self.y_range.start(), 1),
+        );
+        let max = PlotPoint::new(clamp(*self.x_range.end(), 0), clamp(*self.y_range.end(), 1));
+        transform.rect_from_values(&min, &max)
+    }
+
+    /// The edges of the region on the screen, without the ones at infinity.
+    fn edges(&self, transform: &PlotTransform) -> Vec<[Pos2; 2]> {
+        let rect = self.rect(transform);
+        let (x_start, x_end) = (self.x_range.start(), self.x_range.end());
+        let (y_start, y_end) = (self.y_range.start(), self.y_range.end());
+
+        // The screen y-axis points down, and either range may be given in reverse.
+        let (left, right) = if x_start <= x_end {
+            (x_start, x_end)
+        } else {
+            (x_end, x_start)
+        };
+        let (bottom, top) = if y_start <= y_end {
+            (y_start, y_end)
+        } else {
+            (y_end, y_start)
+        };
+        [
+            (left, [rect.left_top(), rect.left_bottom()]),
+            (right, [rect.right_top(), rect.right_bottom()]),
+            (top, [rect.left_top(), rect.right_top()]),
+            (bottom, [rect.left_bottom(), rect.right_bottom()]),
+        ]
+        .into_iter()
+        .filter(|(value, _)| value.is_finite())
+        .map(|(_, edge)| edge)
+        .collect()
+    }
+}
+
+impl DecorationItem for Region {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.has_non_finite_values() {
+            return;
+        }
+
+        let rect = self.rect(transform);
+        let fill_alpha = if self.highlight {
+            2.0 * self.fill_alpha
+        } else {
+            self.fill_alpha
+        };
+        shapes.push(Shape::rect_filled(
+            rect,
+            0.0,
+            self.stroke.color.linear_multiply(fill_alpha),
+        ));
+
+        if self.stroke.width > 0.0 {
+            for edge in self.edges(transform) {
+                self.style
+                    .style_line(edge.to_vec(), self.stroke, self.highlight, shapes);
+            }
+        }
+
+        if let Some(label) = &self.label {
+            let font_id = TextStyle::Small.resolve(ui.style());
+            let pos = rect.intersect(*transform.frame()).left_top() + vec2(3.0, 2.0);
+            shapes.push(ui.fonts(|f| {
+                Shape::text(
+                    f,
+                    pos,
+                    Align2::LEFT_TOP,
+                    label,
+                    font_id,
+                    ui.visuals().text_color(),
+                )
+            }));
+        }
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn show_in_legend(&self) -> bool {
+        self.show_in_legend
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            fill: Some(self.stroke.color.linear_multiply(self.fill_alpha)),
+            ..Default::default()
+        }
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with_x(*self.x_range.start());
+        bounds.extend_with_x(*self.x_range.end());
+        bounds.extend_with_y(*self.y_range.start());
+        bounds.extend_with_y(*self.y_range.end());
+        bounds
+    }
+
+    fn include_in_auto_bounds(&self) -> bool {
+        self.include_in_auto_bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn has_non_finite_values(&self) -> bool {
+        self.x_range.start().is_nan()
+            || self.x_range.end().is_nan()
+            || self.y_range.start().is_nan()
+            || self.y_range.end().is_nan()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        // Hovering the border, since the inside is usually covered by other items.
+        self.edges(transform)
+            .iter()
+            .map(|[a, b]| {
+                let t = ((point - *a).dot(*b - *a) / (*b - *a).length_sq()).clamp(0.0, 1.0);
+                a.lerp(*b, if t.is_finite() { t } else { 0.0 })
+                    .distance_sq(point)
+            })
+            .min_by(|a, b| a.total_cmp(b))
+            .map(|dist_sq| ClosestElem { index: 0, dist_sq })
+    }
+
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+        let rect = self.rect(plot.transform);
+        shapes.push(Shape::rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(2.0, plot.ui.visuals().strong_text_color()),
+            egui::StrokeKind::Inside,
+        ));
+
+        let text = if let Some(hover_text) = &self.hover_text {
+            hover_text.clone()
+        } else {
+            let mut text = self.name.clone();
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&format!(
+                "x: {} to {}\ny: {} to {}",
+                self.x_range.start(),
+                self.x_range.end(),
+                self.y_range.start(),
+                self.y_range.end()
+            ));
+            text
+        };
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        let pos = plot
+            .ui
+            .input(|i| i.pointer.hover_pos())
+            .unwrap_or(rect.center());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
@@ -1,48 +1,23 @@
 use std::ops::{Bound, RangeBounds, RangeInclusive};
 
-use egui::{lerp, Pos2, Shape, Stroke, Vec2};
+use egui::{epaint::ColorMode, lerp, Color32, NumExt as _, Pos2, Shape, Stroke};
 
-use crate::transform::PlotBounds;
+use crate::{data::PlotPoint, transform::PlotBounds};
 
-/// A point coordinate in the plot.
-///
-/// Uses f64 for improved accuracy to enable plotting
-/// large values (e.g. unix time on x axis).
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct PlotPoint {
-    /// This is often something monotonically increasing, such as time, but doesn't have to be.
-    /// Goes from left to right.
-    pub x: f64,
-
-    /// Goes from bottom to top (inverse of everything else in egui!).
-    pub y: f64,
-}
-
-impl From<[f64; 2]> for PlotPoint {
-    #[inline]
-    fn from([x, y]: [f64; 2]) -> Self {
-        Self { x, y }
-    }
-}
+// ----------------------------------------------------------------------------
 
-impl PlotPoint {
-    #[inline(always)]
-    pub fn new(x: impl Into<f64>, y: impl Into<f64>) -> Self {
-        Self {
-            x: x.into(),
-            y: y.into(),
-        }
-    }
+/// Where to draw the name of a [`super::Line`] inside the plot, see [`super::Line::inline_label`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum InlineLabel {
+    /// Next to the last point of the line.
+    AtEnd,
 
-    #[inline(always)]
-    pub fn to_pos2(self) -> Pos2 {
-        Pos2::new(self.x as f32, self.y as f32)
-    }
+    /// Next to the point with the largest y-value.
+    AtMax,
 
-    #[inline(always)]
-    pub fn to_vec2(self) -> Vec2 {
-        Vec2::new(self.x as f32, self.y as f32)
-    }
+    /// Next to the line at the given x-value.
+    AtX(f64),
 }
 
 // ----------------------------------------------------------------------------
@@ -56,6 +31,42 @@ pub enum LineStyle {
     Dashed { length: f32 },
 }
 
+/// How the segments of a thick solid line are joined, see [`crate::Line::join`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineJoin {
+    /// Extend the outer edges until they meet, or cut them off like [`Self::Bevel`] for very sharp angles.
+    #[default]
+    Miter,
+
+    /// A circle around the corner.
+    Round,
+
+    /// Connect the outer edges with a straight line.
+    Bevel,
+}
+
+/// How the ends of a thick solid line look, see [`crate::Line::cap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineCap {
+    /// The line ends at its end points.
+    #[default]
+    Butt,
+
+    /// A half circle around the end points.
+    Round,
+
+    /// The line continues for half its width past the end points.
+    Square,
+}
+
+/// Above this width, solid lines are drawn segment by segment with [`LineJoin`]s between them.
+const THICK_LINE_WIDTH: f32 = 4.0;
+
+/// How far the tip of a [`LineJoin::Miter`] may be from the corner, in half line widths.
+const MITER_LIMIT: f32 = 4.0;
+
 impl LineStyle {
     pub fn dashed_loose() -> Self {
         Self::Dashed { length: 10.0 }
@@ -73,11 +84,31 @@ impl LineStyle {
         Self::Dotted { spacing: 5.0 }
     }
 
-    pub(super) fn style_line(
+    pub(crate) fn style_line(
+        &self,
+        line: Vec<Pos2>,
+        stroke: Stroke,
+        highlight: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        self.style_line_with(
+            line,
+            stroke,
+            highlight,
+            LineJoin::Miter,
+            LineCap::Butt,
+            shapes,
+        );
+    }
+
+    /// Like [`Self::style_line`], with the given join and cap for solid lines.
+    pub(super) fn style_line_with(
         &self,
         line: Vec<Pos2>,
         mut stroke: Stroke,
         highlight: bool,
+        join: LineJoin,
+        cap: LineCap,
         shapes: &mut Vec<Shape>,
     ) {
         match line.len() {
@@ -95,7 +126,14 @@ impl LineStyle {
                         if highlight {
                             stroke.width *= 2.0;
                         }
-                        shapes.push(Shape::line(line, stroke));
+                        if stroke.width > THICK_LINE_WIDTH
+                            || join != LineJoin::Miter
+                            || cap != LineCap::Butt
+                        {
+                            add_thick_line(&line, stroke, join, cap, shapes);
+                        } else {
+                            shapes.push(Shape::line(line, stroke));
+                        }
                     }
                     Self::Dotted { spacing } => {
                         // Take the stroke width for the radius even though it's not "correct", otherwise
@@ -124,6 +162,69 @@ impl LineStyle {
     }
 }
 
+/// Draws a solid line as separate segments, with the given shapes at the joins and ends.
+///
+/// Each part is anti-aliased on its own, so colors with transparency are darker where they overlap.
+fn add_thick_line(
+    line: &[Pos2],
+    stroke: Stroke,
+    join: LineJoin,
+    cap: LineCap,
+    shapes: &mut Vec<Shape>,
+) {
+    let mut line = line.to_vec();
+    line.dedup();
+    let half_width = stroke.width / 2.0;
+    let n = line.len();
+    if n < 2 {
+        if let Some(point) = line.first() {
+            shapes.push(Shape::circle_filled(*point, half_width, stroke.color));
+        }
+        return;
+    }
+
+    match cap {
+        LineCap::Butt => {}
+        LineCap::Round => {
+            shapes.push(Shape::circle_filled(line[0], half_width, stroke.color));
+            shapes.push(Shape::circle_filled(line[n - 1], half_width, stroke.color));
+        }
+        LineCap::Square => {
+            let start = half_width * (line[1] - line[0]).normalized();
+            let end = half_width * (line[n - 1] - line[n - 2]).normalized();
+            line[0] -= start;
+            line[n - 1] += end;
+        }
+    }
+
+    for segment in line.windows(2) {
+        shapes.push(Shape::line_segment([segment[0], segment[1]], stroke));
+    }
+
+    for w in line.windows(3) {
+        let (corner, d0, d1) = (w[1], (w[1] - w[0]).normalized(), (w[2] - w[1]).normalized());
+        if join == LineJoin::Round {
+            shapes.push(Shape::circle_filled(corner, half_width, stroke.color));
+            continue;
+        }
+
+        // The offsets to the outer edges of both segments, away from the turn.
+        let n0 = d0.rot90();
+        let n1 = d1.rot90();
+        let o0 = half_width * if n0.dot(d1) < 0.0 { n0 } else { -n0 };
+        let o1 = half_width * if n1.dot(d0) > 0.0 { n1 } else { -n1 };
+
+        let mid = (o0 + o1) / 2.0;
+        let miter = mid * (half_width * half_width / mid.length_sq().at_least(f32::EPSILON));
+        let points = if join == LineJoin::Miter && miter.length() <= MITER_LIMIT * half_width {
+            vec![corner, corner + o0, corner + miter, corner + o1]
+        } else {
+            vec![corner, corner + o0, corner + o1]
+        };
+        shapes.push(Shape::convex_polygon(points, stroke.color, Stroke::NONE));
+    }
+}
+
 impl std::fmt::Display for LineStyle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -151,6 +252,98 @@ impl Default for Orientation {
 
 // ----------------------------------------------------------------------------
 
+/// Where an item is drawn relative to the grid and the hover rulers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PlotLayer {
+    /// Behind the grid, e.g. for shaded regions that should not hide the grid lines.
+    Background,
+
+    /// Above the grid and below the hover rulers and labels.
+    #[default]
+    Normal,
+
+    /// Above everything else in the plot, including the hover rulers and labels,
+    /// e.g. for alert markers that must always be visible.
+    Foreground,
+}
+
+// ----------------------------------------------------------------------------
+
+/// How the colors of an item are combined with what is drawn below it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Cover what is below, according to the opacity of the colors.
+    #[default]
+    Normal,
+
+    /// Add the colors to what is below, so where many faint items overlap they add up
+    /// to a bright spot, like on a persistence display.
+    ///
+    /// This works best on a dark background.
+    Additive,
+}
+
+impl BlendMode {
+    /// Change the colors of the shapes of an item to blend in this mode.
+    pub(crate) fn apply(self, shapes: &mut [Shape]) {
+        match self {
+            Self::Normal => {}
+            Self::Additive => shapes.iter_mut().for_each(make_additive),
+        }
+    }
+}
+
+fn make_additive(shape: &mut Shape) {
+    let additive_mode = |color: &mut ColorMode| {
+        if let ColorMode::Solid(color) = color {
+            *color = color.additive();
+        }
+    };
+    let additive = |color: &mut Color32| *color = color.additive();
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => shapes.iter_mut().for_each(make_additive),
+        Shape::Circle(circle) => {
+            additive(&mut circle.fill);
+            additive(&mut circle.stroke.color);
+        }
+        Shape::Ellipse(ellipse) => {
+            additive(&mut ellipse.fill);
+            additive(&mut ellipse.stroke.color);
+        }
+        Shape::LineSegment { stroke, .. } => additive(&mut stroke.color),
+        Shape::Path(path) => {
+            additive(&mut path.fill);
+            additive_mode(&mut path.stroke.color);
+        }
+        Shape::Rect(rect) => {
+            additive(&mut rect.fill);
+            additive(&mut rect.stroke.color);
+        }
+        Shape::Text(text) => {
+            additive(&mut text.fallback_color);
+            if let Some(color) = &mut text.override_text_color {
+                additive(color);
+            }
+        }
+        Shape::Mesh(mesh) => {
+            for vertex in &mut std::sync::Arc::make_mut(mesh).vertices {
+                additive(&mut vertex.color);
+            }
+        }
+        Shape::QuadraticBezier(bezier) => {
+            additive(&mut bezier.fill);
+            additive_mode(&mut bezier.stroke.color);
+        }
+        Shape::CubicBezier(bezier) => {
+            additive(&mut bezier.fill);
+            additive_mode(&mut bezier.stroke.color);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Represents many [`PlotPoint`]s.
 ///
 /// These can be an owned `Vec` or generated with a function.
@@ -196,6 +389,16 @@ impl PlotPoints {
         }
     }
 
+    /// Points given as `[angle, radius]`, with the angle in radians, see [`crate::Plot::polar`].
+    pub fn from_polar(points: impl IntoIterator<Item = [f64; 2]>) -> Self {
+        Self::Owned(
+            points
+                .into_iter()
+                .map(|[angle, radius]| PlotPoint::from_polar(angle, radius))
+                .collect(),
+        )
+    }
+
     /// Draw a line based on a function `y=f(x)`, a range (which can be infinite) for x and the number of points.
     pub fn from_explicit_callback(
         function: impl Fn(f64) -> f64 + 'static,
@@ -221,6 +424,56 @@ impl PlotPoints {
         Self::Generator(generator)
     }
 
+    /// The difference or ratio of two series, like a measurement and its baseline.
+    ///
+    /// Both series are resampled onto the same `points` evenly spaced x-values over the visible part
+    /// of the range they have in common, interpolating linearly between their points.
+    /// The points of each series must be sorted by x.
+    ///
+    /// Show it in a panel of [`crate::StackedPanels`] below the two series, so it shares their x-axis:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Comparison, Line, PlotPoints, StackedPanels};
+    ///
+    /// let measurement: Vec<[f64; 2]> = (0..100).map(|i| [i as f64, (i as f64 * 0.1).sin()]).collect();
+    /// let baseline: Vec<[f64; 2]> = (0..20).map(|i| [5.0 * i as f64, 0.0]).collect();
+    /// StackedPanels::new("comparison").show(ui, |panels| {
+    ///     panels.panel(200.0, |plot_ui| {
+    ///         plot_ui.line(Line::new(measurement.clone()).name("measurement"));
+    ///         plot_ui.line(Line::new(baseline.clone()).name("baseline"));
+    ///     });
+    ///     panels.panel(80.0, |plot_ui| {
+    ///         let difference =
+    ///             PlotPoints::comparison(measurement, baseline, Comparison::Difference, 200);
+    ///         plot_ui.line(Line::new(difference).name("difference"));
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn comparison(
+        series: impl Into<Self>,
+        baseline: impl Into<Self>,
+        comparison: Comparison,
+        points: usize,
+    ) -> Self {
+        let series = series.into().points().to_vec();
+        let baseline = baseline.into().points().to_vec();
+        let x_range = |points: &[PlotPoint]| match (points.first(), points.last()) {
+            (Some(first), Some(last)) => first.x..=last.x,
+            _ => f64::NAN..=f64::NAN,
+        };
+        let (series_range, baseline_range) = (x_range(&series), x_range(&baseline));
+        let Some(x_range) = Self::range_intersection(&series_range, &baseline_range) else {
+            return Self::default();
+        };
+        Self::from_explicit_callback(
+            move |x| comparison.apply(interpolate(&series, x), interpolate(&baseline, x)),
+            x_range,
+            points,
+        )
+    }
+
     /// Draw a line based on a function `(x,y)=f(t)`, a range for t and the number of points.
     /// The range may be specified as start..end or as start..=end.
     pub fn from_parametric_callback(
@@ -320,6 +573,40 @@ impl PlotPoints {
 
 // ----------------------------------------------------------------------------
 
+/// How [`PlotPoints::comparison`] compares a series to its baseline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Comparison {
+    /// The series minus the baseline.
+    Difference,
+
+    /// The series divided by the baseline, which is missing where the baseline is zero.
+    Ratio,
+}
+
+impl Comparison {
+    fn apply(self, value: f64, baseline: f64) -> f64 {
+        match self {
+            Self::Difference => value - baseline,
+            Self::Ratio if baseline == 0.0 => f64::NAN,
+            Self::Ratio => value / baseline,
+        }
+    }
+}
+
+/// The y-value of the line through the points, sorted by x, at `x`.
+fn interpolate(points: &[PlotPoint], x: f64) -> f64 {
+    let index = points.partition_point(|point| point.x < x);
+    match (index.checked_sub(1).map(|i| points[i]), points.get(index)) {
+        (_, Some(after)) if after.x == x => after.y,
+        (Some(before), Some(after)) => {
+            lerp(before.y..=after.y, (x - before.x) / (after.x - before.x))
+        }
+        _ => f64::NAN,
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Circle, Diamond, Square, Cross, …
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum MarkerShape {
@@ -433,3 +720,27 @@ pub struct ClosestElem {
     /// Squared distance from the mouse cursor (needed to compare against other `PlotItems`, which might be nearer)
     pub dist_sq: f32,
 }
+
+#[test]
+fn test_comparison() {
+    let series = vec![[0.0, 1.0], [2.0, 5.0], [4.0, 5.0]];
+    let baseline = vec![[1.0, 2.0], [3.0, 0.0]];
+
+    let mut difference =
+        PlotPoints::comparison(series.clone(), baseline.clone(), Comparison::Difference, 3);
+    difference.generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+    assert_eq!(
+        difference.points(),
+        [
+            PlotPoint::new(1.0, 1.0),
+            PlotPoint::new(2.0, 4.0),
+            PlotPoint::new(3.0, 5.0)
+        ]
+    );
+
+    let mut ratio = PlotPoints::comparison(series, baseline, Comparison::Ratio, 3);
+    ratio.generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+    let ys: Vec<f64> = ratio.points().iter().map(|point| point.y).collect();
+    assert_eq!(ys[..2], [1.5, 5.0]);
+    assert!(ys[2].is_nan());
+}
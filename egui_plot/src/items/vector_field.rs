@@ -0,0 +1,412 @@
+use std::ops::RangeInclusive;
+
+use egui::{
+    emath::Rot2, pos2, vec2, Align2, Color32, Id, Pos2, Shape, Stroke, TextStyle, Ui, Vec2,
+};
+use emath::Float as _;
+
+use super::{
+    BlendMode, ClosestElem, LineStyle, PlotConfig, PlotGeometry, PlotItem, PlotLayer, PlotPoint,
+};
+use crate::{Cursor, LabelFormatter, LegendIcon, PlotBounds, PlotTransform};
+
+/// The function giving the vector `(u, v)` at the point `(x, y)`, see [`VectorField::new`].
+type FieldFn = dyn Fn(f64, f64) -> (f64, f64);
+
+/// The number of integration steps per grid cell of a streamline.
+const STREAMLINE_STEPS: usize = 4;
+
+/// A vector field given by a function, sampled on a grid that follows the view.
+///
+/// The grid has a fixed spacing on the screen, so the field is re-sampled when panning and zooming.
+/// The arrows are scaled relative to the longest one in view.
+/// Vectors that are NaN or infinite are skipped.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, VectorField};
+///
+/// Plot::new("field").data_aspect(1.0).show(ui, |plot_ui| {
+///     plot_ui.vector_field(VectorField::new(|x, y| (-y, x)).name("rotation"));
+/// });
+/// # });
+/// ```
+pub struct VectorField {
+    function: Box<FieldFn>,
+    spacing: f32,
+    streamlines: bool,
+    pub(crate) stroke: Stroke,
+    pub(crate) name: String,
+    highlight: bool,
+    allow_hover: bool,
+    allow_highlight: bool,
+    hover_priority: i32,
+    legend_group: Option<String>,
+    layer: PlotLayer,
+    clip: bool,
+    blend_mode: BlendMode,
+    id: Option<Id>,
+}
+
+impl VectorField {
+    pub fn new(function: impl Fn(f64, f64) -> (f64, f64) + 'static) -> Self {
+        Self {
+            function: Box::new(function),
+            spacing: 30.0,
+            streamlines: false,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            name: String::new(),
+            highlight: false,
+            allow_hover: true,
+            allow_highlight: true,
+            hover_priority: 0,
+            legend_group: None,
+            layer: PlotLayer::default(),
+            clip: true,
+            blend_mode: BlendMode::Normal,
+            id: None,
+        }
+    }
+
+    /// The distance between the samples of the field, in ui points. Default: `30.0`.
+    #[inline]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing.max(5.0);
+        self
+    }
+
+    /// Draw streamlines integrated from each sample instead of arrows. Default: `false`.
+    #[inline]
+    pub fn streamlines(mut self, streamlines: bool) -> Self {
+        self.streamlines = streamlines;
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Stroke width. Default: `1.0`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Name of this item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this item in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Highlight this item when its legend entry is hovered, see [`PlotItem::allow_highlight`]. Default: `true`.
+    #[inline]
+    pub fn allow_highlight(mut self, highlight: bool) -> Self {
+        self.allow_highlight = highlight;
+        self
+    }
+
+    /// The priority of this item when hovering, see [`PlotItem::hover_priority`]. Default: `0`.
+    #[inline]
+    pub fn hover_priority(mut self, priority: i32) -> Self {
+        self.hover_priority = priority;
+        self
+    }
+
+    /// Put the legend entry of this item in the group with this name, see [`PlotItem::legend_group`].
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn legend_group(mut self, group: impl ToString) -> Self {
+        self.legend_group = Some(group.to_string());
+        self
+    }
+
+    /// The layer to draw this item on, e.g. behind the grid. Default: [`PlotLayer::Normal`].
+    #[inline]
+    pub fn layer(mut self, layer: PlotLayer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Clip this item to the plot frame, see [`PlotItem::clip`]. Default: `true`.
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// How to blend the colors of this item, see [`PlotItem::blend_mode`]. Default: [`BlendMode::Normal`].
+    #[inline]
+    pub fn blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// Set the item's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// The screen positions of the samples within the frame, on a grid anchored at the data origin
+    /// so that the samples move along when panning.
+    fn samples(&self, transform: &PlotTransform) -> Vec<Pos2> {
+        let frame = *transform.frame();
+        let origin = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+        let origin = if origin.is_finite() {
+            origin
+        } else {
+            frame.min
+        };
+
+        let spacing = self.spacing;
+        let range = |min: f32, max: f32, origin: f32| {
+            let first = ((min - origin) / spacing - 0.5).ceil() as i64;
+            let last = ((max - origin) / spacing - 0.5).floor() as i64;
+            (first..=last).map(move |i| origin + (i as f32 + 0.5) * spacing)
+        };
+        range(frame.top(), frame.bottom(), origin.y)
+            .flat_map(|y| range(frame.left(), frame.right(), origin.x).map(move |x| pos2(x, y)))
+            .collect()
+    }
+
+    /// The vector at the given screen position, and how it moves on the screen.
+    fn vector_at(&self, transform: &PlotTransform, pos: Pos2) -> Option<((f64, f64), Vec2)> {
+        let point = transform.value_from_position(pos);
+        let (u, v) = (self.function)(point.x, point.y);
+        if !u.is_finite() || !v.is_finite() {
+            return None;
+        }
+
+        // Follow the vector for about a point on the screen, which also works for log axes.
+        let [dx, dy] = transform.dvalue_dpos();
+        let t = 1.0 / (u / dx).hypot(v / dy);
+        if !t.is_finite() {
+            return Some(((u, v), Vec2::ZERO));
+        }
+        let to = transform.position_from_point(&PlotPoint::new(point.x + t * u, point.y + t * v));
+        let velocity = (to - pos) / t as f32;
+        velocity.is_finite().then_some(((u, v), velocity))
+    }
+
+    /// A streamline from the given position, following the field for two grid cells.
+    fn streamline(&self, transform: &PlotTransform, start: Pos2) -> Vec<Pos2> {
+        let step = self.spacing / STREAMLINE_STEPS as f32;
+        let direction = |pos: Pos2| {
+            self.vector_at(transform, pos)
+                .map(|(_, velocity)| velocity.normalized())
+                .filter(|dir| dir.is_finite() && *dir != Vec2::ZERO)
+        };
+
+        let mut line = vec![start];
+        let mut pos = start;
+        for _ in 0..2 * STREAMLINE_STEPS {
+            // The midpoint method, which keeps closed orbits from spiraling out quickly.
+            let Some(dir) = direction(pos) else { break };
+            let Some(dir) = direction(pos + 0.5 * step * dir) else {
+                break;
+            };
+            pos += step * dir;
+            if !transform.frame().contains(pos) {
+                break;
+            }
+            line.push(pos);
+        }
+        line
+    }
+}
+
+/// The two sides of the tip of an arrow ending at `tip`.
+fn arrow_tip(tip: Pos2, dir: Vec2, length: f32) -> Vec<Pos2> {
+    let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
+    vec![
+        tip - length * (rot.inverse() * dir),
+        tip,
+        tip - length * (rot * dir),
+    ]
+}
+
+impl PlotItem for VectorField {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.highlight {
+            stroke.width *= 2.0;
+        }
+        let samples = self.samples(transform);
+
+        if self.streamlines {
+            for start in samples {
+                let line = self.streamline(transform, start);
+                if let [.., before, tip] = line[..] {
+                    let dir = (tip - before).normalized();
+                    shapes.push(Shape::line(arrow_tip(tip, dir, self.spacing / 6.0), stroke));
+                    shapes.push(Shape::line(line, stroke));
+                }
+            }
+            return;
+        }
+
+        let velocities: Vec<_> = samples
+            .iter()
+            .filter_map(|pos| Some((*pos, self.vector_at(transform, *pos)?.1)))
+            .collect();
+        let max_speed = velocities
+            .iter()
+            .map(|(_, velocity)| velocity.length())
+            .fold(0.0, f32::max);
+        if max_speed <= 0.0 {
+            return;
+        }
+        for (pos, velocity) in velocities {
+            let vector = 0.9 * self.spacing * velocity / max_speed;
+            if vector.length() < 1.0 {
+                continue;
+            }
+            let (origin, tip) = (pos - 0.5 * vector, pos + 0.5 * vector);
+            shapes.push(Shape::line_segment([origin, tip], stroke));
+            let tip_shape = arrow_tip(tip, vector.normalized(), vector.length() / 4.0);
+            shapes.push(Shape::line(tip_shape, stroke));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn allow_highlight(&self) -> bool {
+        self.allow_highlight
+    }
+
+    fn hover_priority(&self) -> i32 {
+        self.hover_priority
+    }
+
+    fn legend_group(&self) -> Option<&str> {
+        self.legend_group.as_deref()
+    }
+
+    fn layer(&self) -> PlotLayer {
+        self.layer
+    }
+
+    fn clip(&self) -> bool {
+        self.clip
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn legend_icon(&self) -> LegendIcon {
+        LegendIcon {
+            line: Some((self.stroke, LineStyle::Solid)),
+            ..Default::default()
+        }
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        // The field covers the whole plane, so it doesn't take part in the automatic bounds.
+        PlotBounds::NOTHING
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.samples(transform)
+            .iter()
+            .enumerate()
+            .map(|(index, pos)| ClosestElem {
+                index,
+                dist_sq: pos.distance_sq(point),
+            })
+            .min_by_key(|elem| elem.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _label_formatter: &LabelFormatter<'_>,
+    ) {
+        let Some(pos) = self.samples(plot.transform).get(elem.index).copied() else {
+            return;
+        };
+        let Some(((u, v), _)) = self.vector_at(plot.transform, pos) else {
+            return;
+        };
+        shapes.push(Shape::circle_filled(pos, 3.0, self.stroke.color));
+
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        let point = plot.transform.value_from_position(pos);
+        text.push_str(&format!(
+            "({:.3}, {:.3}) → ({u:.3}, {v:.3})",
+            point.x, point.y
+        ));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+}
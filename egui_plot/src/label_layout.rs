@@ -0,0 +1,115 @@
+use egui::{vec2, Align2, Pos2, Rect, Vec2};
+
+/// Distance between a label and its anchor, in ui points.
+const GAP: f32 = 4.0;
+
+/// The sides of the anchor a label can be put on, in order of preference.
+const DIRECTIONS: [(Align2, Vec2); 8] = [
+    (Align2::LEFT_CENTER, vec2(1.0, 0.0)),
+    (Align2::RIGHT_CENTER, vec2(-1.0, 0.0)),
+    (Align2::CENTER_BOTTOM, vec2(0.0, -1.0)),
+    (Align2::CENTER_TOP, vec2(0.0, 1.0)),
+    (Align2::LEFT_BOTTOM, vec2(1.0, -1.0)),
+    (Align2::LEFT_TOP, vec2(1.0, 1.0)),
+    (Align2::RIGHT_BOTTOM, vec2(-1.0, -1.0)),
+    (Align2::RIGHT_TOP, vec2(-1.0, 1.0)),
+];
+
+/// Places labels next to their anchors, one at a time, so that they stay inside the frame
+/// and cover as few of the earlier labels and data points as possible.
+pub(crate) struct LabelLayout {
+    frame: Rect,
+
+    /// The data points to avoid, in screen coordinates.
+    obstacles: Vec<Pos2>,
+
+    /// The labels placed so far.
+    placed: Vec<Rect>,
+}
+
+impl LabelLayout {
+    pub fn new(frame: Rect, obstacles: Vec<Pos2>) -> Self {
+        Self {
+            frame,
+            obstacles,
+            placed: Vec::new(),
+        }
+    }
+
+    /// Finds a spot for a label of the given size next to the anchor, and reserves it.
+    pub fn place(&mut self, anchor: Pos2, size: Vec2) -> Rect {
+        let mut best: Option<(f32, Rect)> = None;
+        'search: for ring in 0..3 {
+            let distance = GAP + ring as f32 * size.y;
+            for (align, direction) in DIRECTIONS {
+                let rect = align.anchor_size(anchor + distance * direction, size);
+                if !self.frame.contains_rect(rect) {
+                    continue;
+                }
+                let overlap = self.overlap(rect);
+                // Moving further away is only worth it if it uncovers something.
+                let cost = overlap + 0.5 * ring as f32;
+                if best.map_or(true, |(best_cost, _)| cost < best_cost) {
+                    best = Some((cost, rect));
+                }
+                if overlap == 0.0 {
+                    break 'search;
+                }
+            }
+        }
+
+        let rect = best.map_or_else(
+            || self.clamp_to_frame(Align2::LEFT_CENTER.anchor_size(anchor + vec2(GAP, 0.0), size)),
+            |(_, rect)| rect,
+        );
+        self.placed.push(rect);
+        rect
+    }
+
+    /// How bad it is to put a label here: overlapping another label is much worse than covering data.
+    fn overlap(&self, rect: Rect) -> f32 {
+        let labels = self
+            .placed
+            .iter()
+            .filter(|other| other.intersects(rect))
+            .count();
+        let points = self
+            .obstacles
+            .iter()
+            .filter(|point| rect.contains(**point))
+            .count();
+        100.0 * labels as f32 + points as f32
+    }
+
+    fn clamp_to_frame(&self, rect: Rect) -> Rect {
+        let dx = (self.frame.left() - rect.left()).max(0.0)
+            + (self.frame.right() - rect.right()).min(0.0);
+        let dy = (self.frame.top() - rect.top()).max(0.0)
+            + (self.frame.bottom() - rect.bottom()).min(0.0);
+        rect.translate(vec2(dx, dy))
+    }
+}
+
+#[test]
+fn test_label_layout() {
+    let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+    let size = vec2(20.0, 10.0);
+
+    // Labels prefer the right side of their anchor.
+    let mut layout = LabelLayout::new(frame, Vec::new());
+    let first = layout.place(Pos2::new(50.0, 50.0), size);
+    assert_eq!(first.left_center(), Pos2::new(54.0, 50.0));
+
+    // A second label at the same anchor goes elsewhere.
+    let second = layout.place(Pos2::new(50.0, 50.0), size);
+    assert!(!first.intersects(second));
+
+    // Labels stay inside the frame.
+    let edge = layout.place(Pos2::new(95.0, 2.0), size);
+    assert!(frame.contains_rect(edge));
+
+    // Data points are avoided.
+    let mut layout = LabelLayout::new(frame, vec![Pos2::new(60.0, 50.0)]);
+    let rect = layout.place(Pos2::new(50.0, 50.0), size);
+    assert!(!rect.contains(Pos2::new(60.0, 50.0)));
+}
@@ -0,0 +1,81 @@
+use egui::Id;
+
+use crate::{PlotGeometry, PlotItem, PlotPoint};
+
+/// The points selected by the user with the lasso, see [`crate::Plot::lasso_selection`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LassoSelection {
+    /// The drawn outline, in plot coordinates.
+    pub polygon: Vec<PlotPoint>,
+
+    /// The id of each item with points inside the outline, together with the indices of those points.
+    ///
+    /// For items without an id, this is `Id::new(name)`.
+    pub items: Vec<(Id, Vec<usize>)>,
+}
+
+impl LassoSelection {
+    pub(crate) fn new(polygon: Vec<PlotPoint>, items: &[Box<dyn PlotItem>]) -> Self {
+        let items = items
+            .iter()
+            .filter_map(|item| {
+                let PlotGeometry::Points(points) = item.geometry() else {
+                    return None;
+                };
+                let indices: Vec<usize> = points
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, point)| polygon_contains(&polygon, point))
+                    .map(|(index, _)| index)
+                    .collect();
+                let item_id = item.id().unwrap_or_else(|| Id::new(item.name()));
+                (!indices.is_empty()).then_some((item_id, indices))
+            })
+            .collect();
+
+        Self { polygon, items }
+    }
+}
+
+/// Is the point inside the (implicitly closed) polygon, using the even-odd rule?
+fn polygon_contains(polygon: &[PlotPoint], point: &PlotPoint) -> bool {
+    let mut inside = false;
+    for (i, a) in polygon.iter().enumerate() {
+        let b = &polygon[(i + 1) % polygon.len()];
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+#[test]
+fn test_polygon_contains() {
+    let square = [
+        PlotPoint::new(0.0, 0.0),
+        PlotPoint::new(2.0, 0.0),
+        PlotPoint::new(2.0, 2.0),
+        PlotPoint::new(0.0, 2.0),
+    ];
+    assert!(polygon_contains(&square, &PlotPoint::new(1.0, 1.0)));
+    assert!(!polygon_contains(&square, &PlotPoint::new(3.0, 1.0)));
+    assert!(!polygon_contains(&square, &PlotPoint::new(1.0, -1.0)));
+
+    // A "U" shape, where the point is in the notch.
+    let u = [
+        PlotPoint::new(0.0, 0.0),
+        PlotPoint::new(3.0, 0.0),
+        PlotPoint::new(3.0, 3.0),
+        PlotPoint::new(2.0, 3.0),
+        PlotPoint::new(2.0, 1.0),
+        PlotPoint::new(1.0, 1.0),
+        PlotPoint::new(1.0, 3.0),
+        PlotPoint::new(0.0, 3.0),
+    ];
+    assert!(!polygon_contains(&u, &PlotPoint::new(1.5, 2.0)));
+    assert!(polygon_contains(&u, &PlotPoint::new(0.5, 2.0)));
+
+    assert!(!polygon_contains(&[], &PlotPoint::new(0.0, 0.0)));
+}
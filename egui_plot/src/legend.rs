@@ -1,11 +1,17 @@
-use std::{collections::BTreeMap, string::String};
+use std::{collections::BTreeMap, string::String, sync::Arc};
 
 use egui::{
-    epaint::CircleShape, pos2, vec2, Align, Color32, Direction, Frame, Layout, PointerButton, Rect,
-    Response, Sense, Shadow, Shape, TextStyle, Ui, Widget, WidgetInfo, WidgetType,
+    collapsing_header::CollapsingState,
+    epaint::CircleShape,
+    pos2, remap,
+    text::{LayoutJob, TextWrapping},
+    vec2, AboveOrBelow, Align, Align2, Checkbox, Color32, CursorIcon, Direction, Frame, Layout,
+    Mesh, Painter, PointerButton, PopupCloseBehavior, Rect, Response, RichText, Sense, Shadow,
+    Stroke, TextStyle, Ui, Vec2, Widget, WidgetInfo, WidgetType,
 };
 
-use super::items::PlotItem;
+use super::items::{add_marker, PlotGeometry, PlotItem};
+use crate::{LineStyle, MarkerShape};
 
 /// Where to place the plot legend.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +36,17 @@ impl Corner {
     }
 }
 
+/// Where to dock a legend outside the plot frame, see [`Legend::outside`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LegendDock {
+    /// A column right of the plot.
+    Right,
+
+    /// Rows below the plot.
+    Bottom,
+}
+
 /// The configuration for a plot legend.
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -38,8 +55,38 @@ pub struct Legend {
     pub background_alpha: f32,
     pub position: Corner,
 
+    /// Dock the legend outside the plot frame instead of in a corner.
+    outside: Option<LegendDock>,
+
+    /// The number of columns to arrange the entries in.
+    columns: usize,
+
+    /// Truncate entries wider than this, see [`Self::max_entry_width`].
+    max_entry_width: f32,
+
+    /// Show a text field filtering the entries, see [`Self::filter`].
+    filter: bool,
+
+    /// Also hide the items of the entries that don't match the filter.
+    hide_filtered_items: bool,
+
+    /// Show the value of each item at the pointer, or its latest one.
+    show_values: bool,
+
+    /// Let the user drag the legend around the plot.
+    movable: bool,
+
+    /// Show a button opening the legend in a popup instead, when the plot is smaller than this.
+    collapse_below: Option<Vec2>,
+
+    /// Let the user drag the entries to reorder them and the items.
+    reorderable: bool,
+
     /// Used for overriding the `hidden_items` set in [`LegendWidget`].
     hidden_items: Option<ahash::HashSet<String>>,
+
+    /// Continuous encodings shown below the items.
+    scales: Vec<LegendScale>,
 }
 
 impl Default for Legend {
@@ -48,8 +95,18 @@ impl Default for Legend {
             text_style: TextStyle::Body,
             background_alpha: 0.75,
             position: Corner::RightTop,
+            outside: None,
+            columns: 1,
+            max_entry_width: f32::INFINITY,
+            filter: false,
+            hide_filtered_items: false,
+            show_values: false,
+            movable: false,
+            collapse_below: None,
+            reorderable: false,
 
             hidden_items: None,
+            scales: Vec::new(),
         }
     }
 }
@@ -76,6 +133,103 @@ impl Legend {
         self
     }
 
+    /// Place the legend outside the plot frame, which shrinks to make room for it,
+    /// so that it never covers the data.
+    ///
+    /// The space is taken from the plot the frame after the legend first shows, like for the axes.
+    /// A legend on the right is aligned to the top or bottom as given by [`Self::position`].
+    #[inline]
+    pub fn outside(mut self, dock: LegendDock) -> Self {
+        self.outside = Some(dock);
+        self
+    }
+
+    /// Arrange the entries in a grid with this many columns, filled row by row. Default: `1`.
+    ///
+    /// This keeps a legend with many short entries from becoming a long column covering the data.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// Cut the text of longer entries short with `…` at this width, showing the whole text
+    /// when hovered. Default: `f32::INFINITY`.
+    ///
+    /// This keeps long item names from making the legend cover the data.
+    #[inline]
+    pub fn max_entry_width(mut self, max_width: f32) -> Self {
+        self.max_entry_width = max_width;
+        self
+    }
+
+    /// Show a text field at the top of the legend that hides the entries whose name doesn't
+    /// contain the typed text, ignoring case. Default: `false`.
+    ///
+    /// This helps finding a series in plots with many of them, like one per CPU core.
+    #[inline]
+    pub fn filter(mut self, filter: bool) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Also hide the items of the entries that don't match the [`Self::filter`] in the plot,
+    /// without unchecking them. Default: `false`.
+    #[inline]
+    pub fn hide_filtered_items(mut self, hide: bool) -> Self {
+        self.hide_filtered_items = hide;
+        self
+    }
+
+    /// Show the value of each item next to its name: the value at the x-coordinate
+    /// of the pointer while it is over the plot, and the latest one otherwise. Default: `false`.
+    ///
+    /// The values are formatted with the [`crate::Plot::value_formatter`] if there is one.
+    #[inline]
+    pub fn show_values(mut self, show_values: bool) -> Self {
+        self.show_values = show_values;
+        self
+    }
+
+    /// Let the user drag the legend to anywhere within the plot. Default: `false`.
+    ///
+    /// The legend is moved relative to its [`Self::position`], and the plot remembers where to.
+    /// This has no effect on a legend [outside](Self::outside) the plot.
+    #[inline]
+    pub fn movable(mut self, movable: bool) -> Self {
+        self.movable = movable;
+        self
+    }
+
+    /// When the plot is narrower or lower than this, show only a button in place of the legend,
+    /// which opens the full legend in a popup. Default: never.
+    ///
+    /// This keeps small plots, like the tiles of a dashboard, clear of the legend,
+    /// while the items can still be shown and hidden.
+    #[inline]
+    pub fn collapse_below(mut self, size: impl Into<Vec2>) -> Self {
+        self.collapse_below = Some(size.into());
+        self
+    }
+
+    /// Let the user drag the entries up and down to change their order. Default: `false`.
+    ///
+    /// The items are then drawn in the order of their entries, so the item of the last entry
+    /// is drawn on top of the others. The plot remembers the order.
+    ///
+    /// Without this, the entries are sorted by name.
+    #[inline]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Where the legend is docked outside the plot, if it is.
+    #[inline]
+    pub(crate) fn dock(&self) -> Option<LegendDock> {
+        self.outside
+    }
+
     /// Specifies hidden items in the legend configuration to override the existing ones. This
     /// allows the legend traces' visibility to be controlled from the application code.
     #[inline]
@@ -86,41 +240,266 @@ impl Legend {
         self.hidden_items = Some(hidden_items.into_iter().collect());
         self
     }
+
+    /// Add an entry explaining a value that is encoded as the color or size of the markers,
+    /// shown below the entries of the plot items.
+    #[inline]
+    pub fn scale(mut self, scale: LegendScale) -> Self {
+        self.scales.push(scale);
+        self
+    }
+}
+
+/// A legend entry for a continuous value encoded as color or size, see [`Legend::scale`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LegendScale {
+    /// A gradient bar through the given `(value, color)` stops, sorted by value.
+    Color {
+        name: String,
+        stops: Vec<(f64, Color32)>,
+    },
+
+    /// Circles with the given `(value, radius)` sizes, sorted by value.
+    Size {
+        name: String,
+        swatches: Vec<(f64, f32)>,
+    },
+}
+
+impl LegendScale {
+    /// The width of a gradient bar.
+    const BAR_WIDTH: f32 = 120.0;
+
+    /// A gradient bar through the given `(value, color)` stops.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn color(name: impl ToString, stops: impl IntoIterator<Item = (f64, Color32)>) -> Self {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self::Color {
+            name: name.to_string(),
+            stops,
+        }
+    }
+
+    /// Circles with the given `(value, radius)` sizes, with the radius in ui points.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn size(name: impl ToString, swatches: impl IntoIterator<Item = (f64, f32)>) -> Self {
+        let mut swatches: Vec<_> = swatches.into_iter().collect();
+        swatches.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self::Size {
+            name: name.to_string(),
+            swatches,
+        }
+    }
+
+    fn ui(&self, ui: &mut Ui, text_style: &TextStyle) -> Response {
+        let font_id = text_style.resolve(ui.style());
+        let text_color = ui.visuals().text_color();
+        let ctx = ui.ctx().clone();
+        let layout =
+            |text: String| ctx.fonts(|f| f.layout_no_wrap(text, font_id.clone(), text_color));
+
+        let (name, body_size) = match self {
+            Self::Color { name, .. } => (
+                name,
+                vec2(Self::BAR_WIDTH, 0.75 * ui.text_style_height(text_style)),
+            ),
+            Self::Size { name, swatches } => {
+                let spacing = ui.spacing().item_spacing.x;
+                let width = swatches
+                    .iter()
+                    .map(|(value, radius)| (2.0 * radius).max(layout(value.to_string()).size().x))
+                    .sum::<f32>()
+                    + spacing * swatches.len().saturating_sub(1) as f32;
+                let height = swatches
+                    .iter()
+                    .map(|(_, radius)| 2.0 * radius)
+                    .fold(0.0, f32::max);
+                (name, vec2(width, height))
+            }
+        };
+        let name = layout(name.clone());
+        let label_height = ui.text_style_height(text_style);
+        let size = vec2(
+            name.size().x.max(body_size.x),
+            name.size().y + body_size.y + label_height,
+        );
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        let painter = ui.painter();
+
+        let body = Rect::from_min_size(rect.min + vec2(0.0, name.size().y), body_size);
+        painter.galley(rect.min, name, text_color);
+
+        match self {
+            Self::Color { stops, .. } => {
+                let (Some((min, _)), Some((max, _))) = (stops.first(), stops.last()) else {
+                    return response;
+                };
+                let mut mesh = Mesh::default();
+                for (i, (value, color)) in stops.iter().enumerate() {
+                    let x = if min < max {
+                        remap(*value, *min..=*max, 0.0..=1.0) as f32
+                    } else {
+                        i as f32 / (stops.len() - 1).max(1) as f32
+                    };
+                    let x = egui::lerp(body.x_range(), x);
+                    mesh.colored_vertex(pos2(x, body.top()), *color);
+                    mesh.colored_vertex(pos2(x, body.bottom()), *color);
+                    if i > 0 {
+                        let i = 2 * i as u32;
+                        mesh.add_triangle(i - 2, i - 1, i);
+                        mesh.add_triangle(i - 1, i, i + 1);
+                    }
+                }
+                painter.add(mesh);
+                painter.text(
+                    body.left_bottom(),
+                    Align2::LEFT_TOP,
+                    min.to_string(),
+                    font_id.clone(),
+                    text_color,
+                );
+                if min < max {
+                    painter.text(
+                        body.right_bottom(),
+                        Align2::RIGHT_TOP,
+                        max.to_string(),
+                        font_id,
+                        text_color,
+                    );
+                }
+            }
+            Self::Size { swatches, .. } => {
+                let color = ui.visuals().noninteractive().fg_stroke.color;
+                let mut left = body.left();
+                for (value, radius) in swatches {
+                    let label = layout(value.to_string());
+                    let width = (2.0 * radius).max(label.size().x);
+                    let center = pos2(left + 0.5 * width, body.center().y);
+                    painter.circle_filled(center, *radius, color);
+                    painter.galley(
+                        pos2(center.x - 0.5 * label.size().x, body.bottom()),
+                        label,
+                        text_color,
+                    );
+                    left += width + ui.spacing().item_spacing.x;
+                }
+            }
+        }
+
+        response
+    }
+}
+
+/// What the icon of an item in the legend shows, see [`PlotItem::legend_icon`].
+///
+/// Without a line or a marker, the icon is a disc of the item's color.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LegendIcon {
+    /// A horizontal line with this stroke and style.
+    pub line: Option<(Stroke, LineStyle)>,
+
+    /// A marker with this shape, filled or not.
+    pub marker: Option<(MarkerShape, bool)>,
+
+    /// A filled area in this color, below the line if there is one,
+    /// like the area under a line or the inside of a polygon or bar.
+    pub fill: Option<Color32>,
+}
+
+type LegendSwatchFn = dyn Fn(&Painter, Rect);
+
+/// A custom icon of an item in the legend, painted by a function, see [`PlotItem::legend_swatch`].
+///
+/// The function is called with the area of the icon, while the item is shown.
+#[derive(Clone)]
+pub struct LegendSwatch(Arc<LegendSwatchFn>);
+
+impl LegendSwatch {
+    pub fn new(paint: impl Fn(&Painter, Rect) + 'static) -> Self {
+        Self(Arc::new(paint))
+    }
 }
 
 #[derive(Clone)]
 struct LegendEntry {
     color: Color32,
+    outline: Option<Stroke>,
+    icon: LegendIcon,
+    swatch: Option<LegendSwatch>,
+    group: Option<String>,
+    value: Option<String>,
     checked: bool,
     hovered: bool,
+
+    /// Where the entry was shown, if it was.
+    rect: Option<Rect>,
 }
 
 impl LegendEntry {
-    fn new(color: Color32, checked: bool) -> Self {
+    fn new(
+        color: Color32,
+        outline: Option<Stroke>,
+        icon: LegendIcon,
+        swatch: Option<LegendSwatch>,
+        group: Option<String>,
+        checked: bool,
+    ) -> Self {
         Self {
             color,
+            outline,
+            icon,
+            swatch,
+            group,
+            value: None,
             checked,
             hovered: false,
+            rect: None,
         }
     }
 
-    fn ui(&self, ui: &mut Ui, text: String, text_style: &TextStyle) -> Response {
+    fn ui(
+        &self,
+        ui: &mut Ui,
+        text: String,
+        text_style: &TextStyle,
+        max_width: f32,
+        sense: Sense,
+    ) -> Response {
         let Self {
             color,
+            outline,
+            icon,
+            swatch,
+            group: _,
+            value,
             checked,
             hovered: _,
+            rect: _,
         } = self;
 
+        let text = match value {
+            Some(value) => format!("{text}: {value}"),
+            None => text,
+        };
+
         let font_id = text_style.resolve(ui.style());
 
-        let galley = ui.fonts(|f| f.layout_delayed_color(text, font_id, f32::INFINITY));
+        let mut job = LayoutJob::simple_singleline(text, font_id, Color32::PLACEHOLDER);
+        job.wrap = TextWrapping::truncate_at_width(max_width);
+        let galley = ui.fonts(|f| f.layout_job(job));
 
         let icon_size = galley.size().y;
         let icon_spacing = icon_size / 5.0;
         let total_extra = vec2(icon_size + icon_spacing, 0.0);
 
         let desired_size = total_extra + galley.size();
-        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, sense);
+        if galley.elided {
+            let text = galley.text().to_owned();
+            response = response.on_hover_text(text);
+        }
 
         response.widget_info(|| {
             WidgetInfo::selected(
@@ -152,16 +531,23 @@ impl LegendEntry {
         });
 
         if *checked {
-            let fill = if *color == Color32::TRANSPARENT {
+            let color = if *color == Color32::TRANSPARENT {
                 ui.visuals().noninteractive().fg_stroke.color
             } else {
                 *color
             };
-            painter.add(Shape::circle_filled(
-                icon_rect.center(),
-                icon_size * 0.4,
-                fill,
-            ));
+            if let Some(swatch) = swatch {
+                (swatch.0)(painter, icon_rect);
+            } else if *icon == LegendIcon::default() {
+                painter.add(CircleShape {
+                    center: icon_rect.center(),
+                    radius: icon_size * 0.4,
+                    fill: color,
+                    stroke: outline.unwrap_or(Stroke::NONE),
+                });
+            } else {
+                paint_icon(ui, icon, icon_rect, color, *outline);
+            }
         }
 
         let text_position_x = if label_on_the_left {
@@ -177,21 +563,96 @@ impl LegendEntry {
     }
 }
 
+/// Paints a small sample of the line and marker of an item within `rect`.
+fn paint_icon(ui: &Ui, icon: &LegendIcon, rect: Rect, color: Color32, outline: Option<Stroke>) {
+    let size = rect.height();
+    let mut shapes = Vec::new();
+
+    if let Some(fill) = icon.fill {
+        let area = rect.shrink(size * 0.15);
+        if icon.line.is_some() {
+            let area = Rect::from_min_max(pos2(rect.left(), rect.center().y), area.right_bottom());
+            shapes.push(egui::Shape::rect_filled(area, 0.0, fill));
+        } else {
+            shapes.push(egui::Shape::rect_filled(area, size * 0.1, fill));
+            shapes.push(egui::Shape::rect_stroke(
+                area,
+                size * 0.1,
+                Stroke::new(1.0, color),
+                egui::StrokeKind::Inside,
+            ));
+        }
+    }
+
+    if let Some((stroke, style)) = icon.line {
+        // Shrink the line so that its style is still visible within the small icon.
+        let stroke = Stroke::new(stroke.width.min(size / 4.0), color);
+        let style = match style {
+            LineStyle::Solid => LineStyle::Solid,
+            LineStyle::Dotted { spacing } => LineStyle::Dotted {
+                spacing: spacing.min(size / 3.0),
+            },
+            LineStyle::Dashed { length } => LineStyle::Dashed {
+                length: length.min(size / 3.0),
+            },
+        };
+        let line = vec![rect.left_center(), rect.right_center()];
+        style.style_line(line, stroke, false, &mut shapes);
+    }
+
+    if let Some((shape, filled)) = icon.marker {
+        let radius = size * 0.3;
+        add_marker(
+            &mut shapes,
+            shape,
+            rect.center(),
+            radius,
+            radius / 5.0,
+            color,
+            filled,
+            outline,
+        );
+    }
+
+    ui.painter().extend(shapes);
+}
+
 #[derive(Clone)]
 pub(super) struct LegendWidget {
     rect: Rect,
     entries: BTreeMap<String, LegendEntry>,
+
+    /// The names of the entries, in the order they are shown.
+    order: Vec<String>,
+
     config: Legend,
+
+    /// The text typed into the filter field.
+    filter: String,
+
+    /// How far the user dragged a [movable](Legend::movable) legend from its position.
+    offset: Vec2,
+
+    /// The width or height a legend outside the plot needed, once shown.
+    thickness: f32,
+
+    /// Show a button opening the legend in a popup, see [`Legend::collapse_below`].
+    collapsed: bool,
 }
 
 impl LegendWidget {
-    /// Create a new legend from items, the names of items that are hidden and the style of the
-    /// text. Returns `None` if the legend has no entries.
+    /// Create a new legend from items, the names of items that are hidden, the text in the
+    /// filter field, how far the legend was moved, the order the user dragged the entries in
+    /// and the style of the text.
+    /// Returns `None` if the legend has no entries.
     pub(super) fn try_new(
         rect: Rect,
         config: Legend,
         items: &[Box<dyn PlotItem>],
         hidden_items: &ahash::HashSet<String>, // Existing hidden items in the plot memory.
+        filter: &str,
+        offset: Vec2,
+        order: &[String],
     ) -> Option<Self> {
         // If `config.hidden_items` is not `None`, it is used.
         let hidden_items = config.hidden_items.as_ref().unwrap_or(hidden_items);
@@ -214,16 +675,102 @@ impl LegendWidget {
                     .or_insert_with(|| {
                         let color = item.color();
                         let checked = !hidden_items.contains(item.name());
-                        LegendEntry::new(color, checked)
+                        let group = item.legend_group().map(ToOwned::to_owned);
+                        LegendEntry::new(
+                            color,
+                            item.marker_outline(),
+                            item.legend_icon(),
+                            item.legend_swatch(),
+                            group,
+                            checked,
+                        )
                     });
             });
-        (!entries.is_empty()).then_some(Self {
+        let order = if config.reorderable {
+            // Keep the order of the known entries, and add new ones in the order of their items.
+            let mut order: Vec<String> = order
+                .iter()
+                .filter(|name| entries.contains_key(*name))
+                .cloned()
+                .collect();
+            for item in items {
+                let name = item.name();
+                if entries.contains_key(name) && !order.iter().any(|known| known == name) {
+                    order.push(name.to_owned());
+                }
+            }
+            order
+        } else {
+            entries.keys().cloned().collect()
+        };
+        (!entries.is_empty() || !config.scales.is_empty()).then_some(Self {
             rect,
             entries,
+            order,
             config,
+            filter: filter.to_owned(),
+            offset,
+            thickness: 0.0,
+            collapsed: false,
         })
     }
 
+    /// Collapse the legend if the plot, including a legend outside it, is smaller than
+    /// [`Legend::collapse_below`].
+    pub fn collapse_for(&mut self, plot_size: Vec2) {
+        self.collapsed = self
+            .config
+            .collapse_below
+            .is_some_and(|min| plot_size.x < min.x || plot_size.y < min.y);
+    }
+
+    /// Set the values shown in the entries, if [`Legend::show_values`] is on.
+    ///
+    /// Each entry shows the value of the first of its items that has one at `x`,
+    /// or the latest value if `x` is `None`.
+    pub fn set_values(
+        &mut self,
+        items: &[Box<dyn PlotItem>],
+        x: Option<f64>,
+        format: impl Fn(f64) -> String,
+    ) {
+        if !self.config.show_values {
+            return;
+        }
+        for item in items {
+            if let Some(entry) = self.entries.get_mut(item.name()) {
+                if entry.value.is_none() {
+                    entry.value = item_value(item.as_ref(), x).map(&format);
+                }
+            }
+        }
+    }
+
+    /// The text typed into the filter field.
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// How far the user dragged the legend from its position.
+    pub fn offset(&self) -> Vec2 {
+        self.offset
+    }
+
+    /// Is the item with this name filtered out of the plot, see [`Legend::hide_filtered_items`]?
+    pub fn is_filtered_out(&self, name: &str) -> bool {
+        self.config.filter && self.config.hide_filtered_items && !matches_filter(name, &self.filter)
+    }
+
+    /// The order the user dragged the entries in, if the legend is [reorderable](Legend::reorderable).
+    pub fn order(&self) -> Option<&[String]> {
+        self.config.reorderable.then_some(self.order.as_slice())
+    }
+
+    /// The width of a legend docked right of the plot, or the height of one below it.
+    pub fn thickness(&self) -> f32 {
+        self.thickness
+    }
+
     // Get the names of the hidden items.
     pub fn hidden_items(&self) -> ahash::HashSet<String> {
         self.entries
@@ -233,6 +780,22 @@ impl LegendWidget {
             .collect()
     }
 
+    /// The names of the entries, and whether they are checked, in the order they are shown.
+    pub fn entries(&self) -> Vec<(String, bool)> {
+        self.order
+            .iter()
+            .filter_map(|name| Some((name.clone(), self.entries.get(name)?.checked)))
+            .collect()
+    }
+
+    /// The names of the entries shown this frame, and where they were shown.
+    pub fn entry_rects(&self) -> Vec<(String, Rect)> {
+        self.entries
+            .iter()
+            .filter_map(|(name, entry)| Some((name.clone(), entry.rect?)))
+            .collect()
+    }
+
     // Get the name of the hovered items.
     pub fn hovered_item_name(&self) -> Option<String> {
         self.entries
@@ -242,29 +805,115 @@ impl LegendWidget {
     }
 }
 
+/// The filter, the entries and the scales of the legend.
+fn contents_ui(
+    ui: &mut Ui,
+    entries: &mut BTreeMap<String, LegendEntry>,
+    order: &mut Vec<String>,
+    config: &Legend,
+    filter: &mut String,
+    filter_at_end: bool,
+) -> Response {
+    let mut focus_on_item = None;
+
+    let mut filter_response = None;
+    if config.filter && !filter_at_end {
+        filter_response = Some(filter_ui(ui, filter));
+    }
+
+    let mut responses = grouped_entries_ui(ui, entries, order, config, filter, &mut focus_on_item);
+    for scale in &config.scales {
+        responses.push(scale.ui(ui, &config.text_style));
+    }
+    if config.filter && filter_at_end {
+        filter_response = Some(filter_ui(ui, filter));
+    }
+    responses.extend(filter_response);
+    let response_union = responses
+        .into_iter()
+        .reduce(|r1, r2| r1.union(r2))
+        .expect("No entries in the legend");
+
+    if let Some(focus_on_item) = focus_on_item {
+        handle_focus_on_legend_item(&focus_on_item, entries);
+    }
+
+    response_union
+}
+
+/// A button in place of the legend, opening the full legend in a popup above or below it.
+fn collapsed_ui(
+    ui: &mut Ui,
+    entries: &mut BTreeMap<String, LegendEntry>,
+    order: &mut Vec<String>,
+    config: &Legend,
+    filter: &mut String,
+    above: bool,
+) -> Response {
+    let button = ui.button("☰").on_hover_text("Legend");
+    let popup_id = button.id.with("legend_popup");
+    if button.clicked() {
+        ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+    }
+    let above_or_below = if above {
+        AboveOrBelow::Above
+    } else {
+        AboveOrBelow::Below
+    };
+    let popup = egui::popup_above_or_below_widget(
+        ui,
+        popup_id,
+        &button,
+        above_or_below,
+        PopupCloseBehavior::CloseOnClickOutside,
+        |ui| contents_ui(ui, entries, order, config, filter, false),
+    );
+    match popup {
+        Some(response) => button.union(response),
+        None => button,
+    }
+}
+
 impl Widget for &mut LegendWidget {
     fn ui(self, ui: &mut Ui) -> Response {
         let LegendWidget {
             rect,
             entries,
+            order,
             config,
+            filter,
+            offset,
+            thickness,
+            collapsed,
         } = self;
 
+        let movable = config.movable && config.outside.is_none();
+        if !movable {
+            *offset = Vec2::ZERO;
+        }
+
         let main_dir = match config.position {
             Corner::LeftTop | Corner::RightTop => Direction::TopDown,
             Corner::LeftBottom | Corner::RightBottom => Direction::BottomUp,
         };
-        let cross_align = match config.position {
-            Corner::LeftTop | Corner::LeftBottom => Align::LEFT,
-            Corner::RightTop | Corner::RightBottom => Align::RIGHT,
+        let cross_align = match (config.position, config.outside) {
+            (_, Some(LegendDock::Right)) | (Corner::LeftTop | Corner::LeftBottom, _) => Align::LEFT,
+            (Corner::RightTop | Corner::RightBottom, _) => Align::RIGHT,
+        };
+        let layout = match config.outside {
+            Some(LegendDock::Bottom) => Layout::left_to_right(Align::TOP).with_main_wrap(true),
+            _ => Layout::from_main_dir_and_cross_align(main_dir, cross_align),
         };
-        let layout = Layout::from_main_dir_and_cross_align(main_dir, cross_align);
         let legend_pad = 4.0;
-        let legend_rect = rect.shrink(legend_pad);
+        let legend_rect = rect.shrink(legend_pad).translate(*offset);
         let mut legend_ui =
             ui.new_child(egui::UiBuilder::new().max_rect(legend_rect).layout(layout));
-        legend_ui
+        let mut response = legend_ui
             .scope(|ui| {
+                if *collapsed {
+                    let above = config.outside.is_none() && main_dir == Direction::BottomUp;
+                    return collapsed_ui(ui, entries, order, config, filter, above);
+                }
                 let background_frame = Frame {
                     inner_margin: vec2(8.0, 4.0).into(),
                     rounding: ui.style().visuals.window_rounding,
@@ -276,41 +925,289 @@ impl Widget for &mut LegendWidget {
                 .multiply_with_opacity(config.background_alpha);
                 background_frame
                     .show(ui, |ui| {
-                        let mut focus_on_item = None;
-
-                        let response_union = entries
-                            .iter_mut()
-                            .map(|(name, entry)| {
-                                let response = entry.ui(ui, name.clone(), &config.text_style);
-
-                                // Handle interactions. Alt-clicking must be deferred to end of loop
-                                // since it may affect all entries.
-                                handle_interaction_on_legend_item(&response, entry);
-                                if response.clicked() && ui.input(|r| r.modifiers.alt) {
-                                    focus_on_item = Some(name.clone());
-                                }
-
-                                response
-                            })
-                            .reduce(|r1, r2| r1.union(r2))
-                            .expect("No entries in the legend");
-
-                        if let Some(focus_on_item) = focus_on_item {
-                            handle_focus_on_legend_item(&focus_on_item, entries);
-                        }
-
-                        response_union
+                        // The filter goes at the top, which is added last when growing upwards.
+                        let filter_at_end =
+                            config.outside.is_none() && main_dir == Direction::BottomUp;
+                        contents_ui(ui, entries, order, config, filter, filter_at_end)
                     })
                     .inner
             })
+            .inner;
+
+        if movable {
+            let drag = drag_legend(ui, &legend_ui, *rect, offset);
+            response = response.union(drag);
+        }
+
+        let used = legend_ui.min_rect().size() + vec2(2.0, 2.0) * legend_pad;
+        *thickness = match config.outside {
+            Some(LegendDock::Right) => used.x,
+            Some(LegendDock::Bottom) => used.y,
+            None => 0.0,
+        };
+        response
+    }
+}
+
+/// Let the user drag the legend shown in `legend_ui` around within `rect`, see [`Legend::movable`].
+fn drag_legend(ui: &Ui, legend_ui: &Ui, rect: Rect, offset: &mut Vec2) -> Response {
+    let legend_rect = legend_ui.min_rect();
+    let drag = ui.interact(legend_rect, legend_ui.id().with("drag"), Sense::drag());
+    let drag = if drag.dragged() {
+        drag.on_hover_and_drag_cursor(CursorIcon::Grabbing)
+    } else {
+        drag.on_hover_cursor(CursorIcon::Grab)
+    };
+
+    // Keep the legend within the plot, also when the plot shrinks.
+    let unmoved = legend_rect.translate(-*offset);
+    let new_offset = (*offset + drag.drag_delta())
+        .max(rect.min - unmoved.min)
+        .min(rect.max - unmoved.max);
+    if new_offset != *offset {
+        *offset = new_offset;
+        ui.ctx().request_repaint();
+    }
+    drag
+}
+
+/// Show the entries, with those of each group under a collapsible header with a checkbox
+/// toggling all of them.
+fn grouped_entries_ui(
+    ui: &mut Ui,
+    entries: &mut BTreeMap<String, LegendEntry>,
+    order: &mut Vec<String>,
+    config: &Legend,
+    filter: &str,
+    focus_on_item: &mut Option<String>,
+) -> Vec<Response> {
+    let shown = |name: &str| !config.filter || matches_filter(name, filter);
+
+    // The number of shown and checked entries of each group.
+    let mut groups: BTreeMap<Option<String>, (usize, usize)> = BTreeMap::new();
+    for (_, entry) in entries.iter().filter(|(name, _)| shown(name)) {
+        let (num_shown, num_checked) = groups.entry(entry.group.clone()).or_default();
+        *num_shown += 1;
+        *num_checked += usize::from(entry.checked);
+    }
+
+    let mut responses = Vec::new();
+    let mut toggled_group = None;
+    for (group, (num_shown, num_checked)) in &groups {
+        let Some(group) = group else {
+            responses.extend(entries_ui(
+                ui,
+                entries,
+                order,
+                None,
+                config,
+                filter,
+                focus_on_item,
+            ));
+            continue;
+        };
+        let id = ui.make_persistent_id(("legend_group", group));
+        let (_, header, body) = CollapsingState::load_with_default_open(ui.ctx(), id, true)
+            .show_header(ui, |ui| {
+                let mut all_checked = num_checked == num_shown;
+                let partly_checked = 0 < *num_checked && !all_checked;
+                let text = RichText::new(group).text_style(config.text_style.clone());
+                ui.add(Checkbox::new(&mut all_checked, text).indeterminate(partly_checked))
+            })
+            .body(|ui| {
+                entries_ui(
+                    ui,
+                    entries,
+                    order,
+                    Some(group),
+                    config,
+                    filter,
+                    focus_on_item,
+                )
+            });
+        if header.inner.clicked() {
+            toggled_group = Some((group.clone(), num_checked != num_shown));
+        }
+        responses.push(header.response);
+        responses.extend(body.map(|body| body.response));
+    }
+    if let Some((group, checked)) = toggled_group {
+        entries
+            .iter_mut()
+            .filter(|(name, entry)| entry.group.as_ref() == Some(&group) && shown(name))
+            .for_each(|(_, entry)| entry.checked = checked);
+    }
+    responses
+}
+
+/// Show the entries of a group, or those without one, that match the filter.
+///
+/// If the legend is [reorderable](Legend::reorderable), an entry dragged over another one
+/// takes its place in the `order`.
+fn entries_ui(
+    ui: &mut Ui,
+    entries: &mut BTreeMap<String, LegendEntry>,
+    order: &mut Vec<String>,
+    group: Option<&str>,
+    config: &Legend,
+    filter: &str,
+    focus_on_item: &mut Option<String>,
+) -> Vec<Response> {
+    let sense = if config.reorderable {
+        Sense::click_and_drag()
+    } else {
+        Sense::click()
+    };
+    let shown: Vec<String> = order
+        .iter()
+        .filter(|name| {
+            entries.get(*name).is_some_and(|entry| {
+                entry.group.as_deref() == group && (!config.filter || matches_filter(name, filter))
+            })
+        })
+        .cloned()
+        .collect();
+    let mut entries_ui = |ui: &mut Ui| -> Vec<Response> {
+        shown
+            .iter()
+            .enumerate()
+            .filter_map(|(i, name)| {
+                let entry = entries.get_mut(name)?;
+                let response = entry.ui(
+                    ui,
+                    name.clone(),
+                    &config.text_style,
+                    config.max_entry_width,
+                    sense,
+                );
+
+                // Handle interactions. Alt-clicking must be deferred to end of loop
+                // since it may affect all entries.
+                handle_interaction_on_legend_item(&response, entry);
+                if response.clicked() && ui.input(|r| r.modifiers.alt) {
+                    *focus_on_item = Some(name.clone());
+                }
+
+                if config.columns > 1 && (i + 1) % config.columns == 0 {
+                    ui.end_row();
+                }
+                Some(response)
+            })
+            .collect()
+    };
+    let responses = if config.columns > 1 {
+        egui::Grid::new(("legend", group))
+            .num_columns(config.columns)
+            .show(ui, entries_ui)
             .inner
+    } else {
+        entries_ui(ui)
+    };
+
+    if config.reorderable {
+        reorder_dragged_entry(ui, &responses, &shown, order);
+    }
+    responses
+}
+
+/// Move the entry being dragged to the place of the entry under the pointer.
+///
+/// The `responses` are those of the `shown` entries.
+fn reorder_dragged_entry(
+    ui: &Ui,
+    responses: &[Response],
+    shown: &[String],
+    order: &mut Vec<String>,
+) {
+    let Some(dragged) = responses.iter().position(Response::dragged) else {
+        return;
+    };
+    ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+    let Some(pointer) = ui.ctx().pointer_interact_pos() else {
+        return;
+    };
+    let Some(target) = responses
+        .iter()
+        .position(|response| response.rect.contains(pointer))
+    else {
+        return;
+    };
+    if target == dragged {
+        return;
     }
+
+    order.retain(|name| *name != shown[dragged]);
+    let target_index = order
+        .iter()
+        .position(|name| *name == shown[target])
+        .unwrap_or(order.len());
+    // Dragging down puts the entry after the target, dragging up before it.
+    let index = if dragged < target {
+        target_index + 1
+    } else {
+        target_index
+    };
+    order.insert(index, shown[dragged].clone());
+    ui.ctx().request_repaint();
+}
+
+/// Draw the named items in the order of their legend entries, see [`Legend::reorderable`].
+///
+/// The items without an entry keep their place among the others.
+pub(super) fn sort_items_by_legend_order(items: &mut Vec<Box<dyn PlotItem>>, order: &[String]) {
+    let rank = |item: &dyn PlotItem| order.iter().position(|name| name == item.name());
+    let slots: Vec<usize> = (0..items.len())
+        .filter(|&index| rank(items[index].as_ref()).is_some())
+        .collect();
+    let mut sorted = slots.clone();
+    sorted.sort_by_key(|&index| rank(items[index].as_ref()));
+
+    let mut taken: Vec<Option<Box<dyn PlotItem>>> = items.drain(..).map(Some).collect();
+    let mut moved: Vec<Option<Box<dyn PlotItem>>> =
+        sorted.iter().map(|&index| taken[index].take()).collect();
+    for (slot, item) in slots.into_iter().zip(&mut moved) {
+        taken[slot] = item.take();
+    }
+    items.extend(taken.into_iter().flatten());
+}
+
+/// The value of the item at `x`, or its latest one: that of the point closest to `x`,
+/// or of the last point, or the first bar at `x`.
+fn item_value(item: &dyn PlotItem, x: Option<f64>) -> Option<f64> {
+    match item.geometry() {
+        PlotGeometry::Points(points) => {
+            let mut points = points.iter().filter(|point| point.is_finite());
+            match x {
+                Some(x) => points.min_by(|a, b| (a.x - x).abs().total_cmp(&(b.x - x).abs())),
+                None => points.next_back(),
+            }
+            .map(|point| point.y)
+        }
+        PlotGeometry::Rects => x.and_then(|x| item.bar_values_at(x).first().copied()),
+        PlotGeometry::None => None,
+    }
+}
+
+/// The text field filtering the entries.
+fn filter_ui(ui: &mut Ui, filter: &mut String) -> Response {
+    ui.add(
+        egui::TextEdit::singleline(filter)
+            .id_salt("legend_filter")
+            .hint_text("Filter")
+            .desired_width(120.0),
+    )
+}
+
+/// Does the name of an entry contain the filter text, ignoring case?
+fn matches_filter(name: &str, filter: &str) -> bool {
+    name.to_lowercase().contains(&filter.to_lowercase())
 }
 
 /// Handle per-entry interactions.
 fn handle_interaction_on_legend_item(response: &Response, entry: &mut LegendEntry) {
     entry.checked ^= response.clicked_by(PointerButton::Primary);
     entry.hovered = response.hovered();
+    entry.rect = Some(response.rect);
 }
 
 /// Handle alt-click interaction (which may affect all entries).
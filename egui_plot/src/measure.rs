@@ -0,0 +1,105 @@
+use egui::{vec2, Rect, Shape, TextStyle, Ui, Vec2};
+
+use crate::{PlotPoint, PlotTransform};
+
+/// The radius of the markers at the ends of a measurement, in ui points.
+const MARKER_RADIUS: f32 = 4.0;
+
+/// The distance between two points the user placed with the measure tool, see [`crate::Plot::measure`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Measurement {
+    /// Where the user started dragging, in plot coordinates.
+    pub start: PlotPoint,
+
+    /// Where the user stopped dragging, in plot coordinates.
+    pub end: PlotPoint,
+}
+
+impl Measurement {
+    pub fn dx(&self) -> f64 {
+        self.end.x - self.start.x
+    }
+
+    pub fn dy(&self) -> f64 {
+        self.end.y - self.start.y
+    }
+
+    /// The length of the line between the points, in plot coordinates.
+    pub fn distance(&self) -> f64 {
+        self.dx().hypot(self.dy())
+    }
+
+    /// `Δy / Δx`, which is infinite for a vertical line.
+    pub fn slope(&self) -> f64 {
+        self.dy() / self.dx()
+    }
+
+    /// The line between the points with markers at its ends, and the readout of the deltas next to the end.
+    pub(crate) fn shapes(&self, ui: &Ui, transform: &PlotTransform) -> Vec<Shape> {
+        let stroke = ui.visuals().selection.stroke;
+        let start = transform.position_from_point(&self.start);
+        let end = transform.position_from_point(&self.end);
+        let mut shapes = vec![
+            Shape::line_segment([start, end], stroke),
+            Shape::circle_stroke(start, MARKER_RADIUS, stroke),
+            Shape::circle_stroke(end, MARKER_RADIUS, stroke),
+        ];
+
+        // Show as many digits as a pixel can tell apart.
+        let [x_resolution, y_resolution] = transform.dvalue_dpos().map(f64::abs);
+        let slope = self.slope();
+        let text = format!(
+            "Δx = {}\nΔy = {}\ndistance = {}\nslope = {}",
+            format_measure(self.dx(), x_resolution),
+            format_measure(self.dy(), y_resolution),
+            format_measure(self.distance(), x_resolution.min(y_resolution)),
+            format_measure(slope, 1e-3 * slope.abs()),
+        );
+        let text_color = ui.visuals().text_color();
+        let galley =
+            ui.painter()
+                .layout_no_wrap(text, TextStyle::Small.resolve(ui.style()), text_color);
+
+        // Next to the end of the line, but within the frame.
+        let frame = transform.frame();
+        let rect = Rect::from_min_size(end + vec2(8.0, 8.0), galley.size()).expand(4.0);
+        let rect = rect.translate(Vec2::new(
+            (frame.right() - rect.right()).min(0.0) + (frame.left() - rect.left()).max(0.0),
+            (frame.bottom() - rect.bottom()).min(0.0) + (frame.top() - rect.top()).max(0.0),
+        ));
+        shapes.push(Shape::rect_filled(
+            rect,
+            4.0,
+            ui.visuals().extreme_bg_color.gamma_multiply(0.85),
+        ));
+        shapes.push(Shape::galley(rect.min + vec2(4.0, 4.0), galley, text_color));
+        shapes
+    }
+}
+
+/// Formats the value with as many decimals as it takes to show the resolution.
+fn format_measure(value: f64, resolution: f64) -> String {
+    if value.is_infinite() {
+        return if value < 0.0 { "-∞" } else { "∞" }.to_owned();
+    }
+    let decimals = if resolution > 0.0 {
+        (-resolution.log10()).ceil().clamp(0.0, 12.0) as usize
+    } else {
+        3
+    };
+    format!("{value:.decimals$}")
+}
+
+#[test]
+fn test_measurement() {
+    let measurement = Measurement {
+        start: PlotPoint::new(1.0, 1.0),
+        end: PlotPoint::new(4.0, 5.0),
+    };
+    assert_eq!(measurement.distance(), 5.0);
+    assert_eq!(measurement.slope(), 4.0 / 3.0);
+    assert_eq!(format_measure(measurement.slope(), 1e-3), "1.333");
+    assert_eq!(format_measure(measurement.dx(), 0.5), "3.0");
+    assert_eq!(format_measure(f64::NEG_INFINITY, f64::INFINITY), "-∞");
+}
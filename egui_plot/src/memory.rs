@@ -1,8 +1,10 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 
-use egui::{Context, Id, Pos2, Vec2b};
+use egui::{Context, Id, Pos2, Rect, Vec2, Vec2b};
 
-use crate::{PlotBounds, PlotTransform};
+use crate::{
+    HeatmapSelection, Measurement, Pin, PlotBounds, PlotPoint, PlotTransform, RenderQuality,
+};
 
 /// Information about the plot that has to persist between frames.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -32,6 +34,76 @@ pub struct PlotMemory {
     /// in order to fit the labels, if necessary.
     pub(crate) x_axis_thickness: BTreeMap<usize, f32>,
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
+
+    /// The width or height of a legend outside the plot the previous frame, see [`crate::Legend::outside`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_thickness: f32,
+
+    /// The text typed into the filter field of the legend, see [`crate::Legend::filter`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_filter: String,
+
+    /// How far the user dragged the legend, see [`crate::Legend::movable`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_offset: Vec2,
+
+    /// The order the user dragged the legend entries in, see [`crate::Legend::reorderable`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_order: Vec<String>,
+
+    /// The names of the legend entries and where they were shown the previous frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) legend_entry_rects: Vec<(String, Rect)>,
+
+    /// Views saved under a name with [`crate::PlotUi::save_view`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) saved_views: BTreeMap<String, SavedView>,
+
+    /// The quality to draw the items with, adapted to the frame budget.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) render_quality: RenderQuality,
+
+    /// The smallest step size of the grid marks of each axis the previous frame,
+    /// see [`crate::GridInput::previous_step_size`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) grid_step_sizes: [Option<f64>; 2],
+
+    /// The item id and index of the point being dragged,
+    /// and the offset of the pointer from it when the drag started.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dragged_point: Option<(Id, usize, Vec2)>,
+
+    /// The outline of the lasso while it is being drawn, in plot coordinates.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) lasso: Option<Vec<PlotPoint>>,
+
+    /// Where the user started dragging over selectable heatmap cells, in plot coordinates.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) cell_selection_origin: Option<PlotPoint>,
+
+    /// The heatmap cells selected by the user.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) heatmap_selection: Option<HeatmapSelection>,
+
+    /// The measurements placed by the user, see [`crate::Plot::measure`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) measurements: Vec<Measurement>,
+
+    /// The measurement the user is dragging out.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) measuring: Option<Measurement>,
+
+    /// The hover labels pinned in place by the user.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) pins: Vec<Pin>,
+
+    /// The item id and index of the point selected with the keyboard.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) selected_point: Option<(Id, usize)>,
+
+    /// The earlier views, to undo pans and zooms.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) view_history: ViewHistory,
 }
 
 impl PlotMemory {
@@ -56,6 +128,49 @@ impl PlotMemory {
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.transform.set_bounds(bounds);
     }
+
+    /// The views saved with [`crate::PlotUi::save_view`], by name.
+    #[inline]
+    pub fn saved_views(&self) -> &BTreeMap<String, SavedView> {
+        &self.saved_views
+    }
+
+    /// Save a view under the given name, replacing any view saved under it before.
+    ///
+    /// Unlike [`crate::PlotUi::save_view`] this can be used to prepare views the user hasn't visited yet.
+    #[inline]
+    pub fn insert_saved_view(&mut self, name: impl Into<String>, view: SavedView) {
+        self.saved_views.insert(name.into(), view);
+    }
+
+    /// Forget the view saved under the given name.
+    #[inline]
+    pub fn remove_saved_view(&mut self, name: &str) {
+        self.saved_views.remove(name);
+    }
+
+    /// Where the legend entry with the given name was shown the previous frame, in ui points.
+    ///
+    /// This is `None` if the entry was not shown, e.g. because the legend is collapsed.
+    #[inline]
+    pub fn legend_entry_rect(&self, name: &str) -> Option<Rect> {
+        self.legend_entry_rects
+            .iter()
+            .find(|(entry, _)| entry == name)
+            .map(|(_, rect)| *rect)
+    }
+
+    /// The measurements placed by the user with [`crate::Plot::measure`], oldest first.
+    #[inline]
+    pub fn measurements(&self) -> &[Measurement] {
+        &self.measurements
+    }
+
+    /// Remove all measurements.
+    #[inline]
+    pub fn clear_measurements(&mut self) {
+        self.measurements.clear();
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -79,3 +194,73 @@ impl PlotMemory {
         ctx.data_mut(|d| d.insert_temp(id, self));
     }
 }
+
+/// A view of the plot saved under a name, see [`crate::PlotUi::save_view`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SavedView {
+    /// The bounds of the plot.
+    pub bounds: PlotBounds,
+
+    /// The items hidden in the legend.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hidden_items: ahash::HashSet<String>,
+}
+
+/// The views of the plot to go back and forth between, see [`crate::PlotUi::undo_view`].
+#[derive(Clone, Default)]
+pub(crate) struct ViewHistory {
+    undo: VecDeque<PlotBounds>,
+    redo: Vec<PlotBounds>,
+
+    /// Was the user changing the view last frame?
+    pub changing: bool,
+}
+
+impl ViewHistory {
+    /// How many views are remembered.
+    const MAX_LEN: usize = 100;
+
+    /// Remember the view from before a change by the user.
+    pub fn record(&mut self, bounds: PlotBounds) {
+        if self.undo.back() != Some(&bounds) {
+            self.undo.push_back(bounds);
+            if self.undo.len() > Self::MAX_LEN {
+                self.undo.pop_front();
+            }
+        }
+        self.redo.clear();
+    }
+
+    /// The view to go back to from the current one, if any.
+    pub fn undo(&mut self, current: PlotBounds) -> Option<PlotBounds> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(current);
+        Some(previous)
+    }
+
+    /// The view to go forward to from the current one, if any.
+    pub fn redo(&mut self, current: PlotBounds) -> Option<PlotBounds> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(current);
+        Some(next)
+    }
+}
+
+#[test]
+fn test_view_history() {
+    let bounds = |x: f64| PlotBounds::from_min_max([x, 0.0], [x + 1.0, 1.0]);
+    let mut history = ViewHistory::default();
+    assert_eq!(history.undo(bounds(0.0)), None);
+
+    history.record(bounds(0.0));
+    history.record(bounds(1.0));
+    assert_eq!(history.undo(bounds(2.0)), Some(bounds(1.0)));
+    assert_eq!(history.undo(bounds(1.0)), Some(bounds(0.0)));
+    assert_eq!(history.undo(bounds(0.0)), None);
+    assert_eq!(history.redo(bounds(0.0)), Some(bounds(1.0)));
+
+    // A new change forgets what was undone.
+    history.record(bounds(1.0));
+    assert_eq!(history.redo(bounds(5.0)), None);
+}
@@ -0,0 +1,126 @@
+use egui::{Id, InputState, Key};
+
+use crate::{PlotGeometry, PlotItem, PlotPoint};
+
+/// A point selected with directional input, see [`crate::Plot::keyboard_navigation`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SelectedPoint {
+    /// The id of the item the point belongs to.
+    ///
+    /// For items without an id this is `Id::new(name)`.
+    pub item_id: Id,
+
+    /// The index of the point within the item.
+    pub index: usize,
+
+    /// The point itself, in plot coordinates.
+    pub value: PlotPoint,
+}
+
+impl SelectedPoint {
+    /// Looks up the selected point, if the item still exists and has a point at that index.
+    pub(crate) fn find(items: &[Box<dyn PlotItem>], (item_id, index): (Id, usize)) -> Option<Self> {
+        let (_, points) = navigable_items(items).find(|(id, _)| *id == item_id)?;
+        Some(Self {
+            item_id,
+            index,
+            value: *points.get(index)?,
+        })
+    }
+}
+
+/// Moves the selection according to the arrow keys pressed this frame.
+///
+/// Left and right step through the points of the selected item,
+/// up and down switch to the point of the next or previous item that is closest in x.
+/// Escape clears the selection.
+pub(crate) fn navigate(
+    selected: Option<(Id, usize)>,
+    items: &[Box<dyn PlotItem>],
+    input: &InputState,
+) -> Option<(Id, usize)> {
+    if input.key_pressed(Key::Escape) {
+        return None;
+    }
+
+    let items: Vec<(Id, &[PlotPoint])> = navigable_items(items).collect();
+    let current = selected.and_then(|(item_id, index)| {
+        let item_index = items.iter().position(|(id, _)| *id == item_id)?;
+        Some((item_index, index.min(items[item_index].1.len() - 1)))
+    });
+
+    let arrow_pressed = [
+        Key::ArrowLeft,
+        Key::ArrowRight,
+        Key::ArrowUp,
+        Key::ArrowDown,
+    ]
+    .into_iter()
+    .any(|key| input.key_pressed(key));
+    let Some((mut item_index, mut index)) = current else {
+        // Start at the first point once the user presses an arrow key.
+        return arrow_pressed
+            .then(|| items.first().map(|(id, _)| (*id, 0)))
+            .flatten();
+    };
+
+    if input.key_pressed(Key::ArrowLeft) {
+        index = index.saturating_sub(1);
+    }
+    if input.key_pressed(Key::ArrowRight) {
+        index = (index + 1).min(items[item_index].1.len() - 1);
+    }
+
+    let item_step = match (
+        input.key_pressed(Key::ArrowUp),
+        input.key_pressed(Key::ArrowDown),
+    ) {
+        (true, false) => 1,
+        (false, true) => items.len() - 1,
+        _ => 0,
+    };
+    if item_step != 0 {
+        let x = items[item_index].1[index].x;
+        item_index = (item_index + item_step) % items.len();
+        index = closest_in_x(items[item_index].1, x);
+    }
+
+    Some((items[item_index].0, index))
+}
+
+/// The items that have points to navigate between, with their ids.
+fn navigable_items(items: &[Box<dyn PlotItem>]) -> impl Iterator<Item = (Id, &[PlotPoint])> + '_ {
+    items
+        .iter()
+        .filter(|item| item.allow_hover())
+        .filter_map(|item| match item.geometry() {
+            PlotGeometry::Points(points) if !points.is_empty() => {
+                Some((item.id().unwrap_or_else(|| Id::new(item.name())), points))
+            }
+            PlotGeometry::Points(_) | PlotGeometry::None | PlotGeometry::Rects => None,
+        })
+}
+
+/// Index of the point with the x-value closest to the given one.
+pub(crate) fn closest_in_x(points: &[PlotPoint], x: f64) -> usize {
+    points
+        .iter()
+        .enumerate()
+        .filter(|(_, point)| point.x.is_finite())
+        .min_by(|(_, a), (_, b)| (a.x - x).abs().total_cmp(&(b.x - x).abs()))
+        .map_or(0, |(index, _)| index)
+}
+
+#[test]
+fn test_closest_in_x() {
+    let points = [
+        PlotPoint::new(0.0, 1.0),
+        PlotPoint::new(f64::NAN, 1.0),
+        PlotPoint::new(2.0, 1.0),
+        PlotPoint::new(5.0, 1.0),
+    ];
+    assert_eq!(closest_in_x(&points, -3.0), 0);
+    assert_eq!(closest_in_x(&points, 1.8), 2);
+    assert_eq!(closest_in_x(&points, 4.0), 3);
+    assert_eq!(closest_in_x(&[], 4.0), 0);
+}
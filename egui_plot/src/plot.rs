@@ -0,0 +1,4048 @@
+//! The [`Plot`] widget, and how it lays out, paints and interacts with its items.
+
+use std::{cmp::Ordering, ops::RangeInclusive, sync::Arc, time::Duration};
+
+use ahash::HashMap;
+use egui::{
+    epaint, remap_clamp, vec2, Color32, CursorIcon, Id, Key, KeyboardShortcut, Layout, Modifiers,
+    NumExt, Painter, PointerButton, Pos2, Rangef, Rect, Response, Rounding, Sense, Shape, Stroke,
+    Ui, Vec2, Vec2b, WidgetText,
+};
+use emath::Float as _;
+
+use crate::{
+    axis::{self, AxisHints, AxisWidget, HPlacement, VPlacement},
+    cache::ItemCache,
+    calendar::{time_formatter, time_grid_spacer},
+    categories::Categories,
+    description::{self, ItemDescription, PlotDescription},
+    error::PlotError,
+    input::PlotInput,
+    inset,
+    items::{
+        self, horizontal_line, rulers_color, vertical_line, ClosestElem, HeatmapSelection,
+        LineStyle, PlotGeometry, PlotItem, PlotLayer,
+    },
+    label_layout::LabelLayout,
+    lasso::LassoSelection,
+    legend::{self, Corner, Legend, LegendDock, LegendWidget},
+    measure::Measurement,
+    memory::{PlotMemory, SavedView},
+    navigation::{self, SelectedPoint},
+    plot_ui::PlotUi,
+    polar,
+    quality::RenderQuality,
+    relative_time::{relative_time_formatter, relative_time_grid_spacer},
+    transform, Axis, AxisScale, BoundsMargin, PlotBounds, PlotPoint, PlotTransform,
+};
+
+#[cfg(feature = "serde")]
+use crate::session;
+
+pub(crate) type LabelFormatterFn<'a> = dyn Fn(&str, &PlotPoint) -> String + 'a;
+pub type LabelFormatter<'a> = Option<Box<LabelFormatterFn<'a>>>;
+
+pub(crate) type ValueFormatterFn<'a> = dyn Fn(Axis, f64) -> String + 'a;
+
+type InputFilterFn<'a> = dyn Fn(PlotInput) -> PlotInput + 'a;
+
+type GridSpacerFn<'a> = dyn Fn(GridInput) -> Vec<GridMark> + 'a;
+pub(crate) type GridSpacer<'a> = Box<GridSpacerFn<'a>>;
+
+type GridStrokeFn<'a> = dyn Fn(GridMark) -> GridStroke + 'a;
+
+type PaintHookFn<'a> = dyn Fn(&Painter, &PlotTransform) + 'a;
+
+type CoordinatesFormatterFn<'a> = dyn Fn(&PlotPoint, &PlotBounds) -> String + 'a;
+type CoordinatesWidgetFn<'a> = dyn Fn(&mut Ui, &PlotPoint, &PlotBounds) + 'a;
+
+/// Specifies the coordinates formatting when passed to [`Plot::coordinates_formatter`].
+pub struct CoordinatesFormatter<'a> {
+    /// `None` formats the coordinates with the [`Plot::value_formatter`].
+    function: Option<Box<CoordinatesFormatterFn<'a>>>,
+    widget: Option<Box<CoordinatesWidgetFn<'a>>>,
+    copy_on_click: bool,
+}
+
+impl<'a> CoordinatesFormatter<'a> {
+    /// Create a new formatter based on the pointer coordinate and the plot bounds.
+    pub fn new(function: impl Fn(&PlotPoint, &PlotBounds) -> String + 'a) -> Self {
+        Self {
+            function: Some(Box::new(function)),
+            ..Default::default()
+        }
+    }
+
+    /// Show a custom widget instead of the text, given the pointer coordinate and the plot bounds.
+    ///
+    /// The widget is laid out in the corner of the plot, and is only shown while the plot is hovered.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{CoordinatesFormatter, Corner, Plot};
+    /// Plot::new("my_plot")
+    ///     .coordinates_formatter(
+    ///         Corner::RightTop,
+    ///         CoordinatesFormatter::widget(|ui, point, _bounds| {
+    ///             ui.strong(format!("{:.1} s", point.x));
+    ///             ui.label(format!("{:.3} V", point.y));
+    ///         }),
+    ///     )
+    ///     .show(ui, |_plot_ui| ());
+    /// # });
+    /// ```
+    pub fn widget(widget: impl Fn(&mut Ui, &PlotPoint, &PlotBounds) + 'a) -> Self {
+        Self {
+            widget: Some(Box::new(widget)),
+            ..Default::default()
+        }
+    }
+
+    /// Copy the text of the readout to the clipboard when the plot is clicked. Default: `false`.
+    ///
+    /// Does nothing for a [`Self::widget`].
+    #[inline]
+    pub fn copy_on_click(mut self, copy_on_click: bool) -> Self {
+        self.copy_on_click = copy_on_click;
+        self
+    }
+
+    /// Show a fixed number of decimal places.
+    pub fn with_decimals(num_decimals: usize) -> Self {
+        Self::new(move |value, _| {
+            format!("x: {:.d$}\ny: {:.d$}", value.x, value.y, d = num_decimals)
+        })
+    }
+
+    fn format(
+        &self,
+        value: &PlotPoint,
+        bounds: &PlotBounds,
+        value_formatter: Option<&ValueFormatterFn<'_>>,
+    ) -> String {
+        match (&self.function, value_formatter) {
+            (Some(function), _) => function(value, bounds),
+            (None, Some(format)) => {
+                format!(
+                    "x: {}\ny: {}",
+                    format(Axis::X, value.x),
+                    format(Axis::Y, value.y)
+                )
+            }
+            (None, None) => format!("x: {:.3}\ny: {:.3}", value.x, value.y),
+        }
+    }
+
+    fn ui(
+        &self,
+        ui: &mut Ui,
+        value: &PlotPoint,
+        bounds: &PlotBounds,
+        value_formatter: Option<&ValueFormatterFn<'_>>,
+        response: &Response,
+    ) {
+        if let Some(widget) = &self.widget {
+            widget(ui, value, bounds);
+            return;
+        }
+
+        let text = self.format(value, bounds, value_formatter);
+        if self.copy_on_click && response.clicked() {
+            ui.ctx().copy_text(text.clone());
+        }
+        ui.add(
+            egui::Label::new(egui::RichText::new(text).monospace())
+                .selectable(false)
+                .wrap_mode(egui::TextWrapMode::Extend),
+        );
+    }
+}
+
+/// Uses the [`Plot::value_formatter`] if there is one, and three decimals otherwise.
+impl Default for CoordinatesFormatter<'_> {
+    fn default() -> Self {
+        Self {
+            function: None,
+            widget: None,
+            copy_on_click: false,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Indicates a vertical or horizontal cursor line in plot coordinates.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Cursor {
+    Horizontal { y: f64 },
+    Vertical { x: f64 },
+}
+
+/// Contains the cursors drawn for a plot widget in a single frame.
+#[derive(PartialEq, Clone)]
+struct PlotFrameCursors {
+    id: Id,
+    cursors: Vec<Cursor>,
+}
+
+#[derive(Default, Clone)]
+struct CursorLinkGroups(HashMap<Id, Vec<PlotFrameCursors>>);
+
+#[derive(Clone)]
+struct LinkedBounds {
+    bounds: PlotBounds,
+    auto_bounds: Vec2b,
+
+    /// The union of the automatic bounds of the plots in the group, see [`LinkReset::Group`].
+    data_bounds: PlotBounds,
+
+    /// The [`Self::data_bounds`] of the frame before [`Self::pass_nr`].
+    last_data_bounds: PlotBounds,
+
+    /// The frame the [`Self::data_bounds`] were collected in.
+    pass_nr: u64,
+}
+
+impl LinkedBounds {
+    /// The union of the automatic bounds of the plots in the group,
+    /// of this frame so far and of the frame before.
+    fn data_bounds(&self, pass_nr: u64) -> PlotBounds {
+        let mut bounds = self.data_bounds;
+        if self.pass_nr == pass_nr {
+            bounds.merge(&self.last_data_bounds);
+        } else if self.pass_nr + 1 != pass_nr {
+            bounds = PlotBounds::NOTHING;
+        }
+        bounds
+    }
+
+    /// Add the automatic bounds of a plot in the group.
+    fn add_data_bounds(&mut self, pass_nr: u64, bounds: &PlotBounds, axes: Vec2b) {
+        if self.pass_nr != pass_nr {
+            self.last_data_bounds = self.data_bounds(pass_nr);
+            self.data_bounds = PlotBounds::NOTHING;
+            self.pass_nr = pass_nr;
+        }
+        if axes.x {
+            self.data_bounds.merge_x(bounds);
+        }
+        if axes.y {
+            self.data_bounds.merge_y(bounds);
+        }
+    }
+}
+
+/// What double-clicking a plot resets in its axis link groups, see [`Plot::link_reset`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LinkReset {
+    /// Fit the linked axes to the data of all plots in the group.
+    ///
+    /// This also applies while the linked axes are automatically bounded in the first place.
+    #[default]
+    Group,
+
+    /// Fit the linked axes to the data of the double-clicked plot, and show the same range in
+    /// the other plots of the group.
+    Broadcast,
+}
+
+/// When a plot takes the scrolling of the mouse wheel or touchpad for scrolling itself,
+/// see [`Plot::capture_scroll`].
+///
+/// Zooming by pinching or with Ctrl+wheel (Cmd+wheel on Mac) works regardless,
+/// since it doesn't scroll a surrounding [`egui::ScrollArea`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CaptureScroll {
+    /// Whenever the pointer is over the plot.
+    ///
+    /// The plot uses up the scrolling, so a surrounding [`egui::ScrollArea`] doesn't scroll along.
+    #[default]
+    WhenHovered,
+
+    /// Only while the pointer is over the plot and these modifiers are held.
+    ///
+    /// Otherwise the scrolling is left to a surrounding [`egui::ScrollArea`],
+    /// so the page can be scrolled past the plot.
+    WithModifier(Modifiers),
+
+    /// Never, leaving the scrolling to a surrounding [`egui::ScrollArea`].
+    ///
+    /// The plot can still be panned by dragging, and zoomed by pinching or with a box, if allowed.
+    Never,
+}
+
+#[derive(Default, Clone)]
+struct BoundsLinkGroups(HashMap<Id, LinkedBounds>);
+
+// ----------------------------------------------------------------------------
+
+/// What [`Plot::show`] returns.
+pub struct PlotResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the plot.
+    pub response: Response,
+
+    /// The transform between screen coordinates and plot coordinates.
+    pub transform: PlotTransform,
+
+    /// The id of a currently hovered item if any.
+    ///
+    /// This is `None` if either no item was hovered, or the hovered item didn't provide an id.
+    pub hovered_plot_item: Option<Id>,
+
+    /// The ids of all items within reach of the pointer, starting with the [`Self::hovered_plot_item`].
+    ///
+    /// Useful for a tooltip or context menu listing everything under the pointer.
+    /// Like for [`Self::hovered_plot_item`], items without an id are left out.
+    pub hovered_plot_items: Vec<Id>,
+
+    /// The quality the items were drawn with this frame.
+    ///
+    /// This is only ever lowered if [`Plot::frame_budget`] is set.
+    pub render_quality: RenderQuality,
+
+    /// How many meshes the items were drawn with this frame.
+    ///
+    /// Large fills, heatmaps and hexbins are split into several meshes to stay below
+    /// the vertex limit of some backends, so this grows with the amount of filled data.
+    pub mesh_count: usize,
+
+    /// The point the user is dragging, if any.
+    ///
+    /// Only points of items that are [`Points::draggable`] or [`Line::draggable`] can be dragged.
+    pub dragged_point: Option<DraggedPoint>,
+
+    /// The region of the box dragged out by the user for boxed zooming, in plot coordinates.
+    ///
+    /// This is set while the box is being dragged, and in the frame it is released.
+    /// See also [`Plot::box_selection`].
+    pub boxed_zoom: Option<PlotBounds>,
+
+    /// Is the user currently dragging out a box?
+    ///
+    /// If this is `false` while [`Self::boxed_zoom`] is set, the box was just released.
+    pub boxed_zoom_in_progress: bool,
+
+    /// The points the user selected with the lasso, in the frame the lasso was released.
+    ///
+    /// See [`Plot::lasso_selection`].
+    pub lasso_selection: Option<LassoSelection>,
+
+    /// The heatmap cells selected by the user, see [`Heatmap::selectable`].
+    ///
+    /// The selection is kept until the user clicks outside of the selectable heatmaps.
+    pub heatmap_selection: Option<HeatmapSelection>,
+
+    /// The point selected with the arrow keys, see [`Plot::keyboard_navigation`].
+    pub selected_point: Option<SelectedPoint>,
+
+    /// The selected point, in the frame the user activated it with enter or space.
+    pub activated_point: Option<SelectedPoint>,
+
+    /// The value under the pointer, if the plot is hovered.
+    ///
+    /// This is snapped to a sample if [`Plot::snap_to_item`] is set,
+    /// or to the grid lines if [`Plot::snap_to_grid`] is set.
+    /// The raw value is `transform.value_from_position(pointer)`.
+    pub hovered_value: Option<PlotPoint>,
+
+    description: PlotDescription,
+}
+
+impl<R> PlotResponse<R> {
+    /// A summary of what the plot showed this frame: the bounds, the items, the axis ticks and the legend.
+    ///
+    /// This is meant for tests that check the content of a plot without comparing screenshots.
+    pub fn snapshot_description(&self) -> &PlotDescription {
+        &self.description
+    }
+}
+
+/// A hover label pinned in place by the user, see [`Plot::pin_on_click`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub(crate) struct Pin {
+    /// The name of the pinned item.
+    pub name: String,
+
+    /// The pinned value.
+    pub value: PlotPoint,
+}
+
+/// What the pointer hovers in a plot, see [`PreparedPlot::hover`].
+#[derive(Default)]
+struct HoverResult {
+    /// The rulers to draw, and to link to other plots.
+    cursors: Vec<Cursor>,
+
+    /// The item shown in the hover label.
+    item: Option<Id>,
+
+    /// All items within reach of the pointer, by descending priority and then by distance.
+    items: Vec<Id>,
+
+    /// The label to pin when clicking.
+    pin: Option<Pin>,
+}
+
+/// A point of a plot item that is being dragged by the user, see [`PlotResponse::dragged_point`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DraggedPoint {
+    /// The id of the item the point belongs to.
+    ///
+    /// For items without an id this is `Id::new(name)`.
+    pub item_id: Id,
+
+    /// The index of the point within the item.
+    pub index: usize,
+
+    /// Where the user wants the point to be, in plot coordinates.
+    pub position: PlotPoint,
+}
+
+// ----------------------------------------------------------------------------
+
+/// A 2D plot, e.g. a graph of a function.
+///
+/// [`Plot`] supports multiple lines and points.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, Plot, PlotPoints};
+///
+/// let sin: PlotPoints = (0..1000).map(|i| {
+///     let x = i as f64 * 0.01;
+///     [x, x.sin()]
+/// }).collect();
+/// let line = Line::new(sin);
+/// Plot::new("my_plot").view_aspect(2.0).show(ui, |plot_ui| plot_ui.line(line));
+/// # });
+/// ```
+pub struct Plot<'a> {
+    id_source: Id,
+    id: Option<Id>,
+
+    center_axis: Vec2b,
+    allow_zoom: Vec2b,
+    allow_drag: Vec2b,
+    allow_scroll: Vec2b,
+    input_filter: Option<Box<InputFilterFn<'a>>>,
+    allow_double_click_reset: bool,
+    view_history_shortcuts: bool,
+    view_bookmark_shortcuts: bool,
+    allow_boxed_zoom: bool,
+    box_selection: bool,
+    bounds_limit: Option<PlotBounds>,
+    min_bounds_size: [f64; 2],
+    max_bounds_size: [f64; 2],
+    lasso_selection: Option<Modifiers>,
+    measure: Option<Modifiers>,
+    default_auto_bounds: Vec2b,
+    min_auto_bounds: PlotBounds,
+    keep_included: bool,
+    assert_finite: bool,
+    margin_fraction: Vec2,
+    bounds_margin: Option<BoundsMargin>,
+    boxed_zoom_pointer_button: PointerButton,
+    linked_axes: Vec<(Id, Vec2b)>,
+    link_reset: LinkReset,
+    capture_scroll: CaptureScroll,
+    linked_cursors: Option<(Id, Vec2b)>,
+
+    min_size: Vec2,
+    width: Option<f32>,
+    height: Option<f32>,
+    data_aspect: Option<f32>,
+    polar: bool,
+    view_aspect: Option<f32>,
+
+    reset: bool,
+
+    show_x: bool,
+    show_y: bool,
+    show_delta: bool,
+    pin_on_click: Option<PointerButton>,
+    keyboard_navigation: bool,
+    color_group: Option<Id>,
+    label_formatter: LabelFormatter<'a>,
+    value_formatter: Option<Box<ValueFormatterFn<'a>>>,
+    coordinates_formatters: Vec<(Corner, CoordinatesFormatter<'a>)>,
+    x_axes: Vec<AxisHints<'a>>, // default x axes
+    y_axes: Vec<AxisHints<'a>>, // default y axes
+    legend_config: Option<Legend>,
+    cursor_color: Option<Color32>,
+    show_background: bool,
+    show_axes: Vec2b,
+    axis_value_tags: Vec2b,
+
+    show_grid: Vec2b,
+    minor_grid: Vec2b,
+    minor_grid_subdivisions: usize,
+    grid_spacing: Rangef,
+    grid_spacers: [GridSpacer<'a>; 2],
+    grid_strokes: [Option<Box<GridStrokeFn<'a>>>; 2],
+    grid_styles: [LineStyle; 2],
+    snap_to_grid: Option<f32>,
+    snap_to_item: Option<Id>,
+    before_items: Option<Box<PaintHookFn<'a>>>,
+    after_items: Option<Box<PaintHookFn<'a>>>,
+    scales: [AxisScale; 2],
+    clamp_grid: bool,
+    frame_budget: Option<Duration>,
+
+    sense: Sense,
+}
+
+impl<'a> Plot<'a> {
+    /// Give a unique id for each plot within the same [`Ui`].
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            id: None,
+
+            center_axis: false.into(),
+            allow_zoom: true.into(),
+            allow_drag: true.into(),
+            allow_scroll: true.into(),
+            input_filter: None,
+            allow_double_click_reset: true,
+            view_history_shortcuts: false,
+            view_bookmark_shortcuts: false,
+            allow_boxed_zoom: true,
+            box_selection: false,
+            bounds_limit: None,
+            min_bounds_size: [0.0; 2],
+            max_bounds_size: [f64::INFINITY; 2],
+            lasso_selection: None,
+            measure: None,
+            default_auto_bounds: true.into(),
+            min_auto_bounds: PlotBounds::NOTHING,
+            keep_included: false,
+            assert_finite: false,
+            margin_fraction: Vec2::splat(0.05),
+            bounds_margin: None,
+            boxed_zoom_pointer_button: PointerButton::Secondary,
+            linked_axes: Vec::new(),
+            link_reset: LinkReset::default(),
+            capture_scroll: CaptureScroll::default(),
+            linked_cursors: None,
+
+            min_size: Vec2::splat(64.0),
+            width: None,
+            height: None,
+            data_aspect: None,
+            polar: false,
+            view_aspect: None,
+
+            reset: false,
+
+            show_x: true,
+            show_y: true,
+            show_delta: false,
+            pin_on_click: None,
+            keyboard_navigation: false,
+            color_group: None,
+            label_formatter: None,
+            value_formatter: None,
+            coordinates_formatters: Vec::new(),
+            x_axes: vec![AxisHints::new(Axis::X)],
+            y_axes: vec![AxisHints::new(Axis::Y)],
+            legend_config: None,
+            cursor_color: None,
+            show_background: true,
+            show_axes: true.into(),
+            axis_value_tags: false.into(),
+
+            show_grid: true.into(),
+            minor_grid: false.into(),
+            minor_grid_subdivisions: 5,
+            grid_spacing: Rangef::new(8.0, 300.0),
+            grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
+            grid_strokes: [None, None],
+            grid_styles: [LineStyle::Solid; 2],
+            snap_to_grid: None,
+            snap_to_item: None,
+            before_items: None,
+            after_items: None,
+            scales: Default::default(),
+            clamp_grid: false,
+            frame_budget: None,
+
+            sense: egui::Sense::click_and_drag(),
+        }
+    }
+
+    /// Set an explicit (global) id for the plot.
+    ///
+    /// This will override the id set by [`Self::new`].
+    ///
+    /// This is the same `Id` that can be used for [`PlotMemory::load`].
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// width / height ratio of the data.
+    /// For instance, it can be useful to set this to `1.0` for when the two axes show the same
+    /// unit.
+    /// By default the plot window's aspect ratio is used.
+    #[inline]
+    pub fn data_aspect(mut self, data_aspect: f32) -> Self {
+        self.data_aspect = Some(data_aspect);
+        self
+    }
+
+    /// Show the plot in polar coordinates, with a grid of circles around the origin and radial spokes.
+    ///
+    /// Give the points of the items as `[angle, radius]` with [`PlotPoints::from_polar`].
+    /// The view always includes the origin and keeps the circles round while panning and zooming,
+    /// and the hover label shows the angle and radius.
+    /// Bars, boxes and horizontal or vertical lines are still drawn in x and y. Default: `false`.
+    #[inline]
+    pub fn polar(mut self, polar: bool) -> Self {
+        self.polar = polar;
+        self
+    }
+
+    /// width / height ratio of the plot region.
+    /// By default no fixed aspect ratio is set (and width/height will fill the ui it is in).
+    #[inline]
+    pub fn view_aspect(mut self, view_aspect: f32) -> Self {
+        self.view_aspect = Some(view_aspect);
+        self
+    }
+
+    /// Width of plot. By default a plot will fill the ui it is in.
+    /// If you set [`Self::view_aspect`], the width can be calculated from the height.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.min_size.x = width;
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of plot. By default a plot will fill the ui it is in.
+    /// If you set [`Self::view_aspect`], the height can be calculated from the width.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.min_size.y = height;
+        self.height = Some(height);
+        self
+    }
+
+    /// Minimum size of the plot view.
+    #[inline]
+    pub fn min_size(mut self, min_size: Vec2) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Show the x-value (e.g. when hovering). Default: `true`.
+    #[inline]
+    pub fn show_x(mut self, show_x: bool) -> Self {
+        self.show_x = show_x;
+        self
+    }
+
+    /// Show the y-value (e.g. when hovering). Default: `true`.
+    #[inline]
+    pub fn show_y(mut self, show_y: bool) -> Self {
+        self.show_y = show_y;
+        self
+    }
+
+    /// When hovering a point, also show how much its y-value changed compared to the previous
+    /// point of the same series, in absolute terms and in percent.
+    ///
+    /// Has no effect if a [`Self::label_formatter`] is set. Default: `false`.
+    #[inline]
+    pub fn show_delta(mut self, show_delta: bool) -> Self {
+        self.show_delta = show_delta;
+        self
+    }
+
+    /// Pin the hover label of the hovered item in place by clicking with the given button,
+    /// so that several values can be compared at once.
+    ///
+    /// Clicking where no item is hovered removes all pins.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn pin_on_click(mut self, button: PointerButton) -> Self {
+        self.pin_on_click = Some(button);
+        self
+    }
+
+    /// Let the user select data points with the arrow keys, e.g. on devices without a pointer.
+    ///
+    /// Once the plot has keyboard focus, left and right step through the points of an item,
+    /// up and down switch between items, and enter or space activates the selected point.
+    /// Escape clears the selection.
+    ///
+    /// See [`PlotResponse::selected_point`] and [`PlotResponse::activated_point`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn keyboard_navigation(mut self, enabled: bool) -> Self {
+        self.keyboard_navigation = enabled;
+        self
+    }
+
+    /// Give items with the same name the same color in all plots of the group.
+    pub(crate) fn color_group(mut self, group: Id) -> Self {
+        self.color_group = Some(group);
+        self
+    }
+
+    /// Always keep the X-axis centered. Default: `false`.
+    #[inline]
+    pub fn center_x_axis(mut self, on: bool) -> Self {
+        self.center_axis.x = on;
+        self
+    }
+
+    /// Always keep the Y-axis centered. Default: `false`.
+    #[inline]
+    pub fn center_y_axis(mut self, on: bool) -> Self {
+        self.center_axis.y = on;
+        self
+    }
+
+    /// Whether to allow zooming in the plot. Default: `true`.
+    ///
+    /// Note: Allowing zoom in one axis but not the other may lead to unexpected results if used in combination with `data_aspect`.
+    #[inline]
+    pub fn allow_zoom<T>(mut self, on: T) -> Self
+    where
+        T: Into<Vec2b>,
+    {
+        self.allow_zoom = on.into();
+        self
+    }
+
+    /// Whether to allow scrolling in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_scroll<T>(mut self, on: T) -> Self
+    where
+        T: Into<Vec2b>,
+    {
+        self.allow_scroll = on.into();
+        self
+    }
+
+    /// When to take the scrolling of the mouse wheel or touchpad for scrolling the plot.
+    /// Default: [`CaptureScroll::WhenHovered`].
+    ///
+    /// For a plot in a [`egui::ScrollArea`], use [`CaptureScroll::WithModifier`] or
+    /// [`CaptureScroll::Never`] to let the page scroll past the plot.
+    #[inline]
+    pub fn capture_scroll(mut self, capture_scroll: CaptureScroll) -> Self {
+        self.capture_scroll = capture_scroll;
+        self
+    }
+
+    /// Intercept the pans and zooms of the user before they are applied, to change or drop them.
+    ///
+    /// This lets you adjust the built-in interactions instead of turning them off and
+    /// reimplementing them, e.g. to quantize the zoom or to not pan below zero:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::Plot;
+    /// Plot::new("my_plot")
+    ///     .input_filter(|mut input| {
+    ///         if input.bounds_after().min()[1] < 0.0 {
+    ///             input.drag_delta.y = 0.0;
+    ///             input.scroll_delta.y = 0.0;
+    ///         }
+    ///         input
+    ///     })
+    ///     .show(ui, |plot_ui| {});
+    /// # });
+    /// ```
+    #[inline]
+    pub fn input_filter(mut self, filter: impl Fn(PlotInput) -> PlotInput + 'a) -> Self {
+        self.input_filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Whether to allow double clicking to reset the view.
+    /// Default: `true`.
+    #[inline]
+    pub fn allow_double_click_reset(mut self, on: bool) -> Self {
+        self.allow_double_click_reset = on;
+        self
+    }
+
+    /// Undo pans and zooms with Ctrl+Z (Cmd+Z on Mac) while the plot is hovered,
+    /// and redo them with Ctrl+Shift+Z or Ctrl+Y.
+    ///
+    /// See also [`PlotUi::undo_view`] and [`PlotUi::redo_view`]. Default: `false`.
+    #[inline]
+    pub fn view_history_shortcuts(mut self, on: bool) -> Self {
+        self.view_history_shortcuts = on;
+        self
+    }
+
+    /// Save the current view under the names `"1"` to `"9"` with Ctrl+Shift+1..9 (Cmd+Shift on Mac)
+    /// while the plot is hovered, and go back to them with Shift+1..9.
+    ///
+    /// See also [`PlotUi::save_view`] and [`PlotUi::restore_view`]. Default: `false`.
+    #[inline]
+    pub fn view_bookmark_shortcuts(mut self, on: bool) -> Self {
+        self.view_bookmark_shortcuts = on;
+        self
+    }
+
+    /// Set the side margin as a fraction of the plot size. Only used for auto bounds.
+    ///
+    /// For instance, a value of `0.1` will add 10% space on both sides.
+    #[inline]
+    pub fn set_margin_fraction(mut self, margin_fraction: Vec2) -> Self {
+        self.margin_fraction = margin_fraction;
+        self
+    }
+
+    /// Set the margin around the data for each side separately,
+    /// either as a fraction of the data range or in ui points. Only used for auto bounds.
+    ///
+    /// This overrides [`Self::set_margin_fraction`].
+    #[inline]
+    pub fn bounds_margin(mut self, margin: BoundsMargin) -> Self {
+        self.bounds_margin = Some(margin);
+        self
+    }
+
+    /// Whether to allow zooming in the plot by dragging out a box with the secondary mouse button.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn allow_boxed_zoom(mut self, on: bool) -> Self {
+        self.allow_boxed_zoom = on;
+        self
+    }
+
+    /// Use the box dragged out for boxed zooming to select a region, without zooming into it.
+    ///
+    /// The selected region is reported in [`PlotResponse::boxed_zoom`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn box_selection(mut self, on: bool) -> Self {
+        self.box_selection = on;
+        self
+    }
+
+    /// Never let the view leave the given region, however the plot is dragged or zoomed.
+    ///
+    /// If the view is larger than the region along an axis, it is shrunk to fit.
+    /// Use infinite values to leave a side unconstrained.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn bounds_limit(mut self, limit: PlotBounds) -> Self {
+        self.bounds_limit = Some(limit);
+        self
+    }
+
+    /// Limit how far the user can zoom in, as the smallest width and height of the view
+    /// in plot coordinates.
+    ///
+    /// Default: `[0.0, 0.0]`.
+    #[inline]
+    pub fn min_bounds_size(mut self, min_size: [f64; 2]) -> Self {
+        self.min_bounds_size = min_size;
+        self
+    }
+
+    /// Limit how far the user can zoom out, as the largest width and height of the view
+    /// in plot coordinates.
+    ///
+    /// Default: no limit.
+    #[inline]
+    pub fn max_bounds_size(mut self, max_size: [f64; 2]) -> Self {
+        self.max_bounds_size = max_size;
+        self
+    }
+
+    /// Let the user select points by drawing a freeform outline around them:
+    /// hold down the given modifiers and drag with the primary mouse button.
+    ///
+    /// While drawing, the plot is not panned.
+    /// The selected points are reported in [`PlotResponse::lasso_selection`].
+    ///
+    /// Default: `None` (disabled).
+    #[inline]
+    pub fn lasso_selection(mut self, modifiers: Modifiers) -> Self {
+        self.lasso_selection = Some(modifiers);
+        self
+    }
+
+    /// Let the user measure the distance between two points:
+    /// hold down the given modifiers and drag with the primary mouse button from one to the other.
+    ///
+    /// The measurements show `Δx`, `Δy`, the distance and the slope, and are kept in the
+    /// [`PlotMemory`] until the user clicks with the modifiers held, or they are cleared
+    /// with [`PlotMemory::clear_measurements`]. While measuring, the plot is not panned.
+    ///
+    /// Default: `None` (disabled).
+    #[inline]
+    pub fn measure(mut self, modifiers: Modifiers) -> Self {
+        self.measure = Some(modifiers);
+        self
+    }
+
+    /// Config the button pointer to use for boxed zooming. Default: [`Secondary`](PointerButton::Secondary)
+    #[inline]
+    pub fn boxed_zoom_pointer_button(mut self, boxed_zoom_pointer_button: PointerButton) -> Self {
+        self.boxed_zoom_pointer_button = boxed_zoom_pointer_button;
+        self
+    }
+
+    /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
+    #[inline]
+    pub fn allow_drag<T>(mut self, on: T) -> Self
+    where
+        T: Into<Vec2b>,
+    {
+        self.allow_drag = on.into();
+        self
+    }
+
+    /// Provide a function to customize the on-hover label for the x and y axis
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints};
+    /// let sin: PlotPoints = (0..1000).map(|i| {
+    ///     let x = i as f64 * 0.01;
+    ///     [x, x.sin()]
+    /// }).collect();
+    /// let line = Line::new(sin);
+    /// Plot::new("my_plot").view_aspect(2.0)
+    /// .label_formatter(|name, value| {
+    ///     if !name.is_empty() {
+    ///         format!("{}: {:.*}%", name, 1, value.y)
+    ///     } else {
+    ///         "".to_owned()
+    ///     }
+    /// })
+    /// .show(ui, |plot_ui| plot_ui.line(line));
+    /// # });
+    /// ```
+    pub fn label_formatter(
+        mut self,
+        label_formatter: impl Fn(&str, &PlotPoint) -> String + 'a,
+    ) -> Self {
+        self.label_formatter = Some(Box::new(label_formatter));
+        self
+    }
+
+    /// Format the values shown on hover, so that their unit and precision only need to be given once.
+    ///
+    /// Used by the default hover labels, the labels of bars and boxes,
+    /// and the default [`CoordinatesFormatter`]. A [`Self::label_formatter`] takes precedence.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Axis, Plot};
+    /// Plot::new("my_plot")
+    ///     .value_formatter(|axis, value| match axis {
+    ///         Axis::X => format!("{value:.0} s"),
+    ///         Axis::Y => format!("{value:.2} V"),
+    ///     })
+    ///     .show(ui, |_plot_ui| ());
+    /// # });
+    /// ```
+    #[inline]
+    pub fn value_formatter(mut self, formatter: impl Fn(Axis, f64) -> String + 'a) -> Self {
+        self.value_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Show the pointer coordinates in the plot.
+    ///
+    /// Can be called several times to add more readouts, which are stacked when they share a corner.
+    pub fn coordinates_formatter(
+        mut self,
+        position: Corner,
+        formatter: CoordinatesFormatter<'a>,
+    ) -> Self {
+        self.coordinates_formatters.push((position, formatter));
+        self
+    }
+
+    /// Configure how the grid in the background is spaced apart along the X axis.
+    ///
+    /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
+    ///
+    /// The function has this signature:
+    /// ```ignore
+    /// fn step_sizes(input: GridInput) -> Vec<GridMark>;
+    /// ```
+    ///
+    /// This function should return all marks along the visible range of the X axis.
+    /// `step_size` also determines how thick/faint each line is drawn.
+    /// For example, if x = 80..=230 is visible and you want big marks at steps of
+    /// 100 and small ones at 25, you can return:
+    /// ```no_run
+    /// # use egui_plot::GridMark;
+    /// vec![
+    ///    // 100s
+    ///    GridMark { value: 100.0, step_size: 100.0 },
+    ///    GridMark { value: 200.0, step_size: 100.0 },
+    ///
+    ///    // 25s
+    ///    GridMark { value: 125.0, step_size: 25.0 },
+    ///    GridMark { value: 150.0, step_size: 25.0 },
+    ///    GridMark { value: 175.0, step_size: 25.0 },
+    ///    GridMark { value: 225.0, step_size: 25.0 },
+    /// ];
+    /// # ()
+    /// ```
+    ///
+    /// There are helpers for common cases, see [`log_grid_spacer`] and [`uniform_grid_spacer`].
+    #[inline]
+    pub fn x_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
+        self.grid_spacers[0] = Box::new(spacer);
+        self
+    }
+
+    /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
+    ///
+    /// See [`Self::x_grid_spacer`] for explanation.
+    #[inline]
+    pub fn y_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
+        self.grid_spacers[1] = Box::new(spacer);
+        self
+    }
+
+    /// Draw the vertical grid lines dashed or dotted. Default: [`LineStyle::Solid`].
+    #[inline]
+    pub fn x_grid_style(mut self, style: LineStyle) -> Self {
+        self.grid_styles[0] = style;
+        self
+    }
+
+    /// Draw the horizontal grid lines dashed or dotted. Default: [`LineStyle::Solid`].
+    #[inline]
+    pub fn y_grid_style(mut self, style: LineStyle) -> Self {
+        self.grid_styles[1] = style;
+        self
+    }
+
+    /// Pick the stroke of each vertical grid line, e.g. to make the line at `x = 0` stand out.
+    ///
+    /// The function is given the marks of the lines that are shown, and lines it returns
+    /// [`GridStroke::Default`] for are drawn like any other.
+    ///
+    /// ```
+    /// # use egui_plot::{GridStroke, Plot};
+    /// # use egui::{Color32, Stroke};
+    /// let plot = Plot::new("plot").x_grid_stroke(|mark| {
+    ///     if mark.value == 0.0 {
+    ///         GridStroke::Custom(Stroke::new(1.5, Color32::GRAY))
+    ///     } else {
+    ///         GridStroke::Default
+    ///     }
+    /// });
+    /// ```
+    #[inline]
+    pub fn x_grid_stroke(mut self, stroke: impl Fn(GridMark) -> GridStroke + 'a) -> Self {
+        self.grid_strokes[0] = Some(Box::new(stroke));
+        self
+    }
+
+    /// Pick the stroke of each horizontal grid line.
+    ///
+    /// See [`Self::x_grid_stroke`] for explanation.
+    #[inline]
+    pub fn y_grid_stroke(mut self, stroke: impl Fn(GridMark) -> GridStroke + 'a) -> Self {
+        self.grid_strokes[1] = Some(Box::new(stroke));
+        self
+    }
+
+    /// Paint something below all items and the grid, like a shaded background region.
+    ///
+    /// The painter is clipped to the plot frame, and the transform maps plot values to it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Plot, PlotPoint};
+    ///
+    /// Plot::new("plot")
+    ///     .before_items(|painter, transform| {
+    ///         let left = transform.position_from_point(&PlotPoint::new(0.0, 0.0)).x;
+    ///         let right = transform.position_from_point(&PlotPoint::new(6.0, 0.0)).x;
+    ///         let night = egui::Rect::from_x_y_ranges(left..=right, transform.frame().y_range());
+    ///         painter.rect_filled(night, 0.0, egui::Color32::from_gray(40));
+    ///     })
+    ///     .show(ui, |plot_ui| {});
+    /// # });
+    /// ```
+    #[inline]
+    pub fn before_items(mut self, paint: impl Fn(&Painter, &PlotTransform) + 'a) -> Self {
+        self.before_items = Some(Box::new(paint));
+        self
+    }
+
+    /// Paint something on top of all items, like a watermark or a custom decoration.
+    ///
+    /// The painter is clipped to the plot frame, and the transform maps plot values to it.
+    /// See also [`Self::before_items`].
+    #[inline]
+    pub fn after_items(mut self, paint: impl Fn(&Painter, &PlotTransform) + 'a) -> Self {
+        self.after_items = Some(Box::new(paint));
+        self
+    }
+
+    /// How the values are spaced along the x-axis, e.g. [`AxisScale::log10`] for a logarithmic axis.
+    ///
+    /// A logarithmic scale also sets the x-grid spacer to [`decade_grid_spacer`],
+    /// or [`symlog_grid_spacer`] for [`AxisScale::SymLog`],
+    /// and the tick formatter of the x-axes, so set those after this. Default: [`AxisScale::Linear`].
+    #[inline]
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.scales[0] = scale;
+        if let Some(spacer) = scaled_grid_spacer(scale) {
+            self.grid_spacers[0] = spacer;
+            for axis in &mut self.x_axes {
+                axis.formatter = Arc::new(log_axis_formatter);
+            }
+        }
+        self
+    }
+
+    /// Label the x-axis, in seconds, relative to the given time, like `"-20 s"`, `"-10 s"`, `"now"`.
+    ///
+    /// This sets the x-grid spacer to [`relative_time_grid_spacer`] and the tick formatter
+    /// of the x-axes to [`relative_time_formatter`], so set those after this.
+    /// For a live view, pass the current time or the latest sample each frame,
+    /// and keep it in view with [`Self::auto_bounds`] or [`PlotUi::set_plot_bounds`].
+    #[inline]
+    pub fn x_axis_relative_time(mut self, reference: f64) -> Self {
+        self.grid_spacers[0] = relative_time_grid_spacer(reference);
+        for axis in &mut self.x_axes {
+            axis.formatter = Arc::new(relative_time_formatter(reference));
+        }
+        self
+    }
+
+    /// Make the x-axis categorical, with the names of the categories at the whole-number positions.
+    ///
+    /// This sets the x-grid spacer and the tick formatter of the x-axes, so set those after this.
+    /// The automatic bounds include all categories, and the view can't be zoomed in to less than one.
+    /// Place the items of a category with [`Categories::position`], [`Categories::bar`] and [`Categories::box_elem`].
+    #[inline]
+    pub fn x_axis_categories(mut self, categories: &Categories) -> Self {
+        self.grid_spacers[0] = categories.grid_spacer();
+        for axis in &mut self.x_axes {
+            axis.formatter = Arc::new(categories.formatter());
+        }
+        if !categories.is_empty() {
+            self.min_auto_bounds.extend_with_x(-0.5);
+            self.min_auto_bounds
+                .extend_with_x(categories.len() as f64 - 0.5);
+        }
+        self.min_bounds_size[0] = self.min_bounds_size[0].max(1.0);
+        self
+    }
+
+    /// Label the x-axis with dates and times, given as seconds since the Unix epoch in UTC.
+    ///
+    /// This sets the x-grid spacer to [`time_grid_spacer`] and the tick formatter
+    /// of the x-axes to [`time_formatter`], so set those after this.
+    #[inline]
+    pub fn x_axis_time(mut self) -> Self {
+        self.grid_spacers[0] = time_grid_spacer();
+        for axis in &mut self.x_axes {
+            axis.formatter = Arc::new(time_formatter);
+        }
+        self
+    }
+
+    /// How the values are spaced along the y-axis, see [`Self::x_axis_scale`].
+    #[inline]
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.scales[1] = scale;
+        if let Some(spacer) = scaled_grid_spacer(scale) {
+            self.grid_spacers[1] = spacer;
+            for axis in &mut self.y_axes {
+                axis.formatter = Arc::new(log_axis_formatter);
+            }
+        }
+        self
+    }
+
+    /// Set when the grid starts showing.
+    ///
+    /// When grid lines are closer than the given minimum, they will be hidden.
+    /// When they get further apart they will fade in, until the reaches the given maximum,
+    /// at which point they are fully opaque.
+    #[inline]
+    pub fn grid_spacing(mut self, grid_spacing: impl Into<Rangef>) -> Self {
+        self.grid_spacing = grid_spacing.into();
+        self
+    }
+
+    /// Snap the hover rulers and the coordinates readout to the nearest grid line within
+    /// this many points of the pointer, to make it easy to read off round values. Default: off.
+    ///
+    /// The snapped value is in [`PlotResponse::hovered_value`],
+    /// while [`PlotUi::pointer_coordinate`] stays the raw value under the pointer.
+    #[inline]
+    pub fn snap_to_grid(mut self, max_distance: f32) -> Self {
+        self.snap_to_grid = Some(max_distance);
+        self
+    }
+
+    /// Snap the hover rulers and the coordinates readout to the sample of the given item
+    /// closest to the pointer, when no item is hovered, like a crosshair following the data.
+    /// Default: off.
+    ///
+    /// The item is found by its [id](PlotItem::id), or by `Id::new(name)` if it has none.
+    /// The snapped value is in [`PlotResponse::hovered_value`], and takes precedence over
+    /// [`Self::snap_to_grid`]. Items without points, like bar charts, are not snapped to.
+    #[inline]
+    pub fn snap_to_item(mut self, id: Id) -> Self {
+        self.snap_to_item = Some(id);
+        self
+    }
+
+    /// Clamp the grid to only be visible at the range of data where we have values.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn clamp_grid(mut self, clamp_grid: bool) -> Self {
+        self.clamp_grid = clamp_grid;
+        self
+    }
+
+    /// Keep drawing the plot within the given time.
+    ///
+    /// If drawing the plot took longer than this in the previous frame, the items are drawn
+    /// with less detail until it fits again, see [`RenderQuality`].
+    ///
+    /// The time measured is how long the plot takes to build the shapes of its grid, items and
+    /// labels. It doesn't include the tessellation of those shapes, which egui does at the end of
+    /// the frame, but the lower quality reduces that as well, since there are fewer shapes.
+    /// The quality used is reported in [`PlotResponse::render_quality`].
+    ///
+    /// Default: `None`, meaning items are always drawn in full quality.
+    #[inline]
+    pub fn frame_budget(mut self, budget: Duration) -> Self {
+        self.frame_budget = Some(budget);
+        self
+    }
+
+    /// Set the sense for the plot rect.
+    ///
+    /// Default: `Sense::click_and_drag()`.
+    #[inline]
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
+    }
+
+    /// Expand bounds to include the given x value.
+    /// For instance, to always show the y axis, call `plot.include_x(0.0)`.
+    #[inline]
+    pub fn include_x(mut self, x: impl Into<f64>) -> Self {
+        self.min_auto_bounds.extend_with_x(x.into());
+        self
+    }
+
+    /// Expand bounds to include the given y value.
+    /// For instance, to always show the x axis, call `plot.include_y(0.0)`.
+    #[inline]
+    pub fn include_y(mut self, y: impl Into<f64>) -> Self {
+        self.min_auto_bounds.extend_with_y(y.into());
+        self
+    }
+
+    /// Keep the values of [`Self::include_x`] and [`Self::include_y`] in view every frame,
+    /// also after the user has moved or zoomed the plot.
+    ///
+    /// Useful to keep a window of streaming data in view. Default: `false`.
+    #[inline]
+    pub fn keep_included(mut self, keep_included: bool) -> Self {
+        self.keep_included = keep_included;
+        self
+    }
+
+    /// In debug builds, panic when an item has NaN or infinite values, to catch bad data early.
+    ///
+    /// Otherwise such values are skipped, as documented for each item.
+    /// NaN values breaking a [`Line`] with [`Line::gap_on_nan`] are not reported. Default: `false`.
+    #[inline]
+    pub fn assert_finite(mut self, assert_finite: bool) -> Self {
+        self.assert_finite = assert_finite;
+        self
+    }
+
+    /// Set whether the bounds should be automatically set based on data by default.
+    ///
+    /// This is enabled by default.
+    #[inline]
+    pub fn auto_bounds(mut self, auto_bounds: impl Into<Vec2b>) -> Self {
+        self.default_auto_bounds = auto_bounds.into();
+        self
+    }
+
+    /// Expand bounds to fit all items across the x axis, including values given by `include_x`.
+    #[deprecated = "Use `auto_bounds` instead"]
+    #[inline]
+    pub fn auto_bounds_x(mut self) -> Self {
+        self.default_auto_bounds.x = true;
+        self
+    }
+
+    /// Expand bounds to fit all items across the y axis, including values given by `include_y`.
+    #[deprecated = "Use `auto_bounds` instead"]
+    #[inline]
+    pub fn auto_bounds_y(mut self) -> Self {
+        self.default_auto_bounds.y = true;
+        self
+    }
+
+    /// Show a legend including all named items.
+    #[inline]
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend_config = Some(legend);
+        self
+    }
+
+    /// Whether or not to show the background [`Rect`].
+    ///
+    /// Can be useful to disable if the plot is overlaid over existing content.
+    /// Default: `true`.
+    #[inline]
+    pub fn show_background(mut self, show: bool) -> Self {
+        self.show_background = show;
+        self
+    }
+
+    /// Show axis labels and grid tick values on the side of the plot.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_axes(mut self, show: impl Into<Vec2b>) -> Self {
+        self.show_axes = show.into();
+        self
+    }
+
+    /// Tag the axes with the value under the pointer, like the cursors of an oscilloscope.
+    ///
+    /// The tags are filled with the selection color, and their text is made
+    /// by the tick formatter of each axis, see [`AxisHints::formatter`].
+    /// Default: `false`.
+    #[inline]
+    pub fn axis_value_tags(mut self, show: impl Into<Vec2b>) -> Self {
+        self.axis_value_tags = show.into();
+        self
+    }
+
+    /// Show a grid overlay on the plot.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn show_grid(mut self, show: impl Into<Vec2b>) -> Self {
+        self.show_grid = show.into();
+        self
+    }
+
+    /// Show fainter minor grid lines between the finest grid lines, for each axis.
+    ///
+    /// The minor lines divide the space between two grid lines into [`Self::minor_grid_subdivisions`] parts,
+    /// and are only shown while they are not too close together.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn minor_grid(mut self, show: impl Into<Vec2b>) -> Self {
+        self.minor_grid = show.into();
+        self
+    }
+
+    /// Into how many parts the [`Self::minor_grid`] divides the space between two grid lines.
+    ///
+    /// Default: `5`.
+    #[inline]
+    pub fn minor_grid_subdivisions(mut self, subdivisions: usize) -> Self {
+        self.minor_grid_subdivisions = subdivisions.max(2);
+        self
+    }
+
+    /// Add this plot to an axis link group so that this plot will share the bounds with other plots in the
+    /// same group.
+    ///
+    /// Each axis can belong to at most one group, so the x and y axis can be linked to different groups
+    /// by calling this twice. Linking an axis again moves it to the new group.
+    #[inline]
+    pub fn link_axis(mut self, group_id: impl Into<Id>, link: impl Into<Vec2b>) -> Self {
+        let link = link.into();
+        for (_, axes) in &mut self.linked_axes {
+            *axes = axes.and(!link);
+        }
+        self.linked_axes.retain(|(_, axes)| axes.any());
+        self.linked_axes.push((group_id.into(), link));
+        self
+    }
+
+    /// What double-clicking the plot resets in its axis link groups, see [`Self::link_axis`].
+    ///
+    /// Give all plots of a group the same setting. Default: [`LinkReset::Group`].
+    #[inline]
+    pub fn link_reset(mut self, link_reset: LinkReset) -> Self {
+        self.link_reset = link_reset;
+        self
+    }
+
+    /// Add this plot to a cursor link group so that this plot will share the cursor position with other plots
+    /// in the same group. A plot cannot belong to more than one cursor group.
+    #[inline]
+    pub fn link_cursor(mut self, group_id: impl Into<Id>, link: impl Into<Vec2b>) -> Self {
+        self.linked_cursors = Some((group_id.into(), link.into()));
+        self
+    }
+
+    /// Round grid positions to full pixels to avoid aliasing. Improves plot appearance but might have an
+    /// undesired effect when shifting the plot bounds. Enabled by default.
+    #[inline]
+    #[deprecated = "This no longer has any effect and is always enabled."]
+    pub fn sharp_grid_lines(self, _enabled: bool) -> Self {
+        self
+    }
+
+    /// Resets the plot.
+    #[inline]
+    pub fn reset(mut self) -> Self {
+        self.reset = true;
+        self
+    }
+
+    /// Set the x axis label of the main X-axis.
+    ///
+    /// Default: no label.
+    #[inline]
+    pub fn x_axis_label(mut self, label: impl Into<WidgetText>) -> Self {
+        if let Some(main) = self.x_axes.first_mut() {
+            main.label = label.into();
+        }
+        self
+    }
+
+    /// Set the y axis label of the main Y-axis.
+    ///
+    /// Default: no label.
+    #[inline]
+    pub fn y_axis_label(mut self, label: impl Into<WidgetText>) -> Self {
+        if let Some(main) = self.y_axes.first_mut() {
+            main.label = label.into();
+        }
+        self
+    }
+
+    /// Set the position of the main X-axis.
+    #[inline]
+    pub fn x_axis_position(mut self, placement: axis::VPlacement) -> Self {
+        if let Some(main) = self.x_axes.first_mut() {
+            main.placement = placement.into();
+        }
+        self
+    }
+
+    /// Set the position of the main Y-axis.
+    #[inline]
+    pub fn y_axis_position(mut self, placement: axis::HPlacement) -> Self {
+        if let Some(main) = self.y_axes.first_mut() {
+            main.placement = placement.into();
+        }
+        self
+    }
+
+    /// Specify custom formatter for ticks on the main X-axis.
+    ///
+    /// Arguments of `fmt`:
+    /// * the grid mark to format
+    /// * currently shown range on this axis.
+    pub fn x_axis_formatter(
+        mut self,
+        fmt: impl Fn(GridMark, &RangeInclusive<f64>) -> String + 'a,
+    ) -> Self {
+        if let Some(main) = self.x_axes.first_mut() {
+            main.formatter = Arc::new(fmt);
+        }
+        self
+    }
+
+    /// Specify custom formatter for ticks on the main Y-axis.
+    ///
+    /// Arguments of `fmt`:
+    /// * the grid mark to format
+    /// * currently shown range on this axis.
+    pub fn y_axis_formatter(
+        mut self,
+        fmt: impl Fn(GridMark, &RangeInclusive<f64>) -> String + 'a,
+    ) -> Self {
+        if let Some(main) = self.y_axes.first_mut() {
+            main.formatter = Arc::new(fmt);
+        }
+        self
+    }
+
+    /// Set the minimum width of the main y-axis, in ui points.
+    ///
+    /// The width will automatically expand if any tickmark text is wider than this.
+    #[inline]
+    pub fn y_axis_min_width(mut self, min_width: f32) -> Self {
+        if let Some(main) = self.y_axes.first_mut() {
+            main.min_thickness = min_width;
+        }
+        self
+    }
+
+    /// Set the main Y-axis-width by number of digits
+    #[inline]
+    #[deprecated = "Use `y_axis_min_width` instead"]
+    pub fn y_axis_width(self, digits: usize) -> Self {
+        self.y_axis_min_width(12.0 * digits as f32)
+    }
+
+    /// Set custom configuration for X-axis
+    ///
+    /// More than one axis may be specified. The first specified axis is considered the main axis.
+    #[inline]
+    pub fn custom_x_axes(mut self, hints: Vec<AxisHints<'a>>) -> Self {
+        self.x_axes = hints;
+        self
+    }
+
+    /// Set custom configuration for left Y-axis
+    ///
+    /// More than one axis may be specified. The first specified axis is considered the main axis.
+    #[inline]
+    pub fn custom_y_axes(mut self, hints: Vec<AxisHints<'a>>) -> Self {
+        self.y_axes = hints;
+        self
+    }
+
+    /// Set custom cursor color.
+    ///
+    /// You may set the color to [`Color32::TRANSPARENT`] to hide the cursors.
+    #[inline]
+    pub fn cursor_color(mut self, color: Color32) -> Self {
+        self.cursor_color = Some(color);
+        self
+    }
+
+    /// Check the configuration for settings that can't be shown together,
+    /// which [`Self::show`] would either panic on in debug builds or show in some unexpected way.
+    ///
+    /// # Errors
+    /// The first problem found in the configuration.
+    pub fn validate(&self) -> Result<(), PlotError> {
+        let valid = |size: f32| size > 0.0 && size.is_finite();
+        for aspect in [self.data_aspect, self.view_aspect].into_iter().flatten() {
+            if !valid(aspect) {
+                return Err(PlotError::InvalidAspect(aspect));
+            }
+        }
+        if let (Some(width), Some(height), Some(aspect)) =
+            (self.width, self.height, self.view_aspect)
+        {
+            if (width / height - aspect).abs() > 1e-3 * aspect {
+                return Err(PlotError::ConflictingAspect(format!(
+                    "a size of {width}x{height} with a view aspect of {aspect}"
+                )));
+            }
+        }
+        if let Some(aspect) = self
+            .data_aspect
+            .filter(|aspect| self.polar && *aspect != 1.0)
+        {
+            return Err(PlotError::ConflictingAspect(format!(
+                "a data aspect of {aspect} in polar mode"
+            )));
+        }
+
+        let (width, height) = (self.width.unwrap_or(1.0), self.height.unwrap_or(1.0));
+        if !valid(width) || !valid(height) {
+            return Err(PlotError::EmptySize { width, height });
+        }
+
+        for axis in [Axis::X, Axis::Y] {
+            let (min, max) = (
+                self.min_bounds_size[axis as usize],
+                self.max_bounds_size[axis as usize],
+            );
+            if min > max {
+                return Err(PlotError::InvalidBoundsSize { axis, min, max });
+            }
+        }
+
+        for (axis, hints) in [(Axis::X, &self.x_axes), (Axis::Y, &self.y_axes)] {
+            for (i, hint) in hints.iter().enumerate() {
+                let label = hint.label.text();
+                let duplicate = hints[..i]
+                    .iter()
+                    .any(|other| other.placement == hint.placement && other.label.text() == label);
+                if duplicate {
+                    return Err(PlotError::DuplicateAxis {
+                        axis,
+                        label: label.to_owned(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Like [`Self::show`], but returns an error instead of showing a plot with an invalid
+    /// configuration.
+    ///
+    /// # Errors
+    /// See [`Self::validate`]. Nothing is shown in that case.
+    pub fn try_show<R>(
+        self,
+        ui: &mut Ui,
+        build_fn: impl FnOnce(&mut PlotUi) -> R + 'a,
+    ) -> Result<PlotResponse<R>, PlotError> {
+        self.validate()?;
+        Ok(self.show(ui, build_fn))
+    }
+
+    /// Interact with and add items to the plot and finally draw it.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        build_fn: impl FnOnce(&mut PlotUi) -> R + 'a,
+    ) -> PlotResponse<R> {
+        self.show_dyn(ui, Box::new(build_fn))
+    }
+
+    #[allow(clippy::too_many_lines)] // TODO(emilk): shorten this function
+    #[allow(clippy::type_complexity)] // build_fn
+    fn show_dyn<R>(
+        self,
+        ui: &mut Ui,
+        build_fn: Box<dyn FnOnce(&mut PlotUi) -> R + 'a>,
+    ) -> PlotResponse<R> {
+        let Self {
+            id_source,
+            id,
+            center_axis,
+            allow_zoom,
+            allow_drag,
+            allow_scroll,
+            input_filter,
+            allow_double_click_reset,
+            view_history_shortcuts,
+            view_bookmark_shortcuts,
+            allow_boxed_zoom,
+            box_selection,
+            bounds_limit,
+            min_bounds_size,
+            max_bounds_size,
+            lasso_selection,
+            measure,
+            boxed_zoom_pointer_button,
+            default_auto_bounds,
+            min_auto_bounds,
+            keep_included,
+            assert_finite,
+            margin_fraction,
+            bounds_margin,
+            width,
+            height,
+            mut min_size,
+            data_aspect,
+            polar,
+            view_aspect,
+            mut show_x,
+            mut show_y,
+            show_delta,
+            pin_on_click,
+            keyboard_navigation,
+            color_group,
+            label_formatter,
+            value_formatter,
+            coordinates_formatters,
+            x_axes,
+            y_axes,
+            legend_config,
+            cursor_color,
+            reset,
+            show_background,
+            show_axes,
+            axis_value_tags,
+            show_grid,
+            minor_grid,
+            minor_grid_subdivisions,
+            grid_spacing,
+            snap_to_grid,
+            snap_to_item,
+            linked_axes,
+            link_reset,
+            capture_scroll,
+            linked_cursors,
+
+            clamp_grid,
+            frame_budget,
+            grid_spacers,
+            grid_strokes,
+            grid_styles,
+            before_items,
+            after_items,
+            scales,
+            sense,
+        } = self;
+
+        // Polar plots keep circles round, and have their own grid instead of the axes.
+        let data_aspect = if polar { Some(1.0) } else { data_aspect };
+        let show_axes = if polar { Vec2b::FALSE } else { show_axes };
+        let label_formatter = match label_formatter {
+            None if polar => Some(polar::label_formatter()),
+            label_formatter => label_formatter,
+        };
+        let min_auto_bounds = if polar {
+            let mut bounds = min_auto_bounds;
+            bounds.extend_with(&PlotPoint::new(0.0, 0.0));
+            bounds
+        } else {
+            min_auto_bounds
+        };
+
+        // Disable interaction if ui is disabled.
+        let allow_zoom = allow_zoom.and(ui.is_enabled());
+        let allow_drag = allow_drag.and(ui.is_enabled());
+        let allow_scroll = allow_scroll.and(ui.is_enabled());
+
+        // Determine position of widget.
+        let pos = ui.available_rect_before_wrap().min;
+        // Minimum values for screen protection
+        min_size.x = min_size.x.at_least(1.0);
+        min_size.y = min_size.y.at_least(1.0);
+
+        // Determine size of widget.
+        let size = {
+            let width = width
+                .unwrap_or_else(|| {
+                    if let (Some(height), Some(aspect)) = (height, view_aspect) {
+                        height * aspect
+                    } else {
+                        ui.available_size_before_wrap().x
+                    }
+                })
+                .at_least(min_size.x);
+
+            let height = height
+                .unwrap_or_else(|| {
+                    if let Some(aspect) = view_aspect {
+                        width / aspect
+                    } else {
+                        ui.available_size_before_wrap().y
+                    }
+                })
+                .at_least(min_size.y);
+            vec2(width, height)
+        };
+
+        // Determine complete rect of widget.
+        let complete_rect = Rect {
+            min: pos,
+            max: pos + size,
+        };
+
+        let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
+
+        let previous_mem = PlotMemory::load(ui.ctx(), plot_id); // TODO(emilk): avoid loading plot memory twice
+
+        // A legend outside the plot gets the space it needed the previous frame.
+        let legend_thickness = previous_mem
+            .as_ref()
+            .map_or(0.0, |mem| mem.legend_thickness);
+        let (axes_rect, legend_rect) = match legend_config.as_ref().and_then(Legend::dock) {
+            None => (complete_rect, None),
+            Some(LegendDock::Right) => {
+                let split = complete_rect.right() - legend_thickness;
+                let (left, right) = complete_rect.split_left_right_at_x(split);
+                (left, Some(right))
+            }
+            Some(LegendDock::Bottom) => {
+                let split = complete_rect.bottom() - legend_thickness;
+                let (top, bottom) = complete_rect.split_top_bottom_at_y(split);
+                (top, Some(bottom))
+            }
+        };
+
+        let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
+            previous_mem.as_ref(),
+            show_axes,
+            axes_rect,
+            [&x_axes, &y_axes],
+        );
+
+        // Allocate the plot window.
+        let response = ui.allocate_rect(plot_rect, sense);
+
+        // Leave the pointer input to the insets shown on top of the plot.
+        let inset_rects = inset::take_inset_rects(ui, response.id);
+        let pointer_over_inset = ui
+            .input(|i| i.pointer.hover_pos())
+            .is_some_and(|pos| inset_rects.iter().any(|rect| rect.contains(pos)));
+
+        // Load or initialize the memory.
+        ui.ctx().check_for_id_clash(plot_id, plot_rect, "Plot");
+
+        let mut mem = if reset {
+            for (name, _) in &linked_axes {
+                ui.data_mut(|data| {
+                    let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                    link_groups.0.remove(name);
+                });
+            }
+            None
+        } else {
+            PlotMemory::load(ui.ctx(), plot_id)
+        }
+        .unwrap_or_else(|| PlotMemory {
+            auto_bounds: default_auto_bounds,
+            hovered_legend_item: None,
+            hidden_items: Default::default(),
+            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis),
+            last_click_pos_for_zoom: None,
+            x_axis_thickness: Default::default(),
+            y_axis_thickness: Default::default(),
+            legend_thickness: 0.0,
+            legend_filter: String::new(),
+            legend_offset: Vec2::ZERO,
+            legend_order: Vec::new(),
+            legend_entry_rects: Vec::new(),
+            saved_views: Default::default(),
+            render_quality: RenderQuality::Full,
+            grid_step_sizes: [None; 2],
+            dragged_point: None,
+            lasso: None,
+            measurements: Vec::new(),
+            measuring: None,
+            cell_selection_origin: None,
+            heatmap_selection: None,
+            pins: Vec::new(),
+            selected_point: None,
+            view_history: Default::default(),
+        });
+
+        #[cfg(feature = "serde")]
+        session::apply_restored(ui.ctx(), plot_id, &mut mem);
+
+        let last_plot_transform = mem.transform;
+
+        // Call the plot build function.
+        let mut plot_ui = PlotUi {
+            ctx: ui.ctx().clone(),
+            items: Vec::new(),
+            next_auto_color_idx: 0,
+            color_group,
+            last_plot_transform,
+            last_auto_bounds: mem.auto_bounds,
+            response,
+            bounds_modifications: Vec::new(),
+        };
+        let inner = build_fn(&mut plot_ui);
+        let PlotUi {
+            mut items,
+            mut response,
+            last_plot_transform,
+            mut bounds_modifications,
+            ..
+        } = plot_ui;
+
+        // Background
+        if show_background {
+            ui.painter()
+                .with_clip_rect(plot_rect)
+                .add(epaint::RectShape::new(
+                    plot_rect,
+                    Rounding::same(2),
+                    ui.visuals().extreme_bg_color,
+                    ui.visuals().widgets.noninteractive.bg_stroke,
+                    egui::StrokeKind::Inside,
+                ));
+        }
+
+        // --- Legend ---
+        let mut legend = legend_config.and_then(|config| {
+            let rect = legend_rect.unwrap_or(plot_rect);
+            LegendWidget::try_new(
+                rect,
+                config,
+                &items,
+                &mem.hidden_items,
+                &mem.legend_filter,
+                mem.legend_offset,
+                &mem.legend_order,
+            )
+            .map(|mut legend| {
+                legend.collapse_for(legend_rect.map_or(plot_rect, |r| r.union(plot_rect)).size());
+                legend
+            })
+        });
+        // Don't show hover cursor when hovering over legend.
+        if mem.hovered_legend_item.is_some() {
+            show_x = false;
+            show_y = false;
+        }
+        // Remove the deselected items.
+        items.retain(|item| !mem.hidden_items.contains(item.name()));
+        if let Some(legend) = &legend {
+            items.retain(|item| !legend.is_filtered_out(item.name()));
+            if let Some(order) = legend.order() {
+                legend::sort_items_by_legend_order(&mut items, order);
+            }
+        }
+        // Highlight the hovered items.
+        if let Some(hovered_name) = &mem.hovered_legend_item {
+            items
+                .iter_mut()
+                .filter(|entry| entry.name() == hovered_name && entry.allow_highlight())
+                .for_each(|entry| entry.highlight());
+        }
+        // Move highlighted items to front.
+        items.sort_by_key(|item| item.highlighted());
+
+        // --- Bound computation ---
+        let mut bounds = *last_plot_transform.bounds();
+
+        // Find the cursors from other plots we need to draw
+        let draw_cursors: Vec<Cursor> = if let Some((id, _)) = linked_cursors.as_ref() {
+            ui.data_mut(|data| {
+                let frames: &mut CursorLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                let cursors = frames.0.entry(*id).or_default();
+
+                // Look for our previous frame
+                let index = cursors
+                    .iter()
+                    .enumerate()
+                    .find(|(_, frame)| frame.id == plot_id)
+                    .map(|(i, _)| i);
+
+                // Remove our previous frame and all older frames as these are no longer displayed. This avoids
+                // unbounded growth, as we add an entry each time we draw a plot.
+                index.map(|index| cursors.drain(0..=index));
+
+                // Gather all cursors of the remaining frames. This will be all the cursors of the
+                // other plots in the group. We want to draw these in the current plot too.
+                cursors
+                    .iter()
+                    .flat_map(|frame| frame.cursors.iter().copied())
+                    .collect()
+            })
+        } else {
+            Vec::new()
+        };
+
+        // Transfer the bounds from the link groups.
+        let pass_nr = ui.ctx().cumulative_pass_nr();
+        let mut group_data_bounds = PlotBounds::NOTHING;
+        for (id, axes) in &linked_axes {
+            ui.data_mut(|data| {
+                let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                if let Some(linked_bounds) = link_groups.0.get(id) {
+                    let linked_data_bounds = linked_bounds.data_bounds(pass_nr);
+                    if axes.x {
+                        bounds.set_x(&linked_bounds.bounds);
+                        mem.auto_bounds.x = linked_bounds.auto_bounds.x;
+                        group_data_bounds.merge_x(&linked_data_bounds);
+                    }
+                    if axes.y {
+                        bounds.set_y(&linked_bounds.bounds);
+                        mem.auto_bounds.y = linked_bounds.auto_bounds.y;
+                        group_data_bounds.merge_y(&linked_data_bounds);
+                    }
+                };
+            });
+        }
+
+        // Allow double-clicking to reset to the initial bounds.
+        let double_click_reset = allow_double_click_reset && response.double_clicked();
+        if double_click_reset {
+            mem.view_history.record(bounds);
+            mem.auto_bounds = true.into();
+        }
+
+        if view_history_shortcuts && response.hovered() {
+            // Check for redo first, since Ctrl+Shift+Z would also match Ctrl+Z.
+            let redo = ui.input_mut(|i| {
+                i.consume_shortcut(&KeyboardShortcut::new(
+                    Modifiers::COMMAND | Modifiers::SHIFT,
+                    Key::Z,
+                )) || i.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Y))
+            });
+            if redo {
+                bounds_modifications.push(BoundsModification::Redo);
+            } else if ui.input_mut(|i| {
+                i.consume_shortcut(&KeyboardShortcut::new(Modifiers::COMMAND, Key::Z))
+            }) {
+                bounds_modifications.push(BoundsModification::Undo);
+            }
+        }
+
+        if view_bookmark_shortcuts && response.hovered() {
+            const DIGITS: [Key; 9] = [
+                Key::Num1,
+                Key::Num2,
+                Key::Num3,
+                Key::Num4,
+                Key::Num5,
+                Key::Num6,
+                Key::Num7,
+                Key::Num8,
+                Key::Num9,
+            ];
+            for (index, key) in DIGITS.into_iter().enumerate() {
+                let name = (index + 1).to_string();
+                // Check for saving first, since Ctrl+Shift+1 would also match Shift+1.
+                if ui.input_mut(|i| {
+                    i.consume_shortcut(&KeyboardShortcut::new(
+                        Modifiers::COMMAND | Modifiers::SHIFT,
+                        key,
+                    ))
+                }) {
+                    bounds_modifications.push(BoundsModification::SaveView(name));
+                } else if ui.input_mut(|i| {
+                    i.consume_shortcut(&KeyboardShortcut::new(Modifiers::SHIFT, key))
+                }) {
+                    bounds_modifications.push(BoundsModification::RestoreView(name));
+                }
+            }
+        }
+
+        // Apply bounds modifications.
+        let mut restored_hidden_items = None;
+        for modification in bounds_modifications {
+            match modification {
+                BoundsModification::Set(new_bounds) => {
+                    bounds = new_bounds;
+                    mem.auto_bounds = false.into();
+                }
+                BoundsModification::Translate(delta) => {
+                    let delta = (delta.x as f64, delta.y as f64);
+                    bounds.translate(delta);
+                    mem.auto_bounds = false.into();
+                }
+                BoundsModification::AutoBounds(new_auto_bounds) => {
+                    mem.auto_bounds = new_auto_bounds;
+                }
+                BoundsModification::Zoom(zoom_factor, center) => {
+                    bounds.zoom(zoom_factor, center);
+                    mem.auto_bounds = false.into();
+                }
+                BoundsModification::Undo => {
+                    if let Some(previous) = mem.view_history.undo(bounds) {
+                        bounds = previous;
+                        mem.auto_bounds = false.into();
+                    }
+                }
+                BoundsModification::Redo => {
+                    if let Some(next) = mem.view_history.redo(bounds) {
+                        bounds = next;
+                        mem.auto_bounds = false.into();
+                    }
+                }
+                BoundsModification::SaveView(name) => {
+                    let view = SavedView {
+                        bounds,
+                        hidden_items: mem.hidden_items.clone(),
+                    };
+                    mem.saved_views.insert(name, view);
+                }
+                BoundsModification::RestoreView(name) => {
+                    if let Some(saved) = mem.saved_views.get(&name) {
+                        mem.view_history.record(bounds);
+                        bounds = saved.bounds;
+                        mem.auto_bounds = false.into();
+                        restored_hidden_items = Some(saved.hidden_items.clone());
+                    }
+                }
+            }
+        }
+
+        // Reset bounds to initial bounds if they haven't been modified.
+        if mem.auto_bounds.x {
+            bounds.set_x(&min_auto_bounds);
+        }
+        if mem.auto_bounds.y {
+            bounds.set_y(&min_auto_bounds);
+        }
+
+        let auto_x = mem.auto_bounds.x && (!min_auto_bounds.is_valid_x() || default_auto_bounds.x);
+        let auto_y = mem.auto_bounds.y && (!min_auto_bounds.is_valid_y() || default_auto_bounds.y);
+
+        // Set bounds automatically based on content.
+        let mut item_cache = ItemCache::load(ui.ctx(), plot_id);
+        if auto_x || auto_y {
+            let items_bounds = item_cache.data_bounds(&items);
+            let mut data_bounds = bounds;
+            if auto_x {
+                data_bounds.merge_x(&items_bounds);
+            }
+            if auto_y {
+                data_bounds.merge_y(&items_bounds);
+            }
+
+            // The margins are added where the axes are linear.
+            bounds = transform::scale_bounds(scales, &data_bounds);
+            if auto_x {
+                match bounds_margin {
+                    Some(margin) => margin.apply(&mut bounds, Axis::X, plot_rect.size()),
+                    None => bounds.add_relative_margin_x(margin_fraction),
+                }
+            }
+
+            if auto_y {
+                match bounds_margin {
+                    Some(margin) => margin.apply(&mut bounds, Axis::Y, plot_rect.size()),
+                    None => bounds.add_relative_margin_y(margin_fraction),
+                }
+            }
+            bounds = transform::unscale_bounds(scales, &bounds);
+        }
+
+        // Fit the axes of a link group to the data of all of its plots.
+        let own_data_bounds = bounds;
+        if link_reset == LinkReset::Group {
+            if auto_x {
+                bounds.merge_x(&group_data_bounds);
+            }
+            if auto_y {
+                bounds.merge_y(&group_data_bounds);
+            }
+        }
+
+        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis);
+        mem.transform.set_scales(scales);
+
+        // Enforce aspect ratio
+        if let Some(data_aspect) = data_aspect {
+            if !linked_axes.is_empty() {
+                let linked = linked_axes
+                    .iter()
+                    .fold(Vec2b::FALSE, |linked, (_, axes)| linked.or(*axes));
+                let change_x = linked.y && !linked.x;
+                mem.transform.set_aspect_by_changing_axis(
+                    data_aspect as f64,
+                    if change_x { Axis::X } else { Axis::Y },
+                );
+            } else if default_auto_bounds.any() {
+                mem.transform.set_aspect_by_expanding(data_aspect as f64);
+            } else {
+                mem.transform
+                    .set_aspect_by_changing_axis(data_aspect as f64, Axis::Y);
+            }
+        }
+
+        let bounds_before_input = *mem.transform.bounds();
+
+        // Dragging individual points
+        if response.drag_started_by(PointerButton::Primary) {
+            let press_origin = ui.input(|i| i.pointer.press_origin());
+            let interact_radius = ui.style().interaction.interact_radius;
+            mem.dragged_point = press_origin.and_then(|origin| {
+                find_draggable_point(&items, origin, &last_plot_transform, interact_radius)
+            });
+        }
+        if !response.dragged_by(PointerButton::Primary) {
+            mem.dragged_point = None;
+        }
+        let dragged_point = mem.dragged_point.and_then(|(item_id, index, grab_offset)| {
+            let pointer = response.interact_pointer_pos()?;
+            Some(DraggedPoint {
+                item_id,
+                index,
+                position: mem.transform.value_from_position(pointer - grab_offset),
+            })
+        });
+        if dragged_point.is_some() {
+            response = response.on_hover_cursor(CursorIcon::Grabbing);
+        }
+
+        // Lasso selection
+        if let Some(modifiers) = lasso_selection {
+            if response.drag_started_by(PointerButton::Primary)
+                && mem.dragged_point.is_none()
+                && ui.input(|i| i.modifiers.matches_logically(modifiers))
+            {
+                mem.lasso = Some(Vec::new());
+            }
+        }
+        let lasso_released = mem.lasso.is_some() && !response.dragged_by(PointerButton::Primary);
+        if let (Some(lasso), Some(pointer)) = (&mut mem.lasso, response.interact_pointer_pos()) {
+            // Only add a vertex once the pointer moved, so the outline doesn't grow while repainting.
+            let moved = lasso.last().map_or(true, |last| {
+                mem.transform.position_from_point(last).distance(pointer) > 1.0
+            });
+            if moved {
+                lasso.push(mem.transform.value_from_position(pointer));
+            }
+            response = response.on_hover_cursor(CursorIcon::Crosshair);
+        }
+
+        // Measuring
+        if let Some(modifiers) = measure {
+            let modifiers_held = ui.input(|i| i.modifiers.matches_logically(modifiers));
+            if response.drag_started_by(PointerButton::Primary)
+                && mem.dragged_point.is_none()
+                && mem.lasso.is_none()
+                && modifiers_held
+            {
+                mem.measuring = ui.input(|i| i.pointer.press_origin()).map(|origin| {
+                    let start = last_plot_transform.value_from_position(origin);
+                    Measurement { start, end: start }
+                });
+            } else if response.clicked_by(PointerButton::Primary) && modifiers_held {
+                mem.measurements.clear();
+            }
+        }
+        if let Some(mut measuring) = mem.measuring {
+            if response.dragged_by(PointerButton::Primary) {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    measuring.end = mem.transform.value_from_position(pointer);
+                }
+                mem.measuring = Some(measuring);
+                response = response.on_hover_cursor(CursorIcon::Crosshair);
+            } else {
+                mem.measurements.push(measuring);
+                mem.measuring = None;
+            }
+        }
+
+        // Selecting heatmap cells
+        if response.drag_started_by(PointerButton::Primary)
+            && mem.dragged_point.is_none()
+            && mem.lasso.is_none()
+            && mem.measuring.is_none()
+        {
+            mem.cell_selection_origin = ui
+                .input(|i| i.pointer.press_origin())
+                .map(|origin| last_plot_transform.value_from_position(origin))
+                .filter(|origin| {
+                    items
+                        .iter()
+                        .any(|item| item.select_cells(*origin, *origin).is_some())
+                });
+        }
+        if !response.dragged_by(PointerButton::Primary) {
+            mem.cell_selection_origin = None;
+        }
+        let pointer_value = response
+            .interact_pointer_pos()
+            .map(|pointer| mem.transform.value_from_position(pointer));
+        let cell_selection = if response.clicked_by(PointerButton::Primary) {
+            pointer_value.map(|value| (value, value))
+        } else {
+            mem.cell_selection_origin.zip(pointer_value)
+        };
+        if let Some((from, to)) = cell_selection {
+            mem.heatmap_selection = items
+                .iter()
+                .rev()
+                .find_map(|item| item.select_cells(from, to));
+        }
+
+        // The pans and zooms are collected, and applied once the input filter had its say.
+        let mut input = PlotInput::new(
+            mem.transform,
+            response.hover_pos().unwrap_or(plot_rect.center()),
+        );
+
+        // Dragging
+        if allow_drag.any()
+            && response.dragged_by(PointerButton::Primary)
+            && dragged_point.is_none()
+            && mem.lasso.is_none()
+            && mem.measuring.is_none()
+            && mem.cell_selection_origin.is_none()
+        {
+            response = response.on_hover_cursor(CursorIcon::Grabbing);
+            let mut delta = response.drag_delta();
+            if !allow_drag.x {
+                delta.x = 0.0;
+            }
+            if !allow_drag.y {
+                delta.y = 0.0;
+            }
+            input.drag_delta = delta;
+        }
+
+        // Zooming
+        let mut boxed_zoom_rect = None;
+        let mut boxed_zoom = None;
+        let mut boxed_zoom_in_progress = false;
+        if allow_boxed_zoom {
+            // Save last click to allow boxed zooming
+            if response.drag_started() && response.dragged_by(boxed_zoom_pointer_button) {
+                // it would be best for egui that input has a memory of the last click pos because it's a common pattern
+                mem.last_click_pos_for_zoom = response.hover_pos();
+            }
+            let box_start_pos = mem.last_click_pos_for_zoom;
+            let box_end_pos = response.hover_pos();
+            if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
+                let box_start_value = mem.transform.value_from_position(box_start_pos);
+                let box_end_value = mem.transform.value_from_position(box_end_pos);
+                let new_bounds = PlotBounds {
+                    min: [
+                        box_start_value.x.min(box_end_value.x),
+                        box_start_value.y.min(box_end_value.y),
+                    ],
+                    max: [
+                        box_start_value.x.max(box_end_value.x),
+                        box_start_value.y.max(box_end_value.y),
+                    ],
+                };
+
+                // while dragging prepare a Shape and draw it later on top of the plot
+                if response.dragged_by(boxed_zoom_pointer_button) {
+                    response = response.on_hover_cursor(if box_selection {
+                        CursorIcon::Crosshair
+                    } else {
+                        CursorIcon::ZoomIn
+                    });
+                    boxed_zoom = Some(new_bounds);
+                    boxed_zoom_in_progress = true;
+                    let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
+                    boxed_zoom_rect = Some((
+                        epaint::RectShape::stroke(
+                            rect,
+                            0.0,
+                            epaint::Stroke::new(4., Color32::DARK_BLUE),
+                            egui::StrokeKind::Middle,
+                        ), // Outer stroke
+                        epaint::RectShape::stroke(
+                            rect,
+                            0.0,
+                            epaint::Stroke::new(2., Color32::WHITE),
+                            egui::StrokeKind::Middle,
+                        ), // Inner stroke
+                    ));
+                }
+                // when the click is release perform the zoom
+                if response.drag_stopped() {
+                    boxed_zoom = Some(new_bounds);
+                    if !box_selection && new_bounds.is_valid() {
+                        mem.transform.set_bounds(new_bounds);
+                        mem.auto_bounds = false.into();
+                    }
+                    // reset the boxed zoom state
+                    mem.last_click_pos_for_zoom = None;
+                }
+            }
+        }
+
+        // Note: we catch zoom/pan if the response contains the pointer, even if it isn't hovered.
+        // For instance: The user is painting another interactive widget on top of the plot
+        // but they still want to be able to pan/zoom the plot.
+        let capturing_scroll = match capture_scroll {
+            CaptureScroll::WhenHovered => true,
+            CaptureScroll::WithModifier(modifiers) => {
+                ui.input(|i| i.modifiers.matches_logically(modifiers))
+            }
+            CaptureScroll::Never => false,
+        };
+        if let (true, Some(hover_pos)) = (
+            response.contains_pointer() && !pointer_over_inset,
+            ui.input(|i| i.pointer.hover_pos()),
+        ) {
+            if allow_zoom.any() {
+                let mut zoom_factor = if data_aspect.is_some() {
+                    Vec2::splat(ui.input(|i| i.zoom_delta()))
+                } else {
+                    ui.input(|i| i.zoom_delta_2d())
+                };
+                if !allow_zoom.x {
+                    zoom_factor.x = 1.0;
+                }
+                if !allow_zoom.y {
+                    zoom_factor.y = 1.0;
+                }
+                input.zoom_factor = zoom_factor;
+                input.zoom_center = hover_pos;
+            }
+            if allow_scroll.any() && capturing_scroll {
+                let mut scroll_delta = ui.input(|i| i.smooth_scroll_delta);
+                if !allow_scroll.x {
+                    scroll_delta.x = 0.0;
+                }
+                if !allow_scroll.y {
+                    scroll_delta.y = 0.0;
+                }
+                input.scroll_delta = scroll_delta;
+
+                // Use up the scrolling, so that a surrounding scroll area doesn't scroll too.
+                ui.input_mut(|i| i.smooth_scroll_delta -= scroll_delta);
+            }
+        }
+
+        if let Some(input_filter) = &input_filter {
+            input = input_filter(input);
+        }
+        input.apply(&mut mem.transform);
+        if input.drag_delta != Vec2::ZERO {
+            mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+        }
+        if input.zoom_factor != Vec2::splat(1.0) {
+            mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
+        }
+        if input.scroll_delta != Vec2::ZERO {
+            mem.auto_bounds = false.into();
+        }
+
+        // Remember the view from before each pan or zoom, for undo.
+        let changing_view = *mem.transform.bounds() != bounds_before_input
+            || (mem.view_history.changing && response.dragged());
+        if changing_view && !mem.view_history.changing {
+            mem.view_history.record(bounds_before_input);
+        }
+        mem.view_history.changing = changing_view;
+
+        // Keep the view within the limits.
+        let mut bounds = *mem.transform.bounds();
+        if keep_included {
+            bounds.merge_x(&min_auto_bounds);
+            bounds.merge_y(&min_auto_bounds);
+        }
+        if bounds.is_valid() {
+            bounds.clamp_size(min_bounds_size, max_bounds_size);
+            if let Some(limit) = bounds_limit {
+                bounds.clamp_to(&limit);
+            }
+        }
+        mem.transform.set_bounds(bounds);
+
+        // --- transform initialized
+
+        // Add legend widgets to plot
+        let bounds = mem.transform.bounds();
+        let scaled_bounds = mem.transform.scaled_bounds();
+        let previous_step_sizes = mem.grid_step_sizes;
+        let x_axis_range = bounds.range_x();
+        let x_steps = Arc::new({
+            let input = GridInput {
+                bounds: (scaled_bounds.min[0], scaled_bounds.max[0]),
+                base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
+                previous_step_size: previous_step_sizes[0],
+            };
+            unscale_marks((grid_spacers[0])(input), scales[0])
+        });
+        let x_ticks = description::ticks(&x_steps, &x_axis_range);
+        let y_axis_range = bounds.range_y();
+        let y_steps = Arc::new({
+            let input = GridInput {
+                bounds: (scaled_bounds.min[1], scaled_bounds.max[1]),
+                base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
+                previous_step_size: previous_step_sizes[1],
+            };
+            unscale_marks((grid_spacers[1])(input), scales[1])
+        });
+        let y_ticks = description::ticks(&y_steps, &y_axis_range);
+        mem.grid_step_sizes = [smallest_step_size(&x_steps), smallest_step_size(&y_steps)];
+        let value_tag_pointer = response
+            .hover_pos()
+            .filter(|pos| mem.transform.frame().contains(*pos));
+        for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
+            widget.range = x_axis_range.clone();
+            widget.transform = Some(mem.transform);
+            widget.steps = x_steps.clone();
+            widget.value_tag = value_tag_pointer.filter(|_| axis_value_tags.x);
+            let (_response, thickness) = widget.ui(ui, Axis::X);
+            mem.x_axis_thickness.insert(i, thickness);
+        }
+        for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
+            widget.range = y_axis_range.clone();
+            widget.transform = Some(mem.transform);
+            widget.steps = y_steps.clone();
+            widget.value_tag = value_tag_pointer.filter(|_| axis_value_tags.y);
+            let (_response, thickness) = widget.ui(ui, Axis::Y);
+            mem.y_axis_thickness.insert(i, thickness);
+        }
+
+        // Initialize values from functions.
+        for item in &mut items {
+            item.initialize(mem.transform.bounds().range_x());
+        }
+
+        if let Some(legend) = &mut legend {
+            let pointer_x = response
+                .hover_pos()
+                .map(|pos| mem.transform.value_from_position(pos).x);
+            legend.set_values(&items, pointer_x, |value| match &value_formatter {
+                Some(format) => format(Axis::Y, value),
+                None => format!("{value:.3}"),
+            });
+        }
+        if assert_finite {
+            for item in &items {
+                debug_assert!(
+                    !item.has_non_finite_values(),
+                    "Plot item {:?} has NaN or infinite values",
+                    item.name()
+                );
+            }
+        }
+
+        let mut activated_point = None;
+        if keyboard_navigation {
+            if response.clicked() {
+                response.request_focus();
+            }
+            if response.has_focus() {
+                // Keep the arrow keys from moving the focus to another widget.
+                ui.memory_mut(|m| {
+                    m.set_focus_lock_filter(
+                        response.id,
+                        egui::EventFilter {
+                            horizontal_arrows: true,
+                            vertical_arrows: true,
+                            ..Default::default()
+                        },
+                    );
+                });
+                mem.selected_point =
+                    ui.input(|i| navigation::navigate(mem.selected_point, &items, i));
+                if ui.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::Space)) {
+                    activated_point = mem
+                        .selected_point
+                        .and_then(|selected| SelectedPoint::find(&items, selected));
+                }
+            }
+        } else {
+            mem.selected_point = None;
+        }
+        let selected_point = mem
+            .selected_point
+            .and_then(|selected| SelectedPoint::find(&items, selected));
+
+        let lasso_selection = if lasso_released {
+            mem.lasso
+                .take()
+                .map(|polygon| LassoSelection::new(polygon, &items))
+        } else {
+            None
+        };
+        let lasso_shape = mem.lasso.as_ref().map(|lasso| {
+            let points = lasso
+                .iter()
+                .map(|point| mem.transform.position_from_point(point))
+                .collect();
+            Shape::closed_line(points, ui.visuals().selection.stroke)
+        });
+        let measurement_shapes: Vec<Shape> = mem
+            .measurements
+            .iter()
+            .chain(&mem.measuring)
+            .flat_map(|measurement| measurement.shapes(ui, &mem.transform))
+            .collect();
+        let heatmap_selection = mem.heatmap_selection.clone();
+        let heatmap_selection_shape = heatmap_selection.as_ref().map(|selection| {
+            let rect = mem.transform.rect_from_values(
+                &selection.bounds.min().into(),
+                &selection.bounds.max().into(),
+            );
+            Shape::rect_stroke(
+                rect,
+                0.0,
+                ui.visuals().selection.stroke,
+                egui::StrokeKind::Outside,
+            )
+        });
+
+        let render_quality = if frame_budget.is_some() {
+            mem.render_quality
+        } else {
+            RenderQuality::Full
+        };
+        for item in &mut items {
+            item.set_render_quality(render_quality);
+        }
+
+        let clamp_range = clamp_grid.then(|| item_cache.data_bounds(&items));
+        let item_descriptions = ItemDescription::describe(&items, &mut item_cache);
+        item_cache.store(ui.ctx(), plot_id);
+
+        let prepared = PreparedPlot {
+            items,
+            show_x,
+            show_y,
+            show_delta,
+            pin_on_click,
+            pins: std::mem::take(&mut mem.pins),
+            selected_point,
+            label_formatter,
+            value_formatter,
+            coordinates_formatters,
+            show_grid,
+            minor_grid,
+            minor_grid_subdivisions,
+            polar,
+            grid_spacing,
+            transform: mem.transform,
+            draw_cursor_x: linked_cursors.as_ref().map_or(false, |group| group.1.x),
+            draw_cursor_y: linked_cursors.as_ref().map_or(false, |group| group.1.y),
+            draw_cursors,
+            cursor_color,
+            grid_spacers,
+            grid_strokes,
+            grid_styles,
+            grid_marks: [x_steps, y_steps],
+            previous_step_sizes,
+            snap_to_grid,
+            snap_to_item,
+            before_items,
+            after_items,
+            clamp_range,
+        };
+        let hovered_value = response
+            .hover_pos()
+            .map(|pointer| prepared.pointer_value(pointer));
+
+        let render_start = web_time::Instant::now();
+
+        let (hovered, pins, mesh_count) = prepared.ui(ui, &response);
+        let HoverResult {
+            cursors: plot_cursors,
+            item: hovered_plot_item,
+            items: hovered_plot_items,
+            ..
+        } = hovered;
+        mem.pins = pins;
+
+        if let Some(frame_budget) = frame_budget {
+            mem.render_quality = render_quality.adapt(frame_budget, render_start.elapsed());
+        }
+
+        if let Some(boxed_zoom_rect) = boxed_zoom_rect {
+            ui.painter()
+                .with_clip_rect(plot_rect)
+                .add(boxed_zoom_rect.0);
+            ui.painter()
+                .with_clip_rect(plot_rect)
+                .add(boxed_zoom_rect.1);
+        }
+
+        if let Some(lasso_shape) = lasso_shape {
+            ui.painter().with_clip_rect(plot_rect).add(lasso_shape);
+        }
+
+        ui.painter()
+            .with_clip_rect(plot_rect)
+            .extend(measurement_shapes);
+
+        if let Some(shape) = heatmap_selection_shape {
+            ui.painter().with_clip_rect(plot_rect).add(shape);
+        }
+
+        let mut legend_entries = Vec::new();
+        let mut new_legend_thickness = 0.0;
+        if let Some(mut legend) = legend {
+            ui.add(&mut legend);
+            mem.hidden_items = legend.hidden_items();
+            mem.hovered_legend_item = legend.hovered_item_name();
+            mem.legend_filter = legend.filter().to_owned();
+            mem.legend_offset = legend.offset();
+            if let Some(order) = legend.order() {
+                mem.legend_order = order.to_vec();
+            }
+            legend_entries = legend.entries();
+            mem.legend_entry_rects = legend.entry_rects();
+            new_legend_thickness = legend.thickness();
+        }
+        if let Some(hidden_items) = restored_hidden_items {
+            // The legend has already been shown this frame, so this takes effect next frame.
+            mem.hidden_items = hidden_items;
+            ui.ctx().request_repaint();
+        }
+        if mem.legend_thickness != new_legend_thickness {
+            // Make room for the legend outside the plot right away.
+            mem.legend_thickness = new_legend_thickness;
+            ui.ctx().request_repaint();
+        }
+
+        if let Some((id, _)) = linked_cursors.as_ref() {
+            // Push the frame we just drew to the list of frames
+            ui.data_mut(|data| {
+                let frames: &mut CursorLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                let cursors = frames.0.entry(*id).or_default();
+                cursors.push(PlotFrameCursors {
+                    id: plot_id,
+                    cursors: plot_cursors,
+                });
+            });
+        }
+
+        // After a reset that is broadcast, the other plots show the bounds of this one.
+        let broadcast_reset = double_click_reset && link_reset == LinkReset::Broadcast;
+        for (id, axes) in &linked_axes {
+            // Save the linked bounds.
+            ui.data_mut(|data| {
+                let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                let linked_bounds = link_groups.0.entry(*id).or_insert(LinkedBounds {
+                    bounds: PlotBounds::NOTHING,
+                    auto_bounds: Vec2b::FALSE,
+                    data_bounds: PlotBounds::NOTHING,
+                    last_data_bounds: PlotBounds::NOTHING,
+                    pass_nr,
+                });
+                linked_bounds.bounds = *mem.transform.bounds();
+                linked_bounds.auto_bounds = if broadcast_reset {
+                    Vec2b::FALSE
+                } else {
+                    mem.auto_bounds
+                };
+                let auto_axes = axes.and(Vec2b::new(auto_x, auto_y));
+                if link_reset == LinkReset::Group && auto_axes.any() {
+                    linked_bounds.add_data_bounds(pass_nr, &own_data_bounds, auto_axes);
+                }
+            });
+        }
+
+        let transform = mem.transform;
+        mem.store(ui.ctx(), plot_id);
+
+        let response = if show_x || show_y {
+            response.on_hover_cursor(CursorIcon::Crosshair)
+        } else {
+            response
+        };
+
+        ui.advance_cursor_after_rect(complete_rect);
+
+        PlotResponse {
+            inner,
+            response,
+            transform,
+            hovered_plot_item,
+            hovered_plot_items,
+            render_quality,
+            mesh_count,
+            dragged_point,
+            boxed_zoom,
+            boxed_zoom_in_progress,
+            lasso_selection,
+            heatmap_selection,
+            selected_point,
+            activated_point,
+            hovered_value,
+            description: PlotDescription {
+                bounds: *transform.bounds(),
+                items: item_descriptions,
+                x_ticks,
+                y_ticks,
+                legend_entries,
+            },
+        }
+    }
+}
+
+/// Finds the point of a draggable item at the given screen position, if any.
+///
+/// Returns the id of the item, the index of the point, and the offset of the position from the point.
+fn find_draggable_point(
+    items: &[Box<dyn PlotItem>],
+    pos: Pos2,
+    transform: &PlotTransform,
+    interact_radius: f32,
+) -> Option<(Id, usize, Vec2)> {
+    let (item, elem) = items
+        .iter()
+        .filter(|item| item.draggable())
+        .filter_map(|item| Some(item).zip(item.find_closest(pos, transform)))
+        .min_by_key(|(_, elem)| elem.dist_sq.ord())
+        .filter(|(_, elem)| elem.dist_sq <= interact_radius.powi(2))?;
+
+    let PlotGeometry::Points(points) = item.geometry() else {
+        return None;
+    };
+    let point = transform.position_from_point(points.get(elem.index)?);
+    let item_id = item.id().unwrap_or_else(|| Id::new(item.name()));
+    Some((item_id, elem.index, pos - point))
+}
+
+/// Returns the rect left after adding axes.
+fn axis_widgets<'a>(
+    mem: Option<&PlotMemory>,
+    show_axes: impl Into<Vec2b>,
+    complete_rect: Rect,
+    [x_axes, y_axes]: [&'a [AxisHints<'a>]; 2],
+) -> ([Vec<AxisWidget<'a>>; 2], Rect) {
+    // Next we want to create this layout.
+    // Indices are only examples.
+    //
+    //  left                     right
+    //  +---+---------x----------+   +
+    //  |   |      X-axis 3      |
+    //  |   +--------------------+    top
+    //  |   |      X-axis 2      |
+    //  +-+-+--------------------+-+-+
+    //  |y|y|                    |y|y|
+    //  |-|-|                    |-|-|
+    //  |A|A|                    |A|A|
+    // y|x|x|    Plot Window     |x|x|
+    //  |i|i|                    |i|i|
+    //  |s|s|                    |s|s|
+    //  |1|0|                    |2|3|
+    //  +-+-+--------------------+-+-+
+    //      |      X-axis 0      |   |
+    //      +--------------------+   | bottom
+    //      |      X-axis 1      |   |
+    //  +   +--------------------+---+
+    //
+    let show_axes = show_axes.into();
+
+    let mut x_axis_widgets = Vec::<AxisWidget<'_>>::new();
+    let mut y_axis_widgets = Vec::<AxisWidget<'_>>::new();
+
+    // Will shrink as we add more axes.
+    let mut rect_left = complete_rect;
+
+    if show_axes.x {
+        // We will fix this later, once we know how much space the y axes take up.
+        let initial_x_range = complete_rect.x_range();
+
+        for (i, cfg) in x_axes.iter().enumerate().rev() {
+            let mut height = cfg.thickness(Axis::X);
+            if let Some(mem) = mem {
+                // If the labels took up too much space the previous frame, give them more space now:
+                height = height.max(mem.x_axis_thickness.get(&i).copied().unwrap_or_default());
+            }
+
+            let rect = match VPlacement::from(cfg.placement) {
+                VPlacement::Bottom => {
+                    let bottom = rect_left.bottom();
+                    *rect_left.bottom_mut() -= height;
+                    let top = rect_left.bottom();
+                    Rect::from_x_y_ranges(initial_x_range, top..=bottom)
+                }
+                VPlacement::Top => {
+                    let top = rect_left.top();
+                    *rect_left.top_mut() += height;
+                    let bottom = rect_left.top();
+                    Rect::from_x_y_ranges(initial_x_range, top..=bottom)
+                }
+            };
+            x_axis_widgets.push(AxisWidget::new(cfg.clone(), rect));
+        }
+    }
+    if show_axes.y {
+        // We know this, since we've already allocated space for the x axes.
+        let plot_y_range = rect_left.y_range();
+
+        for (i, cfg) in y_axes.iter().enumerate().rev() {
+            let mut width = cfg.thickness(Axis::Y);
+            if let Some(mem) = mem {
+                // If the labels took up too much space the previous frame, give them more space now:
+                width = width.max(mem.y_axis_thickness.get(&i).copied().unwrap_or_default());
+            }
+
+            let rect = match HPlacement::from(cfg.placement) {
+                HPlacement::Left => {
+                    let left = rect_left.left();
+                    *rect_left.left_mut() += width;
+                    let right = rect_left.left();
+                    Rect::from_x_y_ranges(left..=right, plot_y_range)
+                }
+                HPlacement::Right => {
+                    let right = rect_left.right();
+                    *rect_left.right_mut() -= width;
+                    let left = rect_left.right();
+                    Rect::from_x_y_ranges(left..=right, plot_y_range)
+                }
+            };
+            y_axis_widgets.push(AxisWidget::new(cfg.clone(), rect));
+        }
+    }
+
+    // The loops iterated through {x,y}_axes in reverse order, so we have to reverse the
+    // {x,y}_axis_widgets vec as well. Otherwise, the indices are messed up and the plot memory
+    // (mem.{x,y}_axis_thickness) will access the wrong axis given an index.
+    x_axis_widgets.reverse();
+    y_axis_widgets.reverse();
+
+    let mut plot_rect = rect_left;
+
+    // If too little space, remove axis widgets
+    if plot_rect.width() <= 0.0 || plot_rect.height() <= 0.0 {
+        y_axis_widgets.clear();
+        x_axis_widgets.clear();
+        plot_rect = complete_rect;
+    }
+
+    // Now that we know the final x_range of the plot_rect,
+    // assign it to the x_axis_widgets (they are currently too wide):
+    for widget in &mut x_axis_widgets {
+        widget.rect = Rect::from_x_y_ranges(plot_rect.x_range(), widget.rect.y_range());
+    }
+
+    ([x_axis_widgets, y_axis_widgets], plot_rect)
+}
+
+/// User-requested modifications to the plot bounds. We collect them in the plot build function to later apply
+/// them at the right time, as other modifications need to happen first.
+pub(crate) enum BoundsModification {
+    Set(PlotBounds),
+    Translate(Vec2),
+    AutoBounds(Vec2b),
+    Zoom(Vec2, PlotPoint),
+    Undo,
+    Redo,
+    SaveView(String),
+    RestoreView(String),
+}
+
+// ----------------------------------------------------------------------------
+// Grid
+
+/// Input for "grid spacer" functions.
+///
+/// See [`Plot::x_grid_spacer()`] and [`Plot::y_grid_spacer()`].
+pub struct GridInput {
+    /// Min/max of the visible data range (the values at the two edges of the plot,
+    /// for the current axis).
+    pub bounds: (f64, f64),
+
+    /// Recommended (but not required) lower-bound on the step size returned by custom grid spacers.
+    ///
+    /// Computed as the ratio between the diagram's bounds (in plot coordinates) and the viewport
+    /// (in frame/window coordinates), scaled up to represent the minimal possible step.
+    ///
+    /// Always positive.
+    pub base_step_size: f64,
+
+    /// The smallest step size of the marks returned for this axis the previous frame, if any.
+    ///
+    /// A spacer can keep using it while [`Self::base_step_size`] is close to it, so that the grid
+    /// doesn't switch back and forth between two step sizes when zooming around a threshold,
+    /// or when the bounds change by a rounding error while panning. See [`log_grid_spacer`].
+    pub previous_step_size: Option<f64>,
+}
+
+/// One mark (horizontal or vertical line) in the background grid of a plot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridMark {
+    /// X or Y value in the plot.
+    pub value: f64,
+
+    /// The (approximate) distance to the next value of same thickness.
+    ///
+    /// Determines how thick the grid line is painted. It's not important that `step_size`
+    /// matches the difference between two `value`s precisely, but rather that grid marks of
+    /// same thickness have same `step_size`. For example, months can have a different number
+    /// of days, but consistently using a `step_size` of 30 days is a valid approximation.
+    pub step_size: f64,
+}
+
+/// The stroke of a grid line, see [`Plot::x_grid_stroke`] and [`Plot::y_grid_stroke`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridStroke {
+    /// Like the other grid lines, fading in as the lines get further apart.
+    Default,
+
+    /// This stroke, whatever the zoom.
+    Custom(Stroke),
+}
+
+/// Recursively splits the grid into `base` subdivisions (e.g. 100, 10, 1).
+///
+/// The logarithmic base, expressing how many times each grid unit is subdivided.
+/// 10 is a typical value, others are possible though.
+///
+/// The step sizes only change once [`GridInput::base_step_size`] is a bit past the threshold,
+/// see [`GridInput::previous_step_size`].
+pub fn log_grid_spacer(log_base: i64) -> GridSpacer<'static> {
+    let log_base = log_base as f64;
+    let step_sizes = move |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+
+        // The distance between two of the thinnest grid lines is "rounded" up
+        // to the next-bigger power of base, unless the one of the previous frame is still close enough.
+        let smallest_visible_unit = input
+            .previous_step_size
+            .filter(|&previous| keeps_step_size(previous, input.base_step_size, log_base))
+            .unwrap_or_else(|| next_power(input.base_step_size, log_base));
+
+        let step_sizes = [
+            smallest_visible_unit,
+            smallest_visible_unit * log_base,
+            smallest_visible_unit * log_base * log_base,
+        ];
+
+        generate_marks(step_sizes, input.bounds)
+    };
+
+    Box::new(step_sizes)
+}
+
+/// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
+///
+/// This function should return 3 positive step sizes, designating where the lines in the grid are drawn.
+/// Lines are thicker for larger step sizes. Ordering of returned value is irrelevant.
+///
+/// Why only 3 step sizes? Three is the number of different line thicknesses that egui typically uses in the grid.
+/// Ideally, those 3 are not hardcoded values, but depend on the visible range (accessible through `GridInput`).
+pub fn uniform_grid_spacer<'a>(spacer: impl Fn(GridInput) -> [f64; 3] + 'a) -> GridSpacer<'a> {
+    let get_marks = move |input: GridInput| -> Vec<GridMark> {
+        let bounds = input.bounds;
+        let step_sizes = spacer(input);
+        generate_marks(step_sizes, bounds)
+    };
+
+    Box::new(get_marks)
+}
+
+/// Marks at every power of ten and the whole multiples in between, for an axis with [`AxisScale::Log10`].
+///
+/// Like all grid spacers of a scaled axis, it gets the bounds and returns the marks
+/// in the space where the axis is linear, here the power of ten of the values.
+pub fn decade_grid_spacer() -> GridSpacer<'static> {
+    Box::new(|input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || max - min > 1000.0 {
+            return Vec::new();
+        }
+
+        let mut marks = Vec::new();
+        for decade in (min.floor() as i64)..=(max.ceil() as i64) {
+            let decade = decade as f64;
+            let step_size = if decade % 10.0 == 0.0 { 10.0 } else { 1.0 };
+            marks.push(GridMark {
+                value: decade,
+                step_size,
+            });
+            for k in 2..10 {
+                let k = k as f64;
+                marks.push(GridMark {
+                    value: decade + k.log10(),
+                    step_size: ((k + 1.0) / k).log10(),
+                });
+            }
+        }
+        marks.retain(|mark| (min..=max).contains(&mark.value));
+        marks
+    })
+}
+
+/// Marks at zero, the thresholds, every power of ten beyond them and the whole multiples in between,
+/// for an axis with [`AxisScale::SymLog`].
+///
+/// Like [`decade_grid_spacer`], it returns the marks in the space where the axis is linear,
+/// where the thresholds are at `±1` and each power of ten beyond them is one further out.
+pub fn symlog_grid_spacer() -> GridSpacer<'static> {
+    Box::new(|input: GridInput| -> Vec<GridMark> {
+        let (min, max) = input.bounds;
+        if !min.is_finite() || !max.is_finite() || max - min > 1000.0 {
+            return Vec::new();
+        }
+
+        let mut marks = vec![GridMark {
+            value: 0.0,
+            step_size: 10.0,
+        }];
+        for sign in [-1.0, 1.0] {
+            // The linear part, in tenths of the threshold.
+            for k in 1..10 {
+                marks.push(GridMark {
+                    value: sign * k as f64 / 10.0,
+                    step_size: 0.1,
+                });
+            }
+            // The decades beyond the threshold.
+            let outermost = if sign < 0.0 { -min } else { max };
+            for decade in 1..=(outermost.ceil() as i64) {
+                let decade = decade as f64;
+                marks.push(GridMark {
+                    value: sign * decade,
+                    step_size: 1.0,
+                });
+                for k in 2..10 {
+                    let k = k as f64;
+                    marks.push(GridMark {
+                        value: sign * (decade + k.log10()),
+                        step_size: ((k + 1.0) / k).log10(),
+                    });
+                }
+            }
+        }
+        marks.retain(|mark| (min..=max).contains(&mark.value));
+        marks
+    })
+}
+
+/// The grid spacer that goes with the scale, if it is not linear.
+fn scaled_grid_spacer(scale: AxisScale) -> Option<GridSpacer<'static>> {
+    match scale {
+        AxisScale::Linear => None,
+        AxisScale::Log10 { .. } => Some(decade_grid_spacer()),
+        AxisScale::SymLog { .. } => Some(symlog_grid_spacer()),
+    }
+}
+
+/// Moves marks from the space where the axis is linear back to the values they label.
+///
+/// The step sizes are kept, since they determine how far apart the marks are drawn.
+fn unscale_marks(mut marks: Vec<GridMark>, scale: AxisScale) -> Vec<GridMark> {
+    if scale != AxisScale::Linear {
+        for mark in &mut marks {
+            mark.value = scale.invert(mark.value);
+        }
+    }
+    marks
+}
+
+/// Tick labels for an axis with [`AxisScale::Log10`] or [`AxisScale::SymLog`], using exponents for very small or large values.
+fn log_axis_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+    let value = mark.value;
+    if value == 0.0 || (1e-4..1e5).contains(&value.abs()) {
+        emath::format_with_decimals_in_range(value, 0..=4)
+    } else {
+        let exponent = value.abs().log10().floor();
+        let mantissa = value / 10_f64.powf(exponent);
+        format!(
+            "{}e{exponent}",
+            emath::format_with_decimals_in_range(mantissa, 0..=2)
+        )
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+struct PreparedPlot<'a> {
+    items: Vec<Box<dyn PlotItem>>,
+    show_x: bool,
+    show_y: bool,
+    show_delta: bool,
+    pin_on_click: Option<PointerButton>,
+    label_formatter: LabelFormatter<'a>,
+    value_formatter: Option<Box<ValueFormatterFn<'a>>>,
+    coordinates_formatters: Vec<(Corner, CoordinatesFormatter<'a>)>,
+    // axis_formatters: [AxisFormatter; 2],
+    transform: PlotTransform,
+    show_grid: Vec2b,
+    minor_grid: Vec2b,
+    minor_grid_subdivisions: usize,
+    polar: bool,
+    grid_spacing: Rangef,
+    grid_spacers: [GridSpacer<'a>; 2],
+    grid_strokes: [Option<Box<GridStrokeFn<'a>>>; 2],
+    grid_styles: [LineStyle; 2],
+    grid_marks: [Arc<Vec<GridMark>>; 2],
+
+    /// The step sizes the grid spacers were given from the previous frame,
+    /// so that the grid is painted with the same marks as [`Self::grid_marks`].
+    previous_step_sizes: [Option<f64>; 2],
+    snap_to_grid: Option<f32>,
+    snap_to_item: Option<Id>,
+    before_items: Option<Box<PaintHookFn<'a>>>,
+    after_items: Option<Box<PaintHookFn<'a>>>,
+    draw_cursor_x: bool,
+    draw_cursor_y: bool,
+    draw_cursors: Vec<Cursor>,
+    cursor_color: Option<Color32>,
+    pins: Vec<Pin>,
+    selected_point: Option<SelectedPoint>,
+
+    /// Only show grid lines within these bounds, if set.
+    clamp_range: Option<PlotBounds>,
+}
+
+impl<'a> PreparedPlot<'a> {
+    /// Paints the plot, and returns what is hovered and the pinned labels.
+    /// Returns the hover result, the pinned labels and the number of meshes of the items.
+    fn ui(mut self, ui: &mut Ui, response: &Response) -> (HoverResult, Vec<Pin>, usize) {
+        let transform = &self.transform;
+
+        let mut plot_ui = ui.new_child(
+            egui::UiBuilder::new()
+                .max_rect(*transform.frame())
+                .layout(Layout::default()),
+        );
+        plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
+
+        let painter = ui.painter().with_clip_rect(*transform.frame());
+        if let Some(before_items) = &self.before_items {
+            before_items(&painter, transform);
+        }
+
+        let mut shapes = Vec::new();
+        let mut mesh_count = self.add_items(ui, &plot_ui, PlotLayer::Background, &mut shapes);
+
+        let mut axes_shapes = Vec::new();
+
+        if self.polar {
+            if self.show_grid.any() {
+                polar::paint_grid(
+                    ui,
+                    &self.transform,
+                    &self.grid_spacers[1],
+                    self.grid_spacing,
+                    &mut axes_shapes,
+                );
+            }
+        } else {
+            if self.show_grid.x {
+                self.paint_grid(ui, &mut axes_shapes, Axis::X, self.grid_spacing);
+            }
+            if self.show_grid.y {
+                self.paint_grid(ui, &mut axes_shapes, Axis::Y, self.grid_spacing);
+            }
+        }
+
+        // Sort the axes by strength so that those with higher strength are drawn in front.
+        axes_shapes.sort_by(|(_, strength1), (_, strength2)| strength1.total_cmp(strength2));
+
+        shapes.extend(axes_shapes.into_iter().map(|(shape, _)| shape));
+
+        mesh_count += self.add_items(ui, &plot_ui, PlotLayer::Normal, &mut shapes);
+        self.paint_auto_placed_labels(ui, &mut shapes);
+
+        let hover_pos = response.hover_pos();
+        let mut hovered = if let Some(pointer) = hover_pos {
+            self.hover(ui, pointer, &mut shapes)
+        } else if let Some(selected) = self.selected_point {
+            HoverResult {
+                cursors: self.show_selected(ui, selected, &mut shapes),
+                ..Default::default()
+            }
+        } else {
+            HoverResult::default()
+        };
+
+        // Pin the hovered label on click, or remove all pins when clicking elsewhere.
+        let mut pins = std::mem::take(&mut self.pins);
+        if let Some(button) = self.pin_on_click {
+            if response.clicked_by(button) {
+                match hovered.pin.take() {
+                    Some(pin) => pins.push(pin),
+                    None => pins.clear(),
+                }
+            }
+        }
+        self.paint_pins(ui, &pins, &mut shapes);
+
+        // Draw cursors
+        let line_color = self.cursor_color.unwrap_or_else(|| rulers_color(ui));
+
+        let mut draw_cursor = |cursors: &Vec<Cursor>, always| {
+            for &cursor in cursors {
+                match cursor {
+                    Cursor::Horizontal { y } => {
+                        if self.draw_cursor_y || always {
+                            shapes.push(horizontal_line(
+                                transform.position_from_point(&PlotPoint::new(0.0, y)),
+                                &self.transform,
+                                line_color,
+                            ));
+                        }
+                    }
+                    Cursor::Vertical { x } => {
+                        if self.draw_cursor_x || always {
+                            shapes.push(vertical_line(
+                                transform.position_from_point(&PlotPoint::new(x, 0.0)),
+                                &self.transform,
+                                line_color,
+                            ));
+                        }
+                    }
+                }
+            }
+        };
+
+        draw_cursor(&self.draw_cursors, false);
+        draw_cursor(&hovered.cursors, true);
+
+        mesh_count += self.add_items(ui, &plot_ui, PlotLayer::Foreground, &mut shapes);
+
+        painter.extend(shapes);
+        if let Some(after_items) = &self.after_items {
+            after_items(&painter, transform);
+        }
+
+        self.coordinates_ui(ui, response);
+
+        (hovered, pins, mesh_count)
+    }
+
+    /// The items to draw on the given layer, in the order they were added.
+    fn items_on_layer(&self, layer: PlotLayer) -> impl Iterator<Item = &dyn PlotItem> {
+        self.items
+            .iter()
+            .map(|item| &**item)
+            .filter(move |item| item.layer() == layer)
+    }
+
+    /// Add the shapes of the items on the given layer to `shapes`, and return how many of them are meshes.
+    ///
+    /// Items that are not [clipped](PlotItem::clip) are painted right away instead, after the
+    /// shapes so far, so they can reach outside the frame.
+    fn add_items(&self, ui: &Ui, plot_ui: &Ui, layer: PlotLayer, shapes: &mut Vec<Shape>) -> usize {
+        let frame = *self.transform.frame();
+        let mut mesh_count = 0;
+        for item in self.items_on_layer(layer) {
+            if !item.clip() {
+                // Paint the shapes so far first, so they stay below the item.
+                ui.painter()
+                    .with_clip_rect(frame)
+                    .extend(std::mem::take(shapes));
+            }
+            let start = shapes.len();
+            item.shapes(plot_ui, &self.transform, shapes);
+            item.blend_mode().apply(&mut shapes[start..]);
+            mesh_count += shapes[start..]
+                .iter()
+                .filter(|shape| matches!(shape, Shape::Mesh(_)))
+                .count();
+            if !item.clip() {
+                ui.painter().extend(std::mem::take(shapes));
+            }
+        }
+        mesh_count
+    }
+
+    /// Shows the [`Plot::coordinates_formatter`] readouts in their corners.
+    fn coordinates_ui(&self, ui: &mut Ui, response: &Response) {
+        let Some(pointer) = response.hover_pos() else {
+            return;
+        };
+        let coordinate = self.pointer_value(pointer);
+        for corner in Corner::all() {
+            let mut formatters = self
+                .coordinates_formatters
+                .iter()
+                .filter(|(c, _)| *c == corner)
+                .peekable();
+            if formatters.peek().is_none() {
+                continue;
+            }
+            let layout = match corner {
+                Corner::LeftTop => Layout::top_down(egui::Align::Min),
+                Corner::RightTop => Layout::top_down(egui::Align::Max),
+                Corner::LeftBottom => Layout::bottom_up(egui::Align::Min),
+                Corner::RightBottom => Layout::bottom_up(egui::Align::Max),
+            };
+            let mut corner_ui = ui.new_child(
+                egui::UiBuilder::new()
+                    .max_rect(self.transform.frame().shrink(4.0))
+                    .layout(layout),
+            );
+            corner_ui.set_clip_rect(*self.transform.frame());
+            for (_, formatter) in formatters {
+                formatter.ui(
+                    &mut corner_ui,
+                    &coordinate,
+                    self.transform.bounds(),
+                    self.value_formatter.as_deref(),
+                    response,
+                );
+            }
+        }
+    }
+
+    /// The value under the pointer, snapped to a sample if [`Plot::snap_to_item`] is set,
+    /// or else to the closest visible grid line of each axis
+    /// if [`Plot::snap_to_grid`] is set and the line is close enough.
+    fn pointer_value(&self, pointer: Pos2) -> PlotPoint {
+        if let Some((_, _, sample)) = self.snapped_sample(pointer) {
+            return sample;
+        }
+        let value = self.transform.value_from_position(pointer);
+        let Some(max_distance) = self.snap_to_grid else {
+            return value;
+        };
+        let snap = |axis: Axis| {
+            let iaxis = usize::from(axis);
+            let to_screen = |value: f64| match axis {
+                Axis::X => self.transform.position_from_point_x(value),
+                Axis::Y => self.transform.position_from_point_y(value),
+            };
+            let points_per_value = self.transform.dpos_dvalue()[iaxis].abs();
+            self.grid_marks[iaxis]
+                .iter()
+                .filter(|mark| (points_per_value * mark.step_size) as f32 > self.grid_spacing.min)
+                .map(|mark| (mark.value, (to_screen(mark.value) - pointer[iaxis]).abs()))
+                .filter(|(_, distance)| *distance <= max_distance)
+                .min_by(|(_, distance1), (_, distance2)| distance1.total_cmp(distance2))
+                .map(|(mark, _)| mark)
+        };
+        PlotPoint::new(
+            snap(Axis::X).unwrap_or(value.x),
+            snap(Axis::Y).unwrap_or(value.y),
+        )
+    }
+
+    /// The sample of the [`Plot::snap_to_item`] item closest to the pointer, if there is one.
+    fn snapped_sample(&self, pointer: Pos2) -> Option<(&dyn PlotItem, ClosestElem, PlotPoint)> {
+        let id = self.snap_to_item?;
+        let item = self
+            .items
+            .iter()
+            .find(|item| item.id().unwrap_or_else(|| Id::new(item.name())) == id)?;
+        let elem = item.find_closest(pointer, &self.transform)?;
+        let PlotGeometry::Points(points) = item.geometry() else {
+            return None;
+        };
+        let sample = *points.get(elem.index)?;
+        Some((&**item, elem, sample))
+    }
+
+    /// Draws the labels placed by the plot, such as inline labels, so that they
+    /// cover as few of each other and of the data points as possible.
+    fn paint_auto_placed_labels(&self, ui: &Ui, shapes: &mut Vec<Shape>) {
+        let labels: Vec<_> = self
+            .items
+            .iter()
+            .filter_map(|item| item.auto_placed_label(ui))
+            .filter(|(value, ..)| value.is_finite())
+            .collect();
+        if labels.is_empty() {
+            return;
+        }
+
+        let frame = *self.transform.frame();
+        let obstacles = self
+            .items
+            .iter()
+            .filter_map(|item| match item.geometry() {
+                PlotGeometry::Points(points) => Some(points),
+                PlotGeometry::None | PlotGeometry::Rects => None,
+            })
+            .flatten()
+            .map(|point| self.transform.position_from_point(point))
+            .filter(|pos| frame.contains(*pos))
+            .collect();
+        let mut layout = LabelLayout::new(frame, obstacles);
+
+        for (value, galley, color) in labels {
+            let anchor = self.transform.position_from_point(&value);
+            if !frame.contains(anchor) {
+                continue;
+            }
+            let rect = layout.place(anchor, galley.size());
+            shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
+        }
+    }
+
+    fn paint_grid(&self, ui: &Ui, shapes: &mut Vec<(Shape, f32)>, axis: Axis, fade_range: Rangef) {
+        #![allow(clippy::collapsible_else_if)]
+        let Self {
+            transform,
+            // axis_formatters,
+            grid_spacers,
+            grid_strokes,
+            grid_styles,
+            minor_grid,
+            minor_grid_subdivisions,
+            clamp_range,
+            ..
+        } = self;
+
+        let iaxis = usize::from(axis);
+
+        // Where on the cross-dimension to show the label values
+        let bounds = transform.bounds();
+        let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
+
+        let scaled_bounds = transform.scaled_bounds();
+        let input = GridInput {
+            bounds: (scaled_bounds.min[iaxis], scaled_bounds.max[iaxis]),
+            base_step_size: transform.dvalue_dpos()[iaxis].abs() * fade_range.min as f64,
+            previous_step_size: self.previous_step_sizes[iaxis],
+        };
+        let (bounds, base_step_size) = (input.bounds, input.base_step_size);
+        let steps = (grid_spacers[iaxis])(input);
+        let minor_steps = if minor_grid[iaxis] {
+            minor_marks(&steps, bounds, base_step_size, *minor_grid_subdivisions)
+        } else {
+            Vec::new()
+        };
+        let steps = unscale_marks(steps, transform.scales()[iaxis])
+            .into_iter()
+            .map(|step| (step, false));
+        let minor_steps = unscale_marks(minor_steps, transform.scales()[iaxis])
+            .into_iter()
+            .map(|step| (step, true));
+
+        for (step, is_minor) in steps.chain(minor_steps) {
+            let value_main = step.value;
+
+            if let Some(clamp_range) = clamp_range {
+                match axis {
+                    Axis::X => {
+                        if !clamp_range.range_x().contains(&value_main) {
+                            continue;
+                        };
+                    }
+                    Axis::Y => {
+                        if !clamp_range.range_y().contains(&value_main) {
+                            continue;
+                        };
+                    }
+                }
+            }
+
+            let value = match axis {
+                Axis::X => PlotPoint::new(value_main, value_cross),
+                Axis::Y => PlotPoint::new(value_cross, value_main),
+            };
+
+            // Snap the line to the center of a pixel, so that it is equally sharp
+            // wherever the plot is panned to, instead of shimmering as it moves across pixels.
+            let mut pos_in_gui = transform.position_from_point(&value);
+            pos_in_gui[iaxis] = ui.painter().round_to_pixel_center(pos_in_gui[iaxis]);
+            let spacing_in_points = (transform.dpos_dvalue()[iaxis] * step.step_size).abs() as f32;
+
+            let line_strength = if is_minor {
+                MINOR_GRID_STRENGTH
+            } else if spacing_in_points <= fade_range.min {
+                continue; // Too close together
+            } else {
+                mark_strength(spacing_in_points, fade_range)
+            };
+
+            let style = grid_strokes[iaxis]
+                .as_ref()
+                .map_or(GridStroke::Default, |grid_stroke| grid_stroke(step));
+            let (stroke, line_strength) = match style {
+                GridStroke::Default => (
+                    Stroke::new(1.0, color_from_strength(ui, line_strength)),
+                    line_strength,
+                ),
+                // Draw the custom lines in front of the others.
+                GridStroke::Custom(stroke) => (stroke, f32::INFINITY),
+            };
+
+            let mut p0 = pos_in_gui;
+            let mut p1 = pos_in_gui;
+            p0[1 - iaxis] = transform.frame().min[1 - iaxis];
+            p1[1 - iaxis] = transform.frame().max[1 - iaxis];
+
+            if let Some(clamp_range) = clamp_range {
+                match axis {
+                    Axis::X => {
+                        p0.y = transform.position_from_point_y(clamp_range.min[1]);
+                        p1.y = transform.position_from_point_y(clamp_range.max[1]);
+                    }
+                    Axis::Y => {
+                        p0.x = transform.position_from_point_x(clamp_range.min[0]);
+                        p1.x = transform.position_from_point_x(clamp_range.max[0]);
+                    }
+                }
+            }
+
+            let line = if grid_styles[iaxis] == LineStyle::Solid {
+                Shape::line_segment([p0, p1], stroke)
+            } else {
+                let mut line = Vec::new();
+                grid_styles[iaxis].style_line(vec![p0, p1], stroke, false, &mut line);
+                Shape::Vec(line)
+            };
+            shapes.push((line, line_strength));
+        }
+    }
+
+    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> HoverResult {
+        let Self {
+            transform,
+            show_x,
+            show_y,
+            show_delta,
+            label_formatter,
+            value_formatter,
+            items,
+            ..
+        } = self;
+
+        if !show_x && !show_y {
+            return HoverResult::default();
+        }
+
+        let interact_radius_sq = ui.style().interaction.interact_radius.powi(2);
+
+        let candidates = items
+            .iter()
+            .filter(|entry| entry.allow_hover())
+            .filter_map(|item| {
+                let item = &**item;
+                let closest = item.find_closest(pointer, transform);
+
+                Some(item).zip(closest)
+            });
+
+        // The items within reach, by descending priority and then by distance.
+        let mut within_reach: Vec<_> = candidates
+            .filter(|(_, elem)| elem.dist_sq <= interact_radius_sq)
+            .collect();
+        within_reach.sort_by(|(item1, elem1), (item2, elem2)| {
+            item2
+                .hover_priority()
+                .cmp(&item1.hover_priority())
+                .then(elem1.dist_sq.total_cmp(&elem2.dist_sq))
+        });
+        let hovered_ids = within_reach
+            .iter()
+            .filter_map(|(item, _)| item.id())
+            .collect();
+        let closest = within_reach.into_iter().next();
+
+        let plot = items::PlotConfig {
+            ui,
+            transform,
+            show_x: *show_x,
+            show_y: *show_y,
+            show_delta: *show_delta,
+            value_formatter: value_formatter.as_deref(),
+            items,
+        };
+
+        let mut cursors = Vec::new();
+
+        let (hovered_plot_item_id, pin) = if let Some((item, elem)) = closest {
+            let index = elem.index;
+            item.on_hover(elem, shapes, &mut cursors, &plot, label_formatter);
+            let value = match item.geometry() {
+                PlotGeometry::Points(points) => points.get(index).copied(),
+                PlotGeometry::None | PlotGeometry::Rects => None,
+            }
+            .unwrap_or_else(|| transform.value_from_position(pointer));
+            let pin = Pin {
+                name: item.name().to_owned(),
+                value,
+            };
+            (item.id(), Some(pin))
+        } else if let Some((item, elem, value)) = self.snapped_sample(pointer) {
+            // Show the sample like a hovered one, but don't count the item as hovered.
+            item.on_hover(elem, shapes, &mut cursors, &plot, label_formatter);
+            let pin = Pin {
+                name: item.name().to_owned(),
+                value,
+            };
+            (None, Some(pin))
+        } else {
+            let value = self.pointer_value(pointer);
+            items::rulers_at_value(
+                pointer,
+                value,
+                None,
+                "",
+                &plot,
+                shapes,
+                &mut cursors,
+                label_formatter,
+            );
+            (None, None)
+        };
+
+        HoverResult {
+            cursors,
+            item: hovered_plot_item_id,
+            items: hovered_ids,
+            pin,
+        }
+    }
+
+    /// Highlights the point selected with the keyboard like a hovered point.
+    fn show_selected(
+        &self,
+        ui: &Ui,
+        selected: SelectedPoint,
+        shapes: &mut Vec<Shape>,
+    ) -> Vec<Cursor> {
+        let mut cursors = Vec::new();
+        if !self.show_x && !self.show_y {
+            return cursors;
+        }
+        let Some(item) = self
+            .items
+            .iter()
+            .find(|item| item.id().unwrap_or_else(|| Id::new(item.name())) == selected.item_id)
+        else {
+            return cursors;
+        };
+
+        let plot = items::PlotConfig {
+            ui,
+            transform: &self.transform,
+            show_x: self.show_x,
+            show_y: self.show_y,
+            show_delta: self.show_delta,
+            value_formatter: self.value_formatter.as_deref(),
+            items: &self.items,
+        };
+        let elem = ClosestElem {
+            index: selected.index,
+            dist_sq: 0.0,
+        };
+        item.on_hover(elem, shapes, &mut cursors, &plot, &self.label_formatter);
+        cursors
+    }
+
+    /// Draws the hover labels the user has pinned in place.
+    fn paint_pins(&self, ui: &Ui, pins: &[Pin], shapes: &mut Vec<Shape>) {
+        if !self.show_x && !self.show_y {
+            return;
+        }
+
+        let plot = items::PlotConfig {
+            ui,
+            transform: &self.transform,
+            show_x: self.show_x,
+            show_y: self.show_y,
+            show_delta: false,
+            value_formatter: self.value_formatter.as_deref(),
+            items: &self.items,
+        };
+        let line_color = rulers_color(ui);
+
+        for pin in pins {
+            let pos = self.transform.position_from_point(&pin.value);
+            if !self.transform.frame().contains(pos) {
+                continue;
+            }
+            shapes.push(Shape::circle_filled(pos, 3.0, line_color));
+            items::rulers_at_value(
+                pos,
+                pin.value,
+                None,
+                &pin.name,
+                &plot,
+                shapes,
+                &mut Vec::new(),
+                &self.label_formatter,
+            );
+        }
+    }
+}
+
+/// Returns next bigger power in given base
+/// e.g.
+/// ```ignore
+/// use egui_plot::next_power;
+/// assert_eq!(next_power(0.01, 10.0), 0.01);
+/// assert_eq!(next_power(0.02, 10.0), 0.1);
+/// assert_eq!(next_power(0.2,  10.0), 1);
+/// ```
+pub(crate) fn next_power(value: f64, base: f64) -> f64 {
+    debug_assert_ne!(value, 0.0, "Bad input"); // can be negative (typical for Y axis)
+    base.powi(value.abs().log(base).ceil() as i32)
+}
+
+/// How far, as a fraction of the threshold, [`GridInput::base_step_size`] has to go past
+/// the thresholds of the step size of the previous frame before [`log_grid_spacer`] changes it.
+const STEP_SIZE_HYSTERESIS: f64 = 0.1;
+
+/// Can [`log_grid_spacer`] keep the step size of the previous frame with this base step size?
+///
+/// Only powers of the base are kept, in case the previous step size came from another spacer.
+fn keeps_step_size(previous: f64, base_step_size: f64, log_base: f64) -> bool {
+    let exponent = previous.log(log_base);
+    (exponent - exponent.round()).abs() < 1e-6
+        && previous / (log_base * (1.0 + STEP_SIZE_HYSTERESIS)) < base_step_size
+        && base_step_size <= previous * (1.0 + STEP_SIZE_HYSTERESIS)
+}
+
+/// The smallest step size of the marks, see [`GridInput::previous_step_size`].
+fn smallest_step_size(marks: &[GridMark]) -> Option<f64> {
+    marks
+        .iter()
+        .map(|mark| mark.step_size)
+        .filter(|step_size| *step_size > 0.0)
+        .min_by(f64::total_cmp)
+}
+
+/// How strongly to paint a grid line or tick label of a mark, from `0.0` to `1.0`,
+/// fading in as the marks of its step size get from `fade_range.min` to `fade_range.max` points apart.
+pub(crate) fn mark_strength(spacing_in_points: f32, fade_range: Rangef) -> f32 {
+    remap_clamp(spacing_in_points, fade_range, 0.0..=1.0)
+}
+
+/// How strong the lines of the [`Plot::minor_grid`] are, between `0.0` and `1.0` like those of the grid.
+const MINOR_GRID_STRENGTH: f32 = 0.05;
+
+/// Marks dividing the space between the finest of the `marks` into `subdivisions` parts,
+/// for the [`Plot::minor_grid`].
+///
+/// The finest marks are those at least `base_step_size` apart, which are shown,
+/// and whose parts are at least half that.
+fn minor_marks(
+    marks: &[GridMark],
+    (min, max): (f64, f64),
+    base_step_size: f64,
+    subdivisions: usize,
+) -> Vec<GridMark> {
+    /// Don't fill the grid with lines when zoomed out very far on a custom grid.
+    const MAX_MINOR_MARKS: f64 = 1000.0;
+
+    let subdivisions = subdivisions.max(2);
+    let min_step = base_step_size.abs();
+    let Some(major) = marks
+        .iter()
+        .filter(|mark| {
+            min_step <= mark.step_size && min_step <= 2.0 * mark.step_size / subdivisions as f64
+        })
+        .min_by(|a, b| a.step_size.total_cmp(&b.step_size))
+    else {
+        return Vec::new();
+    };
+
+    let step_size = major.step_size / subdivisions as f64;
+    if step_size <= 0.0 || !step_size.is_finite() || MAX_MINOR_MARKS < (max - min) / step_size {
+        return Vec::new();
+    }
+
+    // Count from a major mark, so the minor marks line up with the major ones
+    // also for grids that are not aligned to zero.
+    let first = ((min - major.value) / step_size).ceil() as i64;
+    let last = ((max - major.value) / step_size).floor() as i64;
+    (first..=last)
+        .filter(|i| i.rem_euclid(subdivisions as i64) != 0)
+        .map(|i| GridMark {
+            value: major.value + i as f64 * step_size,
+            step_size,
+        })
+        .collect()
+}
+
+/// Fill in all values between [min, max] which are a multiple of `step_size`
+pub(crate) fn generate_marks(step_sizes: [f64; 3], bounds: (f64, f64)) -> Vec<GridMark> {
+    let mut steps = vec![];
+    fill_marks_between(&mut steps, step_sizes[0], bounds);
+    fill_marks_between(&mut steps, step_sizes[1], bounds);
+    fill_marks_between(&mut steps, step_sizes[2], bounds);
+
+    let min_step = step_sizes.iter().fold(f64::INFINITY, |a, &b| a.min(b));
+    deduplicate_marks(steps, min_step)
+}
+
+/// Sorts the marks and keeps only the largest step of marks closer than a tenth of `min_step`.
+pub(crate) fn deduplicate_marks(mut steps: Vec<GridMark>, min_step: f64) -> Vec<GridMark> {
+    // Remove duplicates:
+    // This can happen because we have overlapping steps, e.g.:
+    // step_size[0] =   10  =>  [-10, 0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100, 110, 120]
+    // step_size[1] =  100  =>  [     0,                                     100          ]
+    // step_size[2] = 1000  =>  [     0                                                   ]
+
+    steps.sort_by(|a, b| cmp_f64(a.value, b.value));
+
+    let eps = 0.1 * min_step; // avoid putting two ticks too closely together
+
+    let mut deduplicated: Vec<GridMark> = Vec::with_capacity(steps.len());
+    for step in steps {
+        if let Some(last) = deduplicated.last_mut() {
+            if (last.value - step.value).abs() < eps {
+                // Keep the one with the largest step size
+                if last.step_size < step.step_size {
+                    *last = step;
+                }
+                continue;
+            }
+        }
+        deduplicated.push(step);
+    }
+
+    deduplicated
+}
+
+#[test]
+fn test_generate_marks() {
+    fn approx_eq(a: &GridMark, b: &GridMark) -> bool {
+        (a.value - b.value).abs() < 1e-10 && a.step_size == b.step_size
+    }
+
+    let gm = |value, step_size| GridMark { value, step_size };
+
+    let marks = generate_marks([0.01, 0.1, 1.0], (2.855, 3.015));
+    let expected = vec![
+        gm(2.86, 0.01),
+        gm(2.87, 0.01),
+        gm(2.88, 0.01),
+        gm(2.89, 0.01),
+        gm(2.90, 0.1),
+        gm(2.91, 0.01),
+        gm(2.92, 0.01),
+        gm(2.93, 0.01),
+        gm(2.94, 0.01),
+        gm(2.95, 0.01),
+        gm(2.96, 0.01),
+        gm(2.97, 0.01),
+        gm(2.98, 0.01),
+        gm(2.99, 0.01),
+        gm(3.00, 1.),
+        gm(3.01, 0.01),
+    ];
+
+    let mut problem = None;
+    if marks.len() != expected.len() {
+        problem = Some(format!(
+            "Different lengths: got {}, expected {}",
+            marks.len(),
+            expected.len()
+        ));
+    }
+
+    for (i, (a, b)) in marks.iter().zip(&expected).enumerate() {
+        if !approx_eq(a, b) {
+            problem = Some(format!("Mismatch at index {i}: {a:?} != {b:?}"));
+            break;
+        }
+    }
+
+    if let Some(problem) = problem {
+        panic!("Test failed: {problem}. Got: {marks:#?}, expected: {expected:#?}");
+    }
+}
+
+#[test]
+fn test_generate_marks_while_panning() {
+    // A mark shared by two views slightly panned apart is exactly the same in both.
+    let steps = [0.1, 1.0, 10.0];
+    let before = generate_marks(steps, (0.03, 2.03));
+    let after = generate_marks(steps, (0.0, 2.0));
+    let shared: Vec<_> = before.iter().filter(|mark| mark.value < 2.0).collect();
+    assert_eq!(shared, after.iter().skip(1).collect::<Vec<_>>());
+    assert_eq!(after.first().map(|mark| mark.value), Some(0.0));
+}
+
+#[test]
+fn test_log_grid_spacer_hysteresis() {
+    // The base step size wobbles around the threshold of 1, like when the bounds change by
+    // a rounding error while panning, then goes clearly past it, and back a little.
+    let spacer = log_grid_spacer(10);
+    let mut previous_step_size = None;
+    let mut smallest_step_sizes = Vec::new();
+    for base_step_size in [0.9, 0.99, 1.01, 0.99, 1.01, 1.2, 0.95] {
+        let marks = spacer(GridInput {
+            bounds: (0.0, 100.0),
+            base_step_size,
+            previous_step_size,
+        });
+        previous_step_size = smallest_step_size(&marks);
+        smallest_step_sizes.push(previous_step_size);
+    }
+    assert_eq!(
+        smallest_step_sizes,
+        [1.0, 1.0, 1.0, 1.0, 1.0, 10.0, 10.0].map(Some)
+    );
+}
+
+#[test]
+fn test_minor_marks() {
+    let bounds = (0.0, 2.0);
+    let marks = generate_marks([0.1, 1.0, 10.0], bounds);
+    let minor = minor_marks(&marks, bounds, 0.01, 5);
+    assert_eq!(minor.len(), 80);
+    assert!(minor.iter().all(|mark| mark.step_size == 0.1 / 5.0));
+    assert!(!minor.iter().any(|minor| marks
+        .iter()
+        .any(|mark| (mark.value - minor.value).abs() < 1e-9)));
+
+    // Too close together to subdivide the finest marks.
+    let minor = minor_marks(&marks, bounds, 0.1, 5);
+    assert!(minor.iter().all(|mark| mark.step_size == 1.0 / 5.0));
+}
+
+#[test]
+fn test_validate() {
+    assert_eq!(
+        Plot::new("plot").width(200.0).view_aspect(2.0).validate(),
+        Ok(())
+    );
+    assert_eq!(
+        Plot::new("plot").data_aspect(0.0).validate(),
+        Err(PlotError::InvalidAspect(0.0))
+    );
+    assert!(matches!(
+        Plot::new("plot")
+            .width(200.0)
+            .height(200.0)
+            .view_aspect(2.0)
+            .validate(),
+        Err(PlotError::ConflictingAspect(_))
+    ));
+    assert!(matches!(
+        Plot::new("plot")
+            .min_bounds_size([10.0, 0.0])
+            .max_bounds_size([1.0, 1.0])
+            .validate(),
+        Err(PlotError::InvalidBoundsSize { axis: Axis::X, .. })
+    ));
+    assert!(matches!(
+        Plot::new("plot")
+            .custom_y_axes(vec![AxisHints::new_y(), AxisHints::new_y()])
+            .validate(),
+        Err(PlotError::DuplicateAxis { axis: Axis::Y, .. })
+    ));
+}
+
+fn cmp_f64(a: f64, b: f64) -> Ordering {
+    match a.partial_cmp(&b) {
+        Some(ord) => ord,
+        None => a.is_nan().cmp(&b.is_nan()),
+    }
+}
+
+/// Fill in all values between [min, max] which are a multiple of `step_size`
+///
+/// The marks are whole multiples of the step, so they are the same wherever the bounds are panned to.
+pub(crate) fn fill_marks_between(out: &mut Vec<GridMark>, step_size: f64, (min, max): (f64, f64)) {
+    debug_assert!(min <= max, "Bad plot bounds: min: {min}, max: {max}");
+    let first = (min / step_size).ceil() as i64;
+    let last = (max / step_size).ceil() as i64;
+
+    let marks_iter = (first..last).map(|i| {
+        let value = (i as f64) * step_size;
+        GridMark { value, step_size }
+    });
+    out.extend(marks_iter);
+}
+
+/// Helper for formatting a number so that we always show at least a few decimals,
+/// unless it is an integer, in which case we never show any decimals.
+pub fn format_number(number: f64, num_decimals: usize) -> String {
+    let is_integral = number as i64 as f64 == number;
+    if is_integral {
+        // perfect integer - show it as such:
+        format!("{number:.0}")
+    } else {
+        // make sure we tell the user it is not an integer by always showing a decimal or two:
+        format!("{:.*}", num_decimals.at_least(1), number)
+    }
+}
+
+/// Determine a color from a 0-1 strength value.
+pub fn color_from_strength(ui: &Ui, strength: f32) -> Color32 {
+    let base_color = ui.visuals().text_color();
+    base_color.gamma_multiply(strength.sqrt())
+}
+
+#[test]
+fn test_symlog_grid_spacer() {
+    let marks = symlog_grid_spacer()(GridInput {
+        bounds: (-2.5, 1.5),
+        base_step_size: 0.01,
+        previous_step_size: None,
+    });
+    let scale = AxisScale::symlog(1.0);
+    let values: Vec<f64> = unscale_marks(marks, scale)
+        .iter()
+        .map(|mark| mark.value)
+        .collect();
+    for value in [-30.0, -20.0, -10.0, -1.0, -0.5, 0.0, 0.3, 1.0, 2.0] {
+        assert!(
+            values.iter().any(|v| (v - value).abs() < 1e-9),
+            "missing {value}"
+        );
+    }
+    assert!(values.iter().all(|v| (-31.7..=3.17).contains(v)));
+}
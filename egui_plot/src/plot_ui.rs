@@ -1,6 +1,9 @@
-use egui::{epaint::Hsva, Color32, Pos2, Response, Vec2, Vec2b};
+use egui::{Color32, Id, Pos2, Response, Vec2, Vec2b};
 
-use crate::{BoundsModification, PlotBounds, PlotItem, PlotPoint, PlotTransform};
+use crate::{
+    colors, items::Decoration, BoundsModification, DecorationItem, PlotBounds, PlotItem, PlotPoint,
+    PlotTransform,
+};
 
 #[allow(unused_imports)] // for links in docstrings
 use crate::Plot;
@@ -11,6 +14,9 @@ pub struct PlotUi {
     pub(crate) ctx: egui::Context,
     pub(crate) items: Vec<Box<dyn PlotItem>>,
     pub(crate) next_auto_color_idx: usize,
+
+    /// Items with the same name get the same color in all plots of this group.
+    pub(crate) color_group: Option<Id>,
     pub(crate) last_plot_transform: PlotTransform,
     pub(crate) last_auto_bounds: Vec2b,
     pub(crate) response: Response,
@@ -18,12 +24,26 @@ pub struct PlotUi {
 }
 
 impl PlotUi {
-    fn auto_color(&mut self) -> Color32 {
+    fn auto_color(&mut self, name: &str) -> Color32 {
+        if let Some(group) = self.color_group.filter(|_| !name.is_empty()) {
+            let i = self.ctx.data_mut(|d| {
+                let names: &mut Vec<String> = d.get_temp_mut_or_default(group);
+                names.iter().position(|n| n == name).unwrap_or_else(|| {
+                    names.push(name.to_owned());
+                    names.len() - 1
+                })
+            });
+            return colors::auto_color(i);
+        }
+
         let i = self.next_auto_color_idx;
         self.next_auto_color_idx += 1;
-        let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
-        let h = i as f32 * golden_ratio;
-        Hsva::new(h, 0.85, 0.5, 1.0).into() // TODO(emilk): OkLab or some other perspective color space
+        colors::auto_color(i)
+    }
+
+    /// The item names of a color group, in the order their colors were assigned.
+    pub(crate) fn color_group_names(ctx: &egui::Context, group: Id) -> Vec<String> {
+        ctx.data_mut(|d| d.get_temp(group)).unwrap_or_default()
     }
 
     pub fn ctx(&self) -> &egui::Context {
@@ -61,6 +81,37 @@ impl PlotUi {
             .push(BoundsModification::AutoBounds(auto_bounds.into()));
     }
 
+    /// Go back to the view from before the last pan or zoom, if any.
+    ///
+    /// See also [`crate::Plot::view_history_shortcuts`].
+    pub fn undo_view(&mut self) {
+        self.bounds_modifications.push(BoundsModification::Undo);
+    }
+
+    /// Go forward to the view from before the last [`Self::undo_view`], if any.
+    pub fn redo_view(&mut self) {
+        self.bounds_modifications.push(BoundsModification::Redo);
+    }
+
+    /// Save the current view, and which items are hidden in the legend,
+    /// under the given name, replacing any view saved under it before.
+    ///
+    /// The saved views are kept in the [`crate::PlotMemory`], and persisted with it if the `serde` feature is enabled.
+    pub fn save_view(&mut self, name: impl Into<String>) {
+        self.bounds_modifications
+            .push(BoundsModification::SaveView(name.into()));
+    }
+
+    /// Go to the view saved under the given name with [`Self::save_view`], if there is one.
+    ///
+    /// The items hidden when the view was saved are hidden again, starting next frame.
+    ///
+    /// See also [`crate::Plot::view_bookmark_shortcuts`].
+    pub fn restore_view(&mut self, name: impl Into<String>) {
+        self.bounds_modifications
+            .push(BoundsModification::RestoreView(name.into()));
+    }
+
     /// Can be used to check if the plot was hovered or clicked.
     pub fn response(&self) -> &Response {
         &self.response
@@ -131,6 +182,13 @@ impl PlotUi {
         self.items.push(item);
     }
 
+    /// Add a decoration, like a shaded span or a reference line.
+    ///
+    /// See [`DecorationItem`] for how it differs from the other items.
+    pub fn decoration(&mut self, decoration: impl DecorationItem + 'static) {
+        self.items.push(Box::new(Decoration(decoration)));
+    }
+
     /// Add a data line.
     pub fn line(&mut self, mut line: crate::Line) {
         if line.series.is_empty() {
@@ -139,11 +197,24 @@ impl PlotUi {
 
         // Give the stroke an automatic color if no color has been assigned.
         if line.stroke.color == Color32::TRANSPARENT {
-            line.stroke.color = self.auto_color();
+            line.stroke.color = self.auto_color(&line.name);
         }
         self.items.push(Box::new(line));
     }
 
+    /// Add the runs of an ensemble, with a single legend entry.
+    pub fn ensemble(&mut self, mut ensemble: crate::Ensemble) {
+        if ensemble.is_empty() {
+            return;
+        }
+
+        // Give the stroke an automatic color if no color has been assigned.
+        if ensemble.stroke.color == Color32::TRANSPARENT {
+            ensemble.stroke.color = self.auto_color(&ensemble.name);
+        }
+        self.items.push(Box::new(ensemble));
+    }
+
     /// Add a polygon. The polygon has to be convex.
     pub fn polygon(&mut self, mut polygon: crate::Polygon) {
         if polygon.series.is_empty() {
@@ -152,7 +223,7 @@ impl PlotUi {
 
         // Give the stroke an automatic color if no color has been assigned.
         if polygon.stroke.color == Color32::TRANSPARENT {
-            polygon.stroke.color = self.auto_color();
+            polygon.stroke.color = self.auto_color(&polygon.name);
         }
         self.items.push(Box::new(polygon));
     }
@@ -163,7 +234,7 @@ impl PlotUi {
             return;
         };
 
-        self.items.push(Box::new(text));
+        self.decoration(text);
     }
 
     /// Add data points.
@@ -174,7 +245,7 @@ impl PlotUi {
 
         // Give the points an automatic color if no color has been assigned.
         if points.color == Color32::TRANSPARENT {
-            points.color = self.auto_color();
+            points.color = self.auto_color(&points.name);
         }
         self.items.push(Box::new(points));
     }
@@ -187,14 +258,14 @@ impl PlotUi {
 
         // Give the arrows an automatic color if no color has been assigned.
         if arrows.color == Color32::TRANSPARENT {
-            arrows.color = self.auto_color();
+            arrows.color = self.auto_color(&arrows.name);
         }
         self.items.push(Box::new(arrows));
     }
 
     /// Add an image.
     pub fn image(&mut self, image: crate::PlotImage) {
-        self.items.push(Box::new(image));
+        self.decoration(image);
     }
 
     /// Add a horizontal line.
@@ -202,9 +273,9 @@ impl PlotUi {
     /// Always fills the full width of the plot.
     pub fn hline(&mut self, mut hline: crate::HLine) {
         if hline.stroke.color == Color32::TRANSPARENT {
-            hline.stroke.color = self.auto_color();
+            hline.stroke.color = self.auto_color(&hline.name);
         }
-        self.items.push(Box::new(hline));
+        self.decoration(hline);
     }
 
     /// Add a vertical line.
@@ -212,9 +283,25 @@ impl PlotUi {
     /// Always fills the full height of the plot.
     pub fn vline(&mut self, mut vline: crate::VLine) {
         if vline.stroke.color == Color32::TRANSPARENT {
-            vline.stroke.color = self.auto_color();
+            vline.stroke.color = self.auto_color(&vline.name);
+        }
+        self.decoration(vline);
+    }
+
+    /// Add a straight line or ray through a point, extended to the edges of the plot.
+    pub fn ab_line(&mut self, mut line: crate::AbLine) {
+        if line.stroke.color == Color32::TRANSPARENT {
+            line.stroke.color = self.auto_color(&line.name);
+        }
+        self.decoration(line);
+    }
+
+    /// Add lines or bands repeating along an axis, computed for the current view.
+    pub fn recurring_lines(&mut self, mut lines: crate::RecurringLines) {
+        if lines.stroke.color == Color32::TRANSPARENT {
+            lines.stroke.color = self.auto_color(&lines.name);
         }
-        self.items.push(Box::new(vline));
+        self.decoration(lines);
     }
 
     /// Add a box plot diagram.
@@ -225,7 +312,8 @@ impl PlotUi {
 
         // Give the elements an automatic color if no color has been assigned.
         if box_plot.default_color == Color32::TRANSPARENT {
-            box_plot = box_plot.color(self.auto_color());
+            let color = self.auto_color(&box_plot.name);
+            box_plot = box_plot.color(color);
         }
         self.items.push(Box::new(box_plot));
     }
@@ -238,8 +326,74 @@ impl PlotUi {
 
         // Give the elements an automatic color if no color has been assigned.
         if chart.default_color == Color32::TRANSPARENT {
-            chart = chart.color(self.auto_color());
+            let color = self.auto_color(&chart.name);
+            chart = chart.color(color);
         }
         self.items.push(Box::new(chart));
     }
+
+    /// Add a heatmap.
+    pub fn heatmap(&mut self, heatmap: crate::Heatmap) {
+        if heatmap.rows() == 0 {
+            return;
+        }
+        self.items.push(Box::new(heatmap));
+    }
+
+    /// Add a hexbin density plot.
+    pub fn hexbin(&mut self, hexbin: crate::Hexbin) {
+        if hexbin.points.is_empty() {
+            return;
+        }
+        self.items.push(Box::new(hexbin));
+    }
+
+    /// Add a shaded rectangular region.
+    pub fn region(&mut self, mut region: crate::Region) {
+        if region.stroke.color == Color32::TRANSPARENT {
+            region.stroke.color = self.auto_color(&region.name);
+        }
+        self.decoration(region);
+    }
+
+    /// Add a curve made of cubic Bézier segments.
+    pub fn cubic_bezier(&mut self, mut curve: crate::CubicBezier) {
+        if curve.stroke.color == Color32::TRANSPARENT {
+            curve.stroke.color = self.auto_color(&curve.name);
+        }
+        self.items.push(Box::new(curve));
+    }
+
+    /// Add an ellipse or circle.
+    pub fn ellipse(&mut self, mut ellipse: crate::Ellipse) {
+        if ellipse.stroke.color == Color32::TRANSPARENT {
+            ellipse.stroke.color = self.auto_color(&ellipse.name);
+        }
+        self.items.push(Box::new(ellipse));
+    }
+
+    /// Add a vector field, re-sampled for the current view.
+    pub fn vector_field(&mut self, mut field: crate::VectorField) {
+        if field.stroke.color == Color32::TRANSPARENT {
+            field.stroke.color = self.auto_color(&field.name);
+        }
+        self.items.push(Box::new(field));
+    }
+
+    /// Add a radar chart, with a legend entry for each of its series.
+    pub fn radar(&mut self, radar: crate::Radar) {
+        if radar.axes.is_empty() {
+            return;
+        }
+
+        let (grid, areas) = radar.into_items();
+        self.items.push(Box::new(grid));
+        for mut area in areas {
+            // Give the series an automatic color if no color has been assigned.
+            if area.series.color == Color32::TRANSPARENT {
+                area.series.color = self.auto_color(&area.series.name);
+            }
+            self.items.push(Box::new(area));
+        }
+    }
 }
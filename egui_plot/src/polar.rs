@@ -0,0 +1,124 @@
+use std::f64::consts::TAU;
+
+use egui::{Align2, Rangef, Shape, Stroke, TextStyle, Ui};
+
+use crate::{
+    color_from_strength, mark_strength, GridInput, GridSpacer, LabelFormatterFn, PlotPoint,
+    PlotTransform,
+};
+
+/// The number of radial spokes of the grid.
+const SPOKES: usize = 12;
+
+/// The grid of a plot in [`crate::Plot::polar`] mode: concentric circles around the origin
+/// at the values of the y-axis grid spacer, and radial spokes every 30°.
+pub(crate) fn paint_grid(
+    ui: &Ui,
+    transform: &PlotTransform,
+    spacer: &GridSpacer<'_>,
+    fade_range: Rangef,
+    shapes: &mut Vec<(Shape, f32)>,
+) {
+    let bounds = transform.bounds();
+    let center = transform.position_from_point(&PlotPoint::new(0.0, 0.0));
+    let dpos_dvalue = transform.dpos_dvalue_x().abs();
+
+    // The range of radii within the view.
+    let corners = [
+        [bounds.min[0], bounds.min[1]],
+        [bounds.min[0], bounds.max[1]],
+        [bounds.max[0], bounds.min[1]],
+        [bounds.max[0], bounds.max[1]],
+    ];
+    let max_radius = corners.iter().map(|[x, y]| x.hypot(*y)).fold(0.0, f64::max);
+    let closest = PlotPoint::new(
+        0.0_f64.clamp(bounds.min[0], bounds.max[0]),
+        0.0_f64.clamp(bounds.min[1], bounds.max[1]),
+    );
+    let min_radius = closest.x.hypot(closest.y);
+
+    let font_id = TextStyle::Small.resolve(ui.style());
+    let input = GridInput {
+        bounds: (min_radius, max_radius),
+        base_step_size: transform.dvalue_dpos()[0].abs() * fade_range.min as f64,
+        previous_step_size: None,
+    };
+    for step in spacer(input) {
+        let spacing_in_points = (dpos_dvalue * step.step_size) as f32;
+        if step.value <= 0.0 || spacing_in_points <= fade_range.min {
+            continue;
+        }
+        let strength = mark_strength(spacing_in_points, fade_range);
+        let color = color_from_strength(ui, strength);
+        let radius = (step.value * dpos_dvalue) as f32;
+        shapes.push((
+            Shape::circle_stroke(center, radius, Stroke::new(1.0, color)),
+            strength,
+        ));
+
+        // Label the circles along the first spoke.
+        let label_pos = center + egui::vec2(radius, 0.0);
+        if strength > 0.5 && transform.frame().contains(label_pos) {
+            let text = emath::format_with_decimals_in_range(step.value, 0..=6);
+            let label = ui.fonts(|f| {
+                Shape::text(
+                    f,
+                    label_pos + egui::vec2(2.0, -2.0),
+                    Align2::LEFT_BOTTOM,
+                    text,
+                    font_id.clone(),
+                    ui.visuals().text_color(),
+                )
+            });
+            shapes.push((label, strength));
+        }
+    }
+
+    let spoke_color = color_from_strength(ui, 0.5);
+    let spoke_length = (max_radius * dpos_dvalue) as f32;
+    for i in 0..SPOKES {
+        let angle = i as f64 * TAU / SPOKES as f64;
+        let direction = egui::vec2(angle.cos() as f32, -angle.sin() as f32);
+        shapes.push((
+            Shape::line_segment(
+                [center, center + spoke_length * direction],
+                Stroke::new(1.0, spoke_color),
+            ),
+            0.5,
+        ));
+
+        // Label the spokes next to the edge of the view, if the origin is in view.
+        if transform.frame().contains(center) {
+            let edge = (0..)
+                .map(|distance| center + (distance * 4) as f32 * direction)
+                .take_while(|pos| transform.frame().shrink(12.0).contains(*pos))
+                .last();
+            if let Some(edge) = edge {
+                let label = ui.fonts(|f| {
+                    Shape::text(
+                        f,
+                        edge,
+                        Align2::CENTER_CENTER,
+                        format!("{}°", i * 360 / SPOKES),
+                        font_id.clone(),
+                        ui.visuals().text_color(),
+                    )
+                });
+                shapes.push((label, 1.0));
+            }
+        }
+    }
+}
+
+/// The default hover label of a plot in [`crate::Plot::polar`] mode, showing the angle and radius.
+pub(crate) fn label_formatter<'a>() -> Box<LabelFormatterFn<'a>> {
+    Box::new(|name: &str, value: &PlotPoint| {
+        let [angle, radius] = value.to_polar();
+        let prefix = if name.is_empty() {
+            String::new()
+        } else {
+            format!("{name}\n")
+        };
+        format!("{prefix}θ = {:.1}°\nr = {radius:.3}", angle.to_degrees())
+    })
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+/// How much detail the plot items are drawn with.
+///
+/// When a [`crate::Plot::frame_budget`] is set, the quality is lowered step by step while
+/// drawing the plot takes longer than the budget, and raised again once there is plenty of
+/// time to spare.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RenderQuality {
+    /// Everything is drawn as requested.
+    #[default]
+    Full,
+
+    /// Lines skip points that are less than a ui point apart on screen,
+    /// and markers smaller than a pixel are not drawn.
+    Reduced,
+
+    /// Like [`Self::Reduced`], but lines are thinned out more aggressively
+    /// and fills are drawn without anti-aliasing.
+    Low,
+}
+
+impl RenderQuality {
+    /// Minimum on-screen distance between two consecutive points of a line, in ui points.
+    pub(crate) fn min_point_distance(self) -> f32 {
+        match self {
+            Self::Full => 0.0,
+            Self::Reduced => 1.0,
+            Self::Low => 2.0,
+        }
+    }
+
+    /// The quality to use next frame, given how long this frame took to draw.
+    pub(crate) fn adapt(self, budget: Duration, elapsed: Duration) -> Self {
+        if elapsed > budget {
+            match self {
+                Self::Full => Self::Reduced,
+                Self::Reduced | Self::Low => Self::Low,
+            }
+        } else if elapsed < budget / 4 {
+            // Only go back up with a wide margin, to avoid flickering between two levels.
+            match self {
+                Self::Full | Self::Reduced => Self::Full,
+                Self::Low => Self::Reduced,
+            }
+        } else {
+            self
+        }
+    }
+}
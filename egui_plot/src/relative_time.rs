@@ -0,0 +1,131 @@
+use std::ops::RangeInclusive;
+
+use crate::{generate_marks, next_power, GridInput, GridMark, GridSpacer};
+
+pub(crate) const MINUTE: f64 = 60.0;
+pub(crate) const HOUR: f64 = 60.0 * MINUTE;
+pub(crate) const DAY: f64 = 24.0 * HOUR;
+
+/// The steps between marks on a time axis, in seconds, from one second up to a day.
+const NICE_STEPS: [f64; 18] = [
+    1.0,
+    2.0,
+    5.0,
+    10.0,
+    15.0,
+    30.0,
+    MINUTE,
+    2.0 * MINUTE,
+    5.0 * MINUTE,
+    10.0 * MINUTE,
+    15.0 * MINUTE,
+    30.0 * MINUTE,
+    HOUR,
+    2.0 * HOUR,
+    3.0 * HOUR,
+    6.0 * HOUR,
+    12.0 * HOUR,
+    DAY,
+];
+
+/// The smallest step between marks of at least `min_step` seconds that reads well as a time:
+/// powers of ten below a second, whole seconds, minutes and hours, and powers of ten of days.
+pub(crate) fn nice_time_step(min_step: f64) -> f64 {
+    if min_step < 1.0 {
+        next_power(min_step, 10.0)
+    } else if min_step <= DAY {
+        NICE_STEPS
+            .into_iter()
+            .find(|step| min_step <= *step)
+            .unwrap_or(DAY)
+    } else {
+        DAY * next_power(min_step / DAY, 10.0)
+    }
+}
+
+/// Marks at whole seconds, minutes, hours or days before and after `reference`, for an axis
+/// in seconds that is labeled with [`relative_time_formatter`].
+///
+/// The marks are counted from the reference, so there is always a mark at the reference itself.
+pub fn relative_time_grid_spacer(reference: f64) -> GridSpacer<'static> {
+    Box::new(move |input: GridInput| -> Vec<GridMark> {
+        if input.base_step_size.abs() < f64::EPSILON || !reference.is_finite() {
+            return Vec::new();
+        }
+
+        // Each step is a multiple of the one before, so their marks line up.
+        let smallest = nice_time_step(input.base_step_size);
+        let middle = nice_time_step(5.0 * smallest);
+        let largest = nice_time_step(5.0 * middle);
+
+        let (min, max) = input.bounds;
+        let mut marks = generate_marks(
+            [smallest, middle, largest],
+            (min - reference, max - reference),
+        );
+        for mark in &mut marks {
+            mark.value += reference;
+        }
+        marks
+    })
+}
+
+/// Tick labels for an axis in seconds, relative to `reference`: `"-30 s"`, `"-20 s"`, `"-10 s"`, `"now"`.
+///
+/// The unit is picked from the distance between the marks, so labels become minutes,
+/// hours or days as the user zooms out.
+pub fn relative_time_formatter(
+    reference: f64,
+) -> impl Fn(GridMark, &RangeInclusive<f64>) -> String {
+    move |mark, _range| format_relative_time(mark.value - reference, mark.step_size)
+}
+
+/// Formats a number of seconds in the unit fitting a distance of `step` seconds between labels.
+fn format_relative_time(seconds: f64, step: f64) -> String {
+    // Don't let rounding errors hide the reference.
+    if seconds.abs() < 1e-6 * step {
+        return "now".to_owned();
+    }
+
+    let (unit, suffix) = if DAY <= step {
+        (DAY, "d")
+    } else if HOUR <= step {
+        (HOUR, "h")
+    } else if MINUTE <= step {
+        (MINUTE, "min")
+    } else {
+        (1.0, "s")
+    };
+    let num_decimals = (-(step / unit).log10().floor()).max(0.0) as usize;
+    let value = emath::format_with_decimals_in_range(seconds / unit, 0..=num_decimals);
+    if seconds > 0.0 {
+        format!("+{value} {suffix}")
+    } else {
+        format!("{value} {suffix}")
+    }
+}
+
+#[test]
+fn test_format_relative_time() {
+    assert_eq!(format_relative_time(0.0, 10.0), "now");
+    assert_eq!(format_relative_time(-30.0, 10.0), "-30 s");
+    assert_eq!(format_relative_time(-0.5, 0.1), "-0.5 s");
+    assert_eq!(format_relative_time(-300.0, 60.0), "-5 min");
+    assert_eq!(format_relative_time(-5400.0, 1800.0), "-90 min");
+    assert_eq!(format_relative_time(-7200.0, 3600.0), "-2 h");
+    assert_eq!(format_relative_time(86400.0, 86400.0), "+1 d");
+}
+
+#[test]
+fn test_relative_time_grid_spacer() {
+    let spacer = relative_time_grid_spacer(100.5);
+    let marks = spacer(GridInput {
+        bounds: (60.0, 101.0),
+        base_step_size: 3.0,
+        previous_step_size: None,
+    });
+    assert!(marks.iter().any(|mark| mark.value == 100.5));
+    assert!(marks
+        .iter()
+        .all(|mark| ((mark.value - 100.5) / 5.0).fract() == 0.0));
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use egui::{Context, Id, Vec2b};
+
+use crate::{Measurement, Pin, PlotBounds, PlotMemory, SavedView};
+
+/// The state of a set of plots that the user built up interactively: the visible ranges,
+/// the hidden items, the saved views, the pinned labels and the measurements.
+///
+/// Serialize it to a file to share a review session with other users of the same app,
+/// and [`Self::restore`] it on their side.
+///
+/// The plots are identified by their id, so give them a stable one with [`crate::Plot::id`].
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// # use egui_plot::PlotSession;
+/// let id = egui::Id::new("temperatures");
+/// // Plot::new("temperatures").id(id).show(ui, |plot_ui| …);
+///
+/// let session = PlotSession::capture(&ctx, [id]);
+/// // Write the session to a file, and later read it back:
+/// session.restore(&ctx);
+/// ```
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct PlotSession {
+    plots: ahash::HashMap<Id, PlotState>,
+}
+
+impl PlotSession {
+    /// Collect the state of the plots with the given ids.
+    ///
+    /// Plots that have not been shown yet are left out.
+    pub fn capture(ctx: &Context, plot_ids: impl IntoIterator<Item = Id>) -> Self {
+        let plots = plot_ids
+            .into_iter()
+            .filter_map(|id| Some((id, PlotState::from_memory(&PlotMemory::load(ctx, id)?))))
+            .collect();
+        Self { plots }
+    }
+
+    /// The ids of the plots in this session.
+    pub fn plot_ids(&self) -> impl Iterator<Item = Id> + '_ {
+        self.plots.keys().copied()
+    }
+
+    /// Is the state of any plot in this session?
+    pub fn is_empty(&self) -> bool {
+        self.plots.is_empty()
+    }
+
+    /// Bring the plots back to the state of this session.
+    ///
+    /// Each plot takes on its state the next time it is shown, so this can be called
+    /// before the plots have been shown for the first time.
+    pub fn restore(&self, ctx: &Context) {
+        ctx.data_mut(|data| {
+            #[allow(clippy::iter_over_hash_type)] // The order doesn't matter.
+            for (id, state) in &self.plots {
+                data.insert_temp(pending_id(*id), state.clone());
+            }
+        });
+        ctx.request_repaint();
+    }
+}
+
+/// The state of a single plot in a [`PlotSession`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+struct PlotState {
+    bounds: PlotBounds,
+    auto_bounds: Vec2b,
+
+    #[serde(default)]
+    hidden_items: ahash::HashSet<String>,
+
+    #[serde(default)]
+    saved_views: BTreeMap<String, SavedView>,
+
+    #[serde(default)]
+    pins: Vec<Pin>,
+
+    #[serde(default)]
+    measurements: Vec<Measurement>,
+}
+
+impl PlotState {
+    fn from_memory(mem: &PlotMemory) -> Self {
+        Self {
+            bounds: *mem.bounds(),
+            auto_bounds: mem.auto_bounds,
+            hidden_items: mem.hidden_items.clone(),
+            saved_views: mem.saved_views.clone(),
+            pins: mem.pins.clone(),
+            measurements: mem.measurements.clone(),
+        }
+    }
+
+    fn apply(self, mem: &mut PlotMemory) {
+        mem.view_history.record(*mem.bounds());
+        mem.set_bounds(self.bounds);
+        mem.auto_bounds = self.auto_bounds;
+        mem.hidden_items = self.hidden_items;
+        mem.saved_views = self.saved_views;
+        mem.pins = self.pins;
+        mem.measurements = self.measurements;
+    }
+}
+
+fn pending_id(plot_id: Id) -> Id {
+    plot_id.with("restored_session")
+}
+
+/// Apply the state restored with [`PlotSession::restore`] to the plot, if there is any.
+pub(crate) fn apply_restored(ctx: &Context, plot_id: Id, mem: &mut PlotMemory) {
+    let state = ctx.data_mut(|data| {
+        let id = pending_id(plot_id);
+        let state = data.get_temp::<PlotState>(id);
+        data.remove::<PlotState>(id);
+        state
+    });
+    if let Some(state) = state {
+        state.apply(mem);
+    }
+}
+
+#[test]
+fn test_session_round_trip() {
+    use crate::{Line, Plot, PlotPoint};
+
+    let ctx = Context::default();
+    let plot_id = Id::new("plot");
+    let run = || {
+        let _output: egui::FullOutput = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                Plot::new("plot").id(plot_id).show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(vec![[0.0, 0.0], [1.0, 1.0]]));
+                });
+            });
+        });
+    };
+    run();
+
+    let measurement = Measurement {
+        start: PlotPoint::new(0.0, 0.0),
+        end: PlotPoint::new(1.0, 2.0),
+    };
+    let mut mem = PlotMemory::load(&ctx, plot_id).expect("plot memory");
+    mem.measurements.push(measurement);
+    mem.hidden_items.insert("hidden".to_owned());
+    mem.store(&ctx, plot_id);
+
+    let session = PlotSession::capture(&ctx, [plot_id, Id::new("not shown")]);
+    assert_eq!(session.plot_ids().collect::<Vec<_>>(), vec![plot_id]);
+
+    let mut mem = PlotMemory::load(&ctx, plot_id).expect("plot memory");
+    mem.clear_measurements();
+    mem.hidden_items.clear();
+    mem.store(&ctx, plot_id);
+
+    session.restore(&ctx);
+    run();
+    let mem = PlotMemory::load(&ctx, plot_id).expect("plot memory");
+    assert_eq!(mem.measurements(), &[measurement]);
+    assert!(mem.hidden_items.contains("hidden"));
+}
@@ -0,0 +1,217 @@
+use egui::{emath::NumExt as _, Align2, Id, InnerResponse, Ui};
+
+use crate::{
+    navigation::closest_in_x, MarkerShape, Plot, PlotGeometry, PlotPoint, PlotResponse, PlotUi,
+    Points, Text,
+};
+
+/// Plots stacked on top of each other that share their x-axis,
+/// like a price chart above the traded volume and some indicators.
+///
+/// Only the bottom panel shows the x-axis, and the y-axes of all panels have the same width
+/// so that the panels line up. Hovering a panel shows the cursor in all of them,
+/// and marks the values of the other panels at the hovered x-value.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Bar, BarChart, Line, PlotPoints, StackedPanels};
+///
+/// StackedPanels::new("chart").show(ui, |panels| {
+///     panels.panel(200.0, |plot_ui| {
+///         plot_ui.line(Line::new(PlotPoints::from_explicit_callback(f64::sin, 0.0..=10.0, 100)));
+///     });
+///     panels.panel(80.0, |plot_ui| {
+///         let bars = (0..10).map(|i| Bar::new(i as f64, (i % 3) as f64)).collect();
+///         plot_ui.bar_chart(BarChart::new(bars));
+///     });
+/// });
+/// # });
+/// ```
+pub struct StackedPanels<'a> {
+    id: Id,
+    sync_hover: bool,
+    style: Option<Box<dyn Fn(Plot<'a>) -> Plot<'a> + 'a>>,
+}
+
+impl<'a> StackedPanels<'a> {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            sync_hover: true,
+            style: None,
+        }
+    }
+
+    /// Mark the values of the other panels at the x-value hovered in one of them. Default: `true`.
+    #[inline]
+    pub fn sync_hover(mut self, sync_hover: bool) -> Self {
+        self.sync_hover = sync_hover;
+        self
+    }
+
+    /// Settings applied to every panel, like the grid or the y-axis formatter.
+    #[inline]
+    pub fn style(mut self, style: impl Fn(Plot<'a>) -> Plot<'a> + 'a) -> Self {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    /// Show the panels, added from top to bottom with [`StackedPanelsUi::panel`].
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut StackedPanelsUi<'_, 'a>) -> R,
+    ) -> InnerResponse<R> {
+        let state_id = self.id.with("state");
+        let state: StackedState = ui.data_mut(|d| d.get_temp(state_id)).unwrap_or_default();
+
+        let response = ui.vertical(|ui| {
+            let mut panels = StackedPanelsUi {
+                stacked: &self,
+                ui,
+                index: 0,
+                last: state.count.saturating_sub(1),
+                y_axis_width: state.y_axis_width,
+                next_y_axis_width: 0.0,
+                hovered_x: state.hovered_x,
+                next_hovered_x: None,
+            };
+            let inner = add_contents(&mut panels);
+
+            let new_state = StackedState {
+                count: panels.index,
+                y_axis_width: panels.next_y_axis_width,
+                hovered_x: panels.next_hovered_x,
+            };
+            (inner, new_state)
+        });
+        let InnerResponse {
+            inner: (inner, new_state),
+            response,
+        } = response;
+
+        if new_state.count != state.count || new_state.y_axis_width != state.y_axis_width {
+            // The axes were laid out for the previous panels.
+            ui.ctx().request_repaint();
+        }
+        ui.data_mut(|d| d.insert_temp(state_id, new_state));
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// What the panels of a [`StackedPanels`] need from the previous frame.
+#[derive(Clone, Copy, Default)]
+struct StackedState {
+    count: usize,
+    y_axis_width: f32,
+    hovered_x: Option<f64>,
+}
+
+/// Adds panels to [`StackedPanels`], see [`StackedPanels::show`].
+pub struct StackedPanelsUi<'s, 'a> {
+    stacked: &'s StackedPanels<'a>,
+    ui: &'s mut Ui,
+    index: usize,
+
+    /// The index of the bottom panel in the previous frame.
+    last: usize,
+
+    y_axis_width: f32,
+    next_y_axis_width: f32,
+    hovered_x: Option<f64>,
+    next_hovered_x: Option<f64>,
+}
+
+impl<'a> StackedPanelsUi<'_, 'a> {
+    /// Add the next panel below the previous ones, with the given height.
+    pub fn panel<R>(
+        &mut self,
+        height: f32,
+        build_fn: impl FnOnce(&mut PlotUi) -> R,
+    ) -> PlotResponse<R> {
+        self.panel_with(height, |plot| plot, build_fn)
+    }
+
+    /// Like [`Self::panel`], with extra settings for just this panel.
+    pub fn panel_with<R>(
+        &mut self,
+        height: f32,
+        customize: impl FnOnce(Plot<'a>) -> Plot<'a>,
+        build_fn: impl FnOnce(&mut PlotUi) -> R,
+    ) -> PlotResponse<R> {
+        let stacked = self.stacked;
+        let mut plot = Plot::new(stacked.id.with(self.index))
+            .height(height)
+            .link_axis(stacked.id.with("x"), [true, false])
+            .link_cursor(stacked.id.with("cursor"), [true, false])
+            .show_axes([self.index == self.last, true])
+            .y_axis_min_width(self.y_axis_width);
+        if let Some(style) = &stacked.style {
+            plot = style(plot);
+        }
+        let plot = customize(plot);
+
+        let hovered_x = self.hovered_x.filter(|_| stacked.sync_hover);
+        let response = plot.show(self.ui, |plot_ui| {
+            let inner = build_fn(plot_ui);
+            if let Some(x) = hovered_x {
+                if !plot_ui.response().hovered() {
+                    mark_values_at(plot_ui, x);
+                }
+            }
+            inner
+        });
+
+        let y_axis_width = response.response.rect.width() - response.transform.frame().width();
+        self.next_y_axis_width = self.next_y_axis_width.max(y_axis_width);
+        if let Some(pointer) = response.response.hover_pos() {
+            let x = response.transform.value_from_position(pointer).x;
+            self.hovered_x = Some(x);
+            self.next_hovered_x = Some(x);
+        }
+        self.index += 1;
+        response
+    }
+}
+
+/// Adds a marker with the value of each item at the given x-value.
+fn mark_values_at(plot_ui: &mut PlotUi, x: f64) {
+    let scale = plot_ui.transform().dvalue_dpos();
+    let decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+
+    let values: Vec<_> = plot_ui
+        .items
+        .iter()
+        .filter(|item| item.allow_hover())
+        .filter_map(|item| match item.geometry() {
+            PlotGeometry::Points(points) if !points.is_empty() => {
+                let point = points[closest_in_x(points, x)];
+                point
+                    .is_finite()
+                    .then(|| (item.name().to_owned(), item.color(), point))
+            }
+            PlotGeometry::Points(_) | PlotGeometry::None | PlotGeometry::Rects => None,
+        })
+        .collect();
+
+    for (name, color, point) in values {
+        let text = if name.is_empty() {
+            format!("{:.*}", decimals, point.y)
+        } else {
+            format!("{name}: {:.*}", decimals, point.y)
+        };
+        plot_ui.points(
+            Points::new(vec![[point.x, point.y]])
+                .shape(MarkerShape::Circle)
+                .radius(3.0)
+                .color(color)
+                .allow_hover(false),
+        );
+        plot_ui.text(
+            Text::new(PlotPoint::new(point.x, point.y), text)
+                .anchor(Align2::LEFT_BOTTOM)
+                .color(color)
+                .allow_hover(false),
+        );
+    }
+}
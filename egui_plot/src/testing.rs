@@ -0,0 +1,297 @@
+//! Helpers to simulate user interaction with a plot in tests.
+//!
+//! Each interaction helper returns the [`Event`]s of an interaction, one list per frame,
+//! to be added to the input of a test harness before running the frame.
+//! With `egui_kittest` for instance:
+//!
+//! ```ignore
+//! let transform = egui_plot::testing::transform(&harness.ctx, plot_id).unwrap();
+//! for events in egui_plot::testing::drag(&transform, from, to) {
+//!     harness.input_mut().events.extend(events);
+//!     harness.run();
+//! }
+//! let bounds = egui_plot::testing::bounds(&harness.ctx, plot_id);
+//! ```
+//!
+//! Positions are given in plot coordinates, and converted to the screen with the transform
+//! of the plot from the last frame, see [`transform`].
+//! The results can be read back with [`bounds`], [`hidden_items`] and [`hovered_item`].
+
+use egui::{Context, Event, Id, Modifiers, MouseWheelUnit, PointerButton, Pos2, Vec2};
+
+use crate::{PlotBounds, PlotMemory, PlotPoint, PlotResponse, PlotTransform};
+
+/// The transform of the plot with the given id from the last frame,
+/// to pass to the interaction helpers.
+///
+/// The id is the one given to [`crate::Plot::id`], or derived from [`crate::Plot::new`].
+pub fn transform(ctx: &Context, plot_id: Id) -> Option<PlotTransform> {
+    PlotMemory::load(ctx, plot_id).map(|memory| memory.transform())
+}
+
+/// The bounds the plot with the given id showed the last frame.
+pub fn bounds(ctx: &Context, plot_id: Id) -> Option<PlotBounds> {
+    PlotMemory::load(ctx, plot_id).map(|memory| *memory.bounds())
+}
+
+/// The names of the items hidden with the legend of the plot with the given id.
+pub fn hidden_items(ctx: &Context, plot_id: Id) -> ahash::HashSet<String> {
+    PlotMemory::load(ctx, plot_id).map_or_else(Default::default, |memory| memory.hidden_items)
+}
+
+/// The id of the item whose hover label the plot shows, e.g. after [`hover`].
+pub fn hovered_item<R>(response: &PlotResponse<R>) -> Option<Id> {
+    response.hovered_plot_item
+}
+
+/// Move the pointer to the given point, e.g. to show its hover label.
+pub fn hover(transform: &PlotTransform, point: PlotPoint) -> Vec<Vec<Event>> {
+    vec![vec![Event::PointerMoved(
+        transform.position_from_point(&point),
+    )]]
+}
+
+/// Click the primary button at the given point.
+pub fn click(transform: &PlotTransform, point: PlotPoint) -> Vec<Vec<Event>> {
+    click_at(transform.position_from_point(&point))
+}
+
+/// Double-click at the given point, which resets the view by default.
+pub fn double_click(transform: &PlotTransform, point: PlotPoint) -> Vec<Vec<Event>> {
+    let mut frames = click(transform, point);
+    frames.extend(click(transform, point));
+    frames
+}
+
+/// Drag with the primary button from one point to another, which pans the plot by default.
+pub fn drag(transform: &PlotTransform, from: PlotPoint, to: PlotPoint) -> Vec<Vec<Event>> {
+    drag_with(transform, PointerButton::Primary, from, to)
+}
+
+/// Drag with the given button from one point to another,
+/// e.g. with [`PointerButton::Secondary`] for a boxed zoom.
+pub fn drag_with(
+    transform: &PlotTransform,
+    button: PointerButton,
+    from: PlotPoint,
+    to: PlotPoint,
+) -> Vec<Vec<Event>> {
+    let from = transform.position_from_point(&from);
+    let to = transform.position_from_point(&to);
+    vec![
+        vec![Event::PointerMoved(from), button_event(from, button, true)],
+        vec![Event::PointerMoved(from + 0.5 * (to - from))],
+        vec![Event::PointerMoved(to)],
+        vec![button_event(to, button, false)],
+    ]
+}
+
+/// Click the legend entry of the item with the given name, which shows or hides the item.
+///
+/// Returns `None` if the plot with the given id didn't show the entry the last frame.
+pub fn click_legend_entry(ctx: &Context, plot_id: Id, name: &str) -> Option<Vec<Vec<Event>>> {
+    let rect = PlotMemory::load(ctx, plot_id)?.legend_entry_rect(name)?;
+    Some(click_at(rect.center()))
+}
+
+/// Zoom around the given point, like with a pinch gesture or Ctrl+scroll.
+///
+/// A factor above one zooms in.
+pub fn zoom(transform: &PlotTransform, at: PlotPoint, factor: f32) -> Vec<Vec<Event>> {
+    vec![vec![
+        Event::PointerMoved(transform.position_from_point(&at)),
+        Event::Zoom(factor),
+    ]]
+}
+
+/// Scroll by the given amount of ui points while hovering the given point.
+pub fn scroll(transform: &PlotTransform, at: PlotPoint, delta: Vec2) -> Vec<Vec<Event>> {
+    vec![vec![
+        Event::PointerMoved(transform.position_from_point(&at)),
+        Event::MouseWheel {
+            unit: MouseWheelUnit::Point,
+            delta,
+            modifiers: Modifiers::NONE,
+        },
+    ]]
+}
+
+fn click_at(pos: Pos2) -> Vec<Vec<Event>> {
+    vec![
+        vec![
+            Event::PointerMoved(pos),
+            button_event(pos, PointerButton::Primary, true),
+        ],
+        vec![button_event(pos, PointerButton::Primary, false)],
+    ]
+}
+
+fn button_event(pos: Pos2, button: PointerButton, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+#[cfg(test)]
+fn run_plot(ctx: &Context, events: Vec<Event>) -> PlotResponse<()> {
+    run_plot_with(ctx, events, |plot| plot)
+}
+
+#[cfg(test)]
+fn run_plot_with(
+    ctx: &Context,
+    events: Vec<Event>,
+    configure: impl Fn(crate::Plot<'_>) -> crate::Plot<'_>,
+) -> PlotResponse<()> {
+    run_plot_showing(ctx, events, configure, |_| {})
+}
+
+/// Runs a frame of a plot of a diagonal line, with the extra items added by `add_items`.
+#[cfg(test)]
+fn run_plot_showing(
+    ctx: &Context,
+    events: Vec<Event>,
+    configure: impl Fn(crate::Plot<'_>) -> crate::Plot<'_>,
+    add_items: impl Fn(&mut crate::PlotUi),
+) -> PlotResponse<()> {
+    use crate::{Legend, Line, Plot};
+
+    let input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            Pos2::ZERO,
+            egui::vec2(400.0, 300.0),
+        )),
+        events,
+        ..Default::default()
+    };
+    let mut response = None;
+    let _output: egui::FullOutput = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            response = Some(
+                configure(Plot::new("plot"))
+                    .id(Id::new("plot"))
+                    .legend(Legend::default())
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(
+                            Line::new(vec![[0.0, 0.0], [0.5, 0.5], [1.0, 1.0]])
+                                .name("diagonal")
+                                .id(Id::new("diagonal")),
+                        );
+                        add_items(plot_ui);
+                    }),
+            );
+        });
+    });
+    response.expect("the plot is shown")
+}
+
+#[test]
+fn test_drag_pans() {
+    let ctx = Context::default();
+    let plot_id = Id::new("plot");
+    run_plot(&ctx, Vec::new());
+
+    let transform = transform(&ctx, plot_id).expect("plot memory");
+    let before = *transform.bounds();
+    for events in drag(
+        &transform,
+        PlotPoint::new(0.5, 0.5),
+        PlotPoint::new(0.25, 0.5),
+    ) {
+        run_plot(&ctx, events);
+    }
+    let after = bounds(&ctx, plot_id).expect("plot memory");
+
+    // Dragging to the left moves the view to the right.
+    assert!(after.min()[0] > before.min()[0]);
+    assert!((after.min()[1] - before.min()[1]).abs() < 1e-9);
+}
+
+#[test]
+fn test_hover_and_legend_click() {
+    let ctx = Context::default();
+    let plot_id = Id::new("plot");
+    run_plot(&ctx, Vec::new());
+
+    let transform = transform(&ctx, plot_id).expect("plot memory");
+    let [events] = &hover(&transform, PlotPoint::new(0.5, 0.5))[..] else {
+        unreachable!("hovering takes one frame");
+    };
+    let response = run_plot(&ctx, events.clone());
+    assert_eq!(hovered_item(&response), Some(Id::new("diagonal")));
+
+    assert!(click_legend_entry(&ctx, plot_id, "unknown").is_none());
+    for events in click_legend_entry(&ctx, plot_id, "diagonal").expect("legend entry") {
+        run_plot(&ctx, events);
+    }
+    assert!(hidden_items(&ctx, plot_id).contains("diagonal"));
+
+    for events in click_legend_entry(&ctx, plot_id, "diagonal").expect("legend entry") {
+        run_plot(&ctx, events);
+    }
+    assert!(hidden_items(&ctx, plot_id).is_empty());
+}
+
+#[test]
+fn test_zoom_without_capturing_scroll() {
+    use crate::CaptureScroll;
+
+    let ctx = Context::default();
+    let plot_id = Id::new("plot");
+    let run = |events| {
+        run_plot_with(&ctx, events, |plot| {
+            plot.capture_scroll(CaptureScroll::Never)
+        })
+    };
+    run(Vec::new());
+
+    let transform = transform(&ctx, plot_id).expect("plot memory");
+    let before = *transform.bounds();
+    for events in scroll(&transform, PlotPoint::new(0.5, 0.5), Vec2::new(0.0, 50.0)) {
+        run(events);
+    }
+    assert_eq!(bounds(&ctx, plot_id), Some(before));
+
+    // Pinching still zooms, since it doesn't scroll the page.
+    for events in zoom(&transform, PlotPoint::new(0.5, 0.5), 2.0) {
+        run(events);
+    }
+    let after = bounds(&ctx, plot_id).expect("plot memory");
+    assert!(after.width() < before.width());
+}
+
+#[test]
+fn test_decorations_opt_into_legend_and_bounds() {
+    use crate::HLine;
+
+    let ctx = Context::default();
+    let plot_id = Id::new("plot");
+    let run = |show_in_legend: bool, include_in_auto_bounds: bool| {
+        run_plot_showing(
+            &ctx,
+            Vec::new(),
+            |plot| plot,
+            |plot_ui| {
+                plot_ui.hline(
+                    HLine::new(5.0)
+                        .name("threshold")
+                        .show_in_legend(show_in_legend)
+                        .include_in_auto_bounds(include_in_auto_bounds),
+                );
+            },
+        )
+    };
+
+    // By default, a decoration is neither in the legend nor in the automatic bounds.
+    run(false, false);
+    assert!(bounds(&ctx, plot_id).expect("plot memory").max()[1] < 5.0);
+    assert!(click_legend_entry(&ctx, plot_id, "threshold").is_none());
+
+    run(true, true);
+    run(true, true);
+    assert!(bounds(&ctx, plot_id).expect("plot memory").max()[1] >= 5.0);
+    assert!(click_legend_entry(&ctx, plot_id, "threshold").is_some());
+}
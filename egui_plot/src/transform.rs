@@ -1,10 +1,28 @@
 use std::ops::RangeInclusive;
 
-use egui::{pos2, remap, Pos2, Rect, Vec2, Vec2b};
+use emath::{pos2, remap, Pos2, Rect, Vec2, Vec2b};
 
-use crate::Axis;
+use crate::data::PlotPoint;
 
-use super::PlotPoint;
+/// X or Y axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Horizontal X-Axis
+    X = 0,
+
+    /// Vertical Y-axis
+    Y = 1,
+}
+
+impl From<Axis> for usize {
+    #[inline]
+    fn from(value: Axis) -> Self {
+        match value {
+            Axis::X => 0,
+            Axis::Y => 1,
+        }
+    }
+}
 
 /// 2D bounding box of f64 precision.
 ///
@@ -104,18 +122,22 @@ impl PlotBounds {
         self.extend_with_y(value.y);
     }
 
-    /// Expand to include the given x coordinate
+    /// Expand to include the given x coordinate, unless it is NaN or infinite.
     #[inline]
     pub fn extend_with_x(&mut self, x: f64) {
-        self.min[0] = self.min[0].min(x);
-        self.max[0] = self.max[0].max(x);
+        if x.is_finite() {
+            self.min[0] = self.min[0].min(x);
+            self.max[0] = self.max[0].max(x);
+        }
     }
 
-    /// Expand to include the given y coordinate
+    /// Expand to include the given y coordinate, unless it is NaN or infinite.
     #[inline]
     pub fn extend_with_y(&mut self, y: f64) {
-        self.min[1] = self.min[1].min(y);
-        self.max[1] = self.max[1].max(y);
+        if y.is_finite() {
+            self.min[1] = self.min[1].min(y);
+            self.max[1] = self.max[1].max(y);
+        }
     }
 
     #[inline]
@@ -227,6 +249,38 @@ impl PlotBounds {
         self.max[1] = center.y + (self.max[1] - center.y) / (zoom_factor.y as f64);
     }
 
+    /// Zoom each axis around its center, so that its size is within `min_size..=max_size`.
+    #[inline]
+    pub fn clamp_size(&mut self, min_size: [f64; 2], max_size: [f64; 2]) {
+        for d in 0..2 {
+            let size = self.max[d] - self.min[d];
+            let clamped = size.max(min_size[d]).min(max_size[d]);
+            if clamped != size {
+                let center = (self.min[d] + self.max[d]) / 2.0;
+                self.min[d] = center - clamped / 2.0;
+                self.max[d] = center + clamped / 2.0;
+            }
+        }
+    }
+
+    /// Move these bounds so that they lie within `limit`,
+    /// shrinking them only if they are larger than `limit`.
+    #[inline]
+    pub fn clamp_to(&mut self, limit: &Self) {
+        for d in 0..2 {
+            if self.max[d] - self.min[d] > limit.max[d] - limit.min[d] {
+                self.min[d] = limit.min[d];
+                self.max[d] = limit.max[d];
+            } else if self.min[d] < limit.min[d] {
+                self.max[d] += limit.min[d] - self.min[d];
+                self.min[d] = limit.min[d];
+            } else if self.max[d] > limit.max[d] {
+                self.min[d] -= self.max[d] - limit.max[d];
+                self.max[d] = limit.max[d];
+            }
+        }
+    }
+
     #[inline]
     pub fn add_relative_margin_x(&mut self, margin_fraction: Vec2) {
         let width = self.width().max(0.0);
@@ -264,6 +318,193 @@ impl PlotBounds {
     }
 }
 
+/// How the values along an axis are spaced, see [`crate::Plot::x_axis_scale`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AxisScale {
+    /// Equal steps in value take equal space.
+    #[default]
+    Linear,
+
+    /// Each power of ten takes equal space.
+    ///
+    /// Values at or below the floor, like zero or negative values, are shown at the floor,
+    /// so that bars and filled areas starting at zero start at the floor instead.
+    Log10 { floor: f64 },
+
+    /// Values within the threshold from zero are spaced linearly,
+    /// and beyond it each power of ten takes as much space as the linear part on either side.
+    ///
+    /// This suits data spanning many magnitudes of both positive and negative values.
+    SymLog { threshold: f64 },
+}
+
+impl AxisScale {
+    /// A base 10 logarithmic scale that shows values down to the given positive floor.
+    #[inline]
+    pub fn log10(floor: f64) -> Self {
+        Self::Log10 {
+            floor: if floor > 0.0 {
+                floor
+            } else {
+                f64::MIN_POSITIVE
+            },
+        }
+    }
+
+    /// A symmetric logarithmic scale that is linear between `-threshold` and `threshold`.
+    #[inline]
+    pub fn symlog(threshold: f64) -> Self {
+        Self::SymLog {
+            threshold: if threshold > 0.0 { threshold } else { 1.0 },
+        }
+    }
+
+    /// The value in the space where the axis is linear.
+    #[inline]
+    pub fn apply(&self, value: f64) -> f64 {
+        match *self {
+            Self::Linear => value,
+            Self::Log10 { floor } => value.max(floor).log10(),
+            Self::SymLog { threshold } => {
+                let scaled = value.abs() / threshold;
+                if scaled <= 1.0 {
+                    value / threshold
+                } else {
+                    value.signum() * (1.0 + scaled.log10())
+                }
+            }
+        }
+    }
+
+    /// The value for one in the space where the axis is linear, see [`Self::apply`].
+    #[inline]
+    pub fn invert(&self, scaled: f64) -> f64 {
+        match self {
+            Self::Linear => scaled,
+            Self::Log10 { .. } => 10.0_f64.powf(scaled),
+            Self::SymLog { threshold } => {
+                if scaled.abs() <= 1.0 {
+                    scaled * threshold
+                } else {
+                    scaled.signum() * threshold * 10.0_f64.powf(scaled.abs() - 1.0)
+                }
+            }
+        }
+    }
+
+    /// Narrows the range to values that can be shown with this scale.
+    fn fit(&self, min: &mut f64, max: &mut f64) {
+        if let Self::Log10 { floor } = *self {
+            *min = min.max(floor);
+            if *max <= *min {
+                *max = *min * 10.0;
+            }
+        }
+    }
+}
+
+/// The bounds in the space where the axes are linear, see [`AxisScale::apply`].
+pub(crate) fn scale_bounds(scales: [AxisScale; 2], bounds: &PlotBounds) -> PlotBounds {
+    let mut scaled = *bounds;
+    for (d, scale) in scales.iter().enumerate() {
+        scaled.min[d] = scale.apply(bounds.min[d]);
+        scaled.max[d] = scale.apply(bounds.max[d]);
+    }
+    scaled
+}
+
+/// The opposite of [`scale_bounds`].
+pub(crate) fn unscale_bounds(scales: [AxisScale; 2], scaled: &PlotBounds) -> PlotBounds {
+    let mut bounds = *scaled;
+    for (d, scale) in scales.iter().enumerate() {
+        bounds.min[d] = scale.invert(scaled.min[d]);
+        bounds.max[d] = scale.invert(scaled.max[d]);
+    }
+    bounds
+}
+
+/// Extra space around the data when the bounds are determined automatically.
+///
+/// See [`crate::Plot::bounds_margin`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoundsMargin {
+    /// A fraction of the data range on each side.
+    Fraction {
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+    },
+
+    /// A fixed distance in ui points on each side.
+    Points {
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+    },
+}
+
+impl BoundsMargin {
+    /// The same fraction of the data range on all sides.
+    #[inline]
+    pub fn fraction(fraction: f64) -> Self {
+        Self::Fraction {
+            left: fraction,
+            right: fraction,
+            bottom: fraction,
+            top: fraction,
+        }
+    }
+
+    /// The same distance in ui points on all sides.
+    #[inline]
+    pub fn points(points: f32) -> Self {
+        Self::Points {
+            left: points,
+            right: points,
+            bottom: points,
+            top: points,
+        }
+    }
+
+    /// Add the margin to the given axis of the bounds, which are shown in a frame of the given size.
+    pub(crate) fn apply(&self, bounds: &mut PlotBounds, axis: Axis, frame_size: Vec2) {
+        let d = usize::from(axis);
+        let size = bounds.max[d] - bounds.min[d];
+        if !size.is_finite() {
+            return;
+        }
+        let (low, high) = match (*self, axis) {
+            (Self::Fraction { left, right, .. }, Axis::X) => (left * size, right * size),
+            (Self::Fraction { bottom, top, .. }, Axis::Y) => (bottom * size, top * size),
+            (Self::Points { left, right, .. }, Axis::X) => {
+                points_to_values(size, frame_size.x, left, right)
+            }
+            (Self::Points { bottom, top, .. }, Axis::Y) => {
+                points_to_values(size, frame_size.y, bottom, top)
+            }
+        };
+        bounds.min[d] -= low;
+        bounds.max[d] += high;
+        bounds.clamp_to_finite();
+    }
+}
+
+/// Converts margins in ui points to plot values, such that `size` fills what is left of the frame.
+fn points_to_values(size: f64, frame_size: f32, low: f32, high: f32) -> (f64, f64) {
+    let available = frame_size - low - high;
+    if available <= 0.0 || size <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let values_per_point = size / available as f64;
+    (
+        low as f64 * values_per_point,
+        high as f64 * values_per_point,
+    )
+}
+
 /// Contains the screen rectangle and the plot bounds and provides methods to transform between them.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug)]
@@ -276,6 +517,10 @@ pub struct PlotTransform {
 
     /// Whether to always center the x-range or y-range of the bounds.
     centered: Vec2b,
+
+    /// How the values are spaced along each axis.
+    #[cfg_attr(feature = "serde", serde(default))]
+    scales: [AxisScale; 2],
 }
 
 impl PlotTransform {
@@ -285,16 +530,64 @@ impl PlotTransform {
             "Bad plot frame: {frame:?}"
         );
         let center_axis = center_axis.into();
+        let mut new_bounds = Self::sanitize(bounds);
+
+        // Scale axes so that the origin is in the center.
+        if center_axis.x {
+            new_bounds.make_x_symmetrical();
+        };
+        if center_axis.y {
+            new_bounds.make_y_symmetrical();
+        };
+
+        debug_assert!(
+            new_bounds.is_valid(),
+            "Bad final plot bounds: {new_bounds:?}"
+        );
+
+        Self {
+            frame,
+            bounds: new_bounds,
+            centered: center_axis,
+            scales: Default::default(),
+        }
+    }
+
+    /// How the values are spaced along the x- and y-axis.
+    #[inline]
+    pub fn scales(&self) -> [AxisScale; 2] {
+        self.scales
+    }
+
+    /// Set how the values are spaced along the axes, narrowing the bounds to what can be shown.
+    pub fn set_scales(&mut self, scales: [AxisScale; 2]) {
+        self.scales = scales;
+        self.fit_to_scales();
+    }
+
+    fn fit_to_scales(&mut self) {
+        for d in 0..2 {
+            self.scales[d].fit(&mut self.bounds.min[d], &mut self.bounds.max[d]);
+        }
+    }
+
+    /// Turns any bounds into bounds that can be shown.
+    ///
+    /// Reversed ranges are flipped.
+    /// When a given bound axis is "thin" (e.g. width or height is 0) but finite, we center the
+    /// bounds around that value. If the other axis is "fat", we reuse its extent for the thin
+    /// axis, and default to +/- 1.0 otherwise. Axes that are not finite become +/- 1.0.
+    fn sanitize(mut bounds: PlotBounds) -> PlotBounds {
+        for d in 0..2 {
+            if bounds.min[d] > bounds.max[d] {
+                std::mem::swap(&mut bounds.min[d], &mut bounds.max[d]);
+            }
+        }
 
         // Since the current Y bounds an affect the final X bounds and vice versa, we need to keep
         // the original version of the `bounds` before we start modifying it.
         let mut new_bounds = bounds;
 
-        // Sanitize bounds.
-        //
-        // When a given bound axis is "thin" (e.g. width or height is 0) but finite, we center the
-        // bounds around that value. If the other axis is "fat", we reuse its extent for the thin
-        // axis, and default to +/- 1.0 otherwise.
         if !bounds.is_finite_x() {
             new_bounds.set_x(&PlotBounds::new_symmetrical(1.0));
         } else if bounds.width() <= 0.0 {
@@ -321,24 +614,7 @@ impl PlotTransform {
             );
         };
 
-        // Scale axes so that the origin is in the center.
-        if center_axis.x {
-            new_bounds.make_x_symmetrical();
-        };
-        if center_axis.y {
-            new_bounds.make_y_symmetrical();
-        };
-
-        debug_assert!(
-            new_bounds.is_valid(),
-            "Bad final plot bounds: {new_bounds:?}"
-        );
-
-        Self {
-            frame,
-            bounds: new_bounds,
-            centered: center_axis,
-        }
+        new_bounds
     }
 
     /// ui-space rectangle.
@@ -353,9 +629,28 @@ impl PlotTransform {
         &self.bounds
     }
 
+    /// Set the plot-space bounds. Bounds that can't be shown are fixed like in [`Self::new`].
     #[inline]
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
-        self.bounds = bounds;
+        self.bounds = Self::sanitize(bounds);
+        self.fit_to_scales();
+    }
+
+    /// The bounds in the space where the axes are linear, see [`AxisScale::apply`].
+    #[inline]
+    pub fn scaled_bounds(&self) -> PlotBounds {
+        scale_bounds(self.scales, &self.bounds)
+    }
+
+    /// The bounds with a margin of their size on each side.
+    ///
+    /// Lines are clamped to these before projecting them, so that points far outside of the view
+    /// don't overflow the screen coordinates.
+    pub(crate) fn clip_bounds(&self) -> PlotBounds {
+        let mut scaled = self.scaled_bounds();
+        scaled.expand_x(scaled.width());
+        scaled.expand_y(scaled.height());
+        unscale_bounds(self.scales, &scaled)
     }
 
     pub fn translate_bounds(&mut self, mut delta_pos: (f64, f64)) {
@@ -367,15 +662,22 @@ impl PlotTransform {
         }
         delta_pos.0 *= self.dvalue_dpos()[0];
         delta_pos.1 *= self.dvalue_dpos()[1];
-        self.bounds.translate((delta_pos.0, delta_pos.1));
+        let mut scaled = self.scaled_bounds();
+        scaled.translate((delta_pos.0, delta_pos.1));
+        self.bounds = unscale_bounds(self.scales, &scaled);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
+        let center = PlotPoint::new(
+            self.scales[0].apply(center.x),
+            self.scales[1].apply(center.y),
+        );
 
-        let mut new_bounds = self.bounds;
+        let mut new_bounds = self.scaled_bounds();
         new_bounds.zoom(zoom_factor, center);
+        let new_bounds = unscale_bounds(self.scales, &new_bounds);
 
         if new_bounds.is_valid() {
             self.bounds = new_bounds;
@@ -383,17 +685,19 @@ impl PlotTransform {
     }
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
+        let scale = self.scales[0];
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
+            scale.apply(value),
+            scale.apply(self.bounds.min[0])..=scale.apply(self.bounds.max[0]),
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         ) as f32
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let scale = self.scales[1];
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
+            scale.apply(value),
+            scale.apply(self.bounds.min[1])..=scale.apply(self.bounds.max[1]),
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         ) as f32
     }
@@ -406,19 +710,52 @@ impl PlotTransform {
         )
     }
 
+    /// Screen/ui positions of many points on the plot, appended to `out`.
+    ///
+    /// This gives the same as [`Self::position_from_point`] for each point, but works out
+    /// the mapping only once, so that the loop over the points can be vectorized.
+    pub fn positions_from_points(&self, points: &[PlotPoint], out: &mut Vec<Pos2>) {
+        let scaled = self.scaled_bounds();
+        let [x_factor, y_factor] = [
+            (self.frame.right() - self.frame.left()) as f64 / (scaled.max[0] - scaled.min[0]),
+            (self.frame.top() - self.frame.bottom()) as f64 / (scaled.max[1] - scaled.min[1]),
+        ];
+        let (x_start, y_start) = (self.frame.left() as f64, self.frame.bottom() as f64);
+        let [x_min, y_min] = scaled.min;
+
+        out.reserve(points.len());
+        if self.scales == [AxisScale::Linear; 2] {
+            out.extend(points.iter().map(|point| {
+                pos2(
+                    ((point.x - x_min) * x_factor + x_start) as f32,
+                    ((point.y - y_min) * y_factor + y_start) as f32,
+                )
+            }));
+        } else {
+            let [x_scale, y_scale] = self.scales;
+            out.extend(points.iter().map(|point| {
+                pos2(
+                    ((x_scale.apply(point.x) - x_min) * x_factor + x_start) as f32,
+                    ((y_scale.apply(point.y) - y_min) * y_factor + y_start) as f32,
+                )
+            }));
+        }
+    }
+
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let scaled = self.scaled_bounds();
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.range_x(),
+            scaled.range_x(),
         );
         let y = remap(
             pos.y as f64,
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.range_y(),
+            scaled.range_y(),
         );
-        PlotPoint::new(x, y)
+        PlotPoint::new(self.scales[0].invert(x), self.scales[1].invert(y))
     }
 
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
@@ -437,12 +774,12 @@ impl PlotTransform {
 
     /// delta position / delta value = how many ui points per step in the X axis in "plot space"
     pub fn dpos_dvalue_x(&self) -> f64 {
-        self.frame.width() as f64 / self.bounds.width()
+        self.frame.width() as f64 / self.scaled_bounds().width()
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis in "plot space"
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height() // negated y axis!
+        -self.frame.height() as f64 / self.scaled_bounds().height() // negated y axis!
     }
 
     /// delta position / delta value = how many ui points per step in "plot space"
@@ -507,3 +844,84 @@ impl PlotTransform {
         }
     }
 }
+
+#[test]
+fn test_pathological_bounds() {
+    let mut bounds = PlotBounds::NOTHING;
+    for value in [f64::NAN, f64::INFINITY, 1.0, f64::NEG_INFINITY, 2.0] {
+        bounds.extend_with(&PlotPoint::new(value, value));
+    }
+    assert_eq!(bounds, PlotBounds::from_min_max([1.0, 1.0], [2.0, 2.0]));
+
+    let frame = Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0));
+
+    // Reversed ranges are flipped.
+    let reversed = PlotBounds::from_min_max([2.0, 1.0], [1.0, 2.0]);
+    let transform = PlotTransform::new(frame, reversed, false);
+    assert_eq!(
+        *transform.bounds(),
+        PlotBounds::from_min_max([1.0, 1.0], [2.0, 2.0])
+    );
+
+    // Empty, zero-size and infinite bounds become something that can be shown.
+    for bounds in [
+        PlotBounds::NOTHING,
+        PlotBounds::from_min_max([1.0, 1.0], [1.0, 1.0]),
+        PlotBounds::from_min_max([f64::NAN, 0.0], [f64::INFINITY, 0.0]),
+    ] {
+        assert!(PlotTransform::new(frame, bounds, false).bounds().is_valid());
+    }
+}
+
+#[test]
+fn test_log_scale() {
+    let frame = Rect::from_min_size(Pos2::ZERO, Vec2::splat(100.0));
+    let mut transform = PlotTransform::new(
+        frame,
+        PlotBounds::from_min_max([0.0, 0.0], [1.0, 1000.0]),
+        false,
+    );
+    transform.set_scales([AxisScale::Linear, AxisScale::log10(1.0)]);
+
+    // Each power of ten takes a third of the height, and values below the floor are at the bottom.
+    assert!((transform.position_from_point_y(10.0) - 100.0 * 2.0 / 3.0).abs() < 1e-3);
+    assert!((transform.position_from_point_y(0.0) - 100.0).abs() < 1e-3);
+    let value = transform.value_from_position(Pos2::new(0.0, 100.0 / 3.0));
+    assert!((value.y - 100.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_symlog_scale() {
+    let scale = AxisScale::symlog(10.0);
+    assert_eq!(scale.apply(5.0), 0.5);
+    assert_eq!(scale.apply(-100.0), -2.0);
+    assert_eq!(scale.apply(0.0), 0.0);
+    for value in [-12345.0, -10.0, -0.5, 0.0, 3.0, 10.0, 1e6] {
+        assert!((scale.invert(scale.apply(value)) - value).abs() < 1e-9 * value.abs().max(1.0));
+    }
+}
+
+#[test]
+fn test_positions_from_points() {
+    let frame = Rect::from_min_size(Pos2::new(10.0, 20.0), Vec2::new(300.0, 200.0));
+    let points: Vec<PlotPoint> = (0..100)
+        .map(|i| PlotPoint::new(1.7e9 + i as f64 * 0.37, (i as f64 * 0.1).sin() + 1.5))
+        .collect();
+    for scales in [
+        [AxisScale::Linear; 2],
+        [AxisScale::Linear, AxisScale::log10(0.1)],
+    ] {
+        let mut transform = PlotTransform::new(
+            frame,
+            PlotBounds::from_min_max([1.7e9, 0.1], [1.7e9 + 40.0, 3.0]),
+            false,
+        );
+        transform.set_scales(scales);
+        let mut positions = Vec::new();
+        transform.positions_from_points(&points, &mut positions);
+        for (point, pos) in points.iter().zip(&positions) {
+            let expected = transform.position_from_point(point);
+            assert!((*pos - expected).length() < 1e-3, "{pos:?} != {expected:?}");
+        }
+    }
+}
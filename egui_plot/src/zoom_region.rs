@@ -0,0 +1,194 @@
+use egui::{CursorIcon, Id, Rect, Sense, Ui, Vec2b};
+
+use crate::{PlotBounds, PlotMemory, PlotPoint, PlotResponse};
+
+/// The thickness of the edges of the region that can be dragged to resize it, in ui points.
+const EDGE_THICKNESS: f32 = 6.0;
+
+/// An edge of a [`ZoomRegion`] that can be dragged.
+#[derive(Clone, Copy, Hash)]
+enum Edge {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Edge {
+    /// The area to drag the edge of the region by.
+    fn rect(self, region: Rect) -> Rect {
+        let half = EDGE_THICKNESS / 2.0;
+        match self {
+            Self::Left => Rect::from_x_y_ranges(
+                region.left() - half..=region.left() + half,
+                region.y_range(),
+            ),
+            Self::Right => Rect::from_x_y_ranges(
+                region.right() - half..=region.right() + half,
+                region.y_range(),
+            ),
+            Self::Top => {
+                Rect::from_x_y_ranges(region.x_range(), region.top() - half..=region.top() + half)
+            }
+            Self::Bottom => Rect::from_x_y_ranges(
+                region.x_range(),
+                region.bottom() - half..=region.bottom() + half,
+            ),
+        }
+    }
+
+    fn cursor(self) -> CursorIcon {
+        match self {
+            Self::Left | Self::Right => CursorIcon::ResizeHorizontal,
+            Self::Top | Self::Bottom => CursorIcon::ResizeVertical,
+        }
+    }
+}
+
+/// A rectangle on an overview plot that shows the bounds of a detail plot,
+/// which the user can drag to pan the detail plot or resize by its edges to zoom it.
+///
+/// The detail plot is found by its id, so give it a fixed one with [`crate::Plot::id`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, Plot, PlotPoints, ZoomRegion};
+///
+/// let detail_id = egui::Id::new("detail");
+/// let sin = || PlotPoints::from_explicit_callback(f64::sin, 0.0..=100.0, 2000);
+/// Plot::new("detail").id(detail_id).height(200.0).show(ui, |plot_ui| {
+///     plot_ui.line(Line::new(sin()));
+/// });
+/// let overview = Plot::new("overview").height(80.0).allow_drag(false).show(ui, |plot_ui| {
+///     plot_ui.line(Line::new(sin()));
+/// });
+/// ZoomRegion::new(detail_id).axes([true, false]).show(ui, &overview);
+/// # });
+/// ```
+pub struct ZoomRegion {
+    detail_id: Id,
+    axes: Vec2b,
+}
+
+impl ZoomRegion {
+    /// The region of the plot with the given id.
+    pub fn new(detail_id: Id) -> Self {
+        Self {
+            detail_id,
+            axes: true.into(),
+        }
+    }
+
+    /// Which axes of the detail plot to show and change. Default: both.
+    ///
+    /// On the other axes the region covers the whole overview plot,
+    /// like a band showing the x-range of the detail plot.
+    #[inline]
+    pub fn axes(mut self, axes: impl Into<Vec2b>) -> Self {
+        self.axes = axes.into();
+        self
+    }
+
+    /// Show the region on top of the overview plot that was just shown with the given response.
+    ///
+    /// Returns the response of dragging the region.
+    pub fn show<R>(self, ui: &Ui, overview: &PlotResponse<R>) -> Option<egui::Response> {
+        let mut mem = PlotMemory::load(ui.ctx(), self.detail_id)?;
+        let transform = &overview.transform;
+        let frame = *transform.frame();
+
+        let bounds = *mem.bounds();
+        let mut rect = transform.rect_from_values(
+            &PlotPoint::new(bounds.min()[0], bounds.min()[1]),
+            &PlotPoint::new(bounds.max()[0], bounds.max()[1]),
+        );
+        if !self.axes.x {
+            rect.set_left(frame.left());
+            rect.set_right(frame.right());
+        }
+        if !self.axes.y {
+            rect.set_top(frame.top());
+            rect.set_bottom(frame.bottom());
+        }
+
+        // The edges are added last, so they are on top of the body.
+        let id = overview.response.id.with(self.detail_id);
+        let body = ui.interact(rect.intersect(frame), id, Sense::drag());
+        let cursor = if body.dragged() {
+            CursorIcon::Grabbing
+        } else {
+            CursorIcon::Grab
+        };
+        let body = body.on_hover_and_drag_cursor(cursor);
+        let mut edges = Vec::new();
+        if self.axes.x {
+            edges.extend([Edge::Left, Edge::Right]);
+        }
+        if self.axes.y {
+            edges.extend([Edge::Top, Edge::Bottom]);
+        }
+        let edges: Vec<_> = edges
+            .into_iter()
+            .map(|edge| {
+                let response = ui
+                    .interact(
+                        edge.rect(rect).intersect(frame),
+                        id.with(edge),
+                        Sense::drag(),
+                    )
+                    .on_hover_and_drag_cursor(edge.cursor());
+                (edge, response)
+            })
+            .collect();
+
+        // Move the dragged edge, or the whole region.
+        let mut new_rect = rect;
+        if let Some((edge, dragged)) = edges.iter().find(|(_, response)| response.dragged()) {
+            let delta = dragged.drag_delta();
+            match edge {
+                Edge::Left => new_rect.min.x += delta.x,
+                Edge::Right => new_rect.max.x += delta.x,
+                Edge::Top => new_rect.min.y += delta.y,
+                Edge::Bottom => new_rect.max.y += delta.y,
+            }
+        } else if body.dragged() {
+            new_rect = new_rect.translate(body.drag_delta());
+        }
+        let response = edges
+            .into_iter()
+            .fold(body, |response, (_, edge)| response | edge);
+
+        if new_rect != rect && new_rect.is_positive() {
+            if response.drag_started() {
+                mem.view_history.record(bounds);
+            }
+            let min = transform.value_from_position(new_rect.left_bottom());
+            let max = transform.value_from_position(new_rect.right_top());
+            let new_bounds = PlotBounds::from_min_max([min.x, min.y], [max.x, max.y]);
+            let mut bounds = bounds;
+            if self.axes.x {
+                bounds.set_x(&new_bounds);
+                mem.auto_bounds.x = false;
+            }
+            if self.axes.y {
+                bounds.set_y(&new_bounds);
+                mem.auto_bounds.y = false;
+            }
+            mem.set_bounds(bounds);
+            mem.store(ui.ctx(), self.detail_id);
+            rect = new_rect;
+        }
+
+        let visuals = &ui.visuals().selection;
+        let painter = ui.painter().with_clip_rect(frame);
+        painter.rect(
+            rect,
+            0.0,
+            visuals.bg_fill.gamma_multiply(0.2),
+            visuals.stroke,
+            egui::StrokeKind::Inside,
+        );
+
+        Some(response)
+    }
+}
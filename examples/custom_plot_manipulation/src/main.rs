@@ -3,7 +3,7 @@
 #![allow(rustdoc::missing_crate_level_docs)] // it's an example
 
 use eframe::egui::{self, DragValue, Event, Vec2};
-use egui_plot::{Legend, Line, PlotPoints};
+use egui_plot::{gallery::PlotExample, Legend, Line, PlotPoints};
 
 fn main() -> eframe::Result {
     env_logger::init(); // Log to stderr (if you run with `RUST_LOG=debug`).
@@ -11,11 +11,11 @@ fn main() -> eframe::Result {
     eframe::run_native(
         "Plot",
         options,
-        Box::new(|_cc| Ok(Box::<PlotExample>::default())),
+        Box::new(|_cc| Ok(Box::<CustomPlotManipulation>::default())),
     )
 }
 
-struct PlotExample {
+struct CustomPlotManipulation {
     lock_x: bool,
     lock_y: bool,
     ctrl_to_zoom: bool,
@@ -24,7 +24,7 @@ struct PlotExample {
     scroll_speed: f32,
 }
 
-impl Default for PlotExample {
+impl Default for CustomPlotManipulation {
     fn default() -> Self {
         Self {
             lock_x: false,
@@ -37,93 +37,118 @@ impl Default for PlotExample {
     }
 }
 
-impl eframe::App for PlotExample {
+impl eframe::App for CustomPlotManipulation {
     fn update(&mut self, ctx: &egui::Context, _: &mut eframe::Frame) {
-        egui::SidePanel::left("options").show(ctx, |ui| {
-            ui.checkbox(&mut self.lock_x, "Lock x axis").on_hover_text("Check to keep the X axis fixed, i.e., pan and zoom will only affect the Y axis");
-            ui.checkbox(&mut self.lock_y, "Lock y axis").on_hover_text("Check to keep the Y axis fixed, i.e., pan and zoom will only affect the X axis");
-            ui.checkbox(&mut self.ctrl_to_zoom, "Ctrl to zoom").on_hover_text("If unchecked, the behavior of the Ctrl key is inverted compared to the default controls\ni.e., scrolling the mouse without pressing any keys zooms the plot");
-            ui.checkbox(&mut self.shift_to_horizontal, "Shift for horizontal scroll").on_hover_text("If unchecked, the behavior of the shift key is inverted compared to the default controls\ni.e., hold to scroll vertically, release to scroll horizontally");
-            ui.horizontal(|ui| {
-                ui.add(
-                    DragValue::new(&mut self.zoom_speed)
-                        .range(0.1..=2.0)
-                        .speed(0.1),
-                );
-                ui.label("Zoom speed").on_hover_text("How fast to zoom in and out with the mouse wheel");
-            });
-            ui.horizontal(|ui| {
-                ui.add(
-                    DragValue::new(&mut self.scroll_speed)
-                        .range(0.1..=100.0)
-                        .speed(0.1),
-                );
-                ui.label("Scroll speed").on_hover_text("How fast to pan with the mouse wheel");
-            });
+        egui::SidePanel::left("options").show(ctx, |ui| self.options_ui(ui));
+        egui::CentralPanel::default().show(ctx, |ui| self.plot_ui(ui));
+    }
+}
+
+/// Lets the example be shown in a gallery with other plots and screenshot tested.
+impl PlotExample for CustomPlotManipulation {
+    fn name(&self) -> &'static str {
+        "Custom Plot Manipulation"
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        ui.collapsing("Options", |ui| self.options_ui(ui));
+        self.plot_ui(ui)
+    }
+}
+
+impl CustomPlotManipulation {
+    fn options_ui(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.lock_x, "Lock x axis").on_hover_text(
+            "Check to keep the X axis fixed, i.e., pan and zoom will only affect the Y axis",
+        );
+        ui.checkbox(&mut self.lock_y, "Lock y axis").on_hover_text(
+            "Check to keep the Y axis fixed, i.e., pan and zoom will only affect the X axis",
+        );
+        ui.checkbox(&mut self.ctrl_to_zoom, "Ctrl to zoom").on_hover_text("If unchecked, the behavior of the Ctrl key is inverted compared to the default controls\ni.e., scrolling the mouse without pressing any keys zooms the plot");
+        ui.checkbox(&mut self.shift_to_horizontal, "Shift for horizontal scroll").on_hover_text("If unchecked, the behavior of the shift key is inverted compared to the default controls\ni.e., hold to scroll vertically, release to scroll horizontally");
+        ui.horizontal(|ui| {
+            ui.add(
+                DragValue::new(&mut self.zoom_speed)
+                    .range(0.1..=2.0)
+                    .speed(0.1),
+            );
+            ui.label("Zoom speed")
+                .on_hover_text("How fast to zoom in and out with the mouse wheel");
+        });
+        ui.horizontal(|ui| {
+            ui.add(
+                DragValue::new(&mut self.scroll_speed)
+                    .range(0.1..=100.0)
+                    .speed(0.1),
+            );
+            ui.label("Scroll speed")
+                .on_hover_text("How fast to pan with the mouse wheel");
         });
-        egui::CentralPanel::default().show(ctx, |ui| {
-            let (scroll, pointer_down, modifiers) = ui.input(|i| {
-                let scroll = i.events.iter().find_map(|e| match e {
-                    Event::MouseWheel {
-                        unit: _,
-                        delta,
-                        modifiers: _,
-                    } => Some(*delta),
-                    _ => None,
-                });
-                (scroll, i.pointer.primary_down(), i.modifiers)
+    }
+
+    fn plot_ui(&self, ui: &mut egui::Ui) -> egui::Response {
+        let (scroll, pointer_down, modifiers) = ui.input(|i| {
+            let scroll = i.events.iter().find_map(|e| match e {
+                Event::MouseWheel {
+                    unit: _,
+                    delta,
+                    modifiers: _,
+                } => Some(*delta),
+                _ => None,
             });
+            (scroll, i.pointer.primary_down(), i.modifiers)
+        });
 
-            ui.label("This example shows how to use raw input events to implement different plot controls than the ones egui provides by default, e.g., default to zooming instead of panning when the Ctrl key is not pressed, or controlling much it zooms with each mouse wheel step.");
+        ui.label("This example shows how to use raw input events to implement different plot controls than the ones egui provides by default, e.g., default to zooming instead of panning when the Ctrl key is not pressed, or controlling much it zooms with each mouse wheel step.");
 
-            egui_plot::Plot::new("plot")
-                .allow_zoom(false)
-                .allow_drag(false)
-                .allow_scroll(false)
-                .legend(Legend::default())
-                .show(ui, |plot_ui| {
-                    if let Some(mut scroll) = scroll {
-                        if modifiers.ctrl == self.ctrl_to_zoom {
-                            scroll = Vec2::splat(scroll.x + scroll.y);
-                            let mut zoom_factor = Vec2::from([
-                                (scroll.x * self.zoom_speed / 10.0).exp(),
-                                (scroll.y * self.zoom_speed / 10.0).exp(),
-                            ]);
-                            if self.lock_x {
-                                zoom_factor.x = 1.0;
-                            }
-                            if self.lock_y {
-                                zoom_factor.y = 1.0;
-                            }
-                            plot_ui.zoom_bounds_around_hovered(zoom_factor);
-                        } else {
-                            if modifiers.shift == self.shift_to_horizontal {
-                                scroll = Vec2::new(scroll.y, scroll.x);
-                            }
-                            if self.lock_x {
-                                scroll.x = 0.0;
-                            }
-                            if self.lock_y {
-                                scroll.y = 0.0;
-                            }
-                            let delta_pos = self.scroll_speed * scroll;
-                            plot_ui.translate_bounds(delta_pos);
+        egui_plot::Plot::new("plot")
+            .allow_zoom(false)
+            .allow_drag(false)
+            .allow_scroll(false)
+            .legend(Legend::default())
+            .show(ui, |plot_ui| {
+                if let Some(mut scroll) = scroll {
+                    if modifiers.ctrl == self.ctrl_to_zoom {
+                        scroll = Vec2::splat(scroll.x + scroll.y);
+                        let mut zoom_factor = Vec2::from([
+                            (scroll.x * self.zoom_speed / 10.0).exp(),
+                            (scroll.y * self.zoom_speed / 10.0).exp(),
+                        ]);
+                        if self.lock_x {
+                            zoom_factor.x = 1.0;
+                        }
+                        if self.lock_y {
+                            zoom_factor.y = 1.0;
+                        }
+                        plot_ui.zoom_bounds_around_hovered(zoom_factor);
+                    } else {
+                        if modifiers.shift == self.shift_to_horizontal {
+                            scroll = Vec2::new(scroll.y, scroll.x);
                         }
-                    }
-                    if plot_ui.response().hovered() && pointer_down {
-                        let mut pointer_translate = -plot_ui.pointer_coordinate_drag_delta();
                         if self.lock_x {
-                            pointer_translate.x = 0.0;
+                            scroll.x = 0.0;
                         }
                         if self.lock_y {
-                            pointer_translate.y = 0.0;
+                            scroll.y = 0.0;
                         }
-                        plot_ui.translate_bounds(pointer_translate);
+                        let delta_pos = self.scroll_speed * scroll;
+                        plot_ui.translate_bounds(delta_pos);
+                    }
+                }
+                if plot_ui.response().hovered() && pointer_down {
+                    let mut pointer_translate = -plot_ui.pointer_coordinate_drag_delta();
+                    if self.lock_x {
+                        pointer_translate.x = 0.0;
                     }
+                    if self.lock_y {
+                        pointer_translate.y = 0.0;
+                    }
+                    plot_ui.translate_bounds(pointer_translate);
+                }
 
-                    let sine_points = PlotPoints::from_explicit_callback(|x| x.sin(), .., 5000);
-                    plot_ui.line(Line::new(sine_points).name("Sine"));
-                });
-        });
+                let sine_points = PlotPoints::from_explicit_callback(|x| x.sin(), .., 5000);
+                plot_ui.line(Line::new(sine_points).name("Sine"));
+            })
+            .response
     }
 }
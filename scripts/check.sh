@@ -28,6 +28,7 @@ CLIPPY_CONF_DIR="scripts/clippy_wasm" cargo clippy --quiet --target wasm32-unkno
 
 cargo check --quiet  --all-targets
 cargo check --quiet  --all-targets --no-default-features
+cargo check --quiet  -p egui_plot --lib --all-targets --no-default-features --features serde
 cargo check --quiet  --all-targets --all-features
 cargo test  --quiet --all-targets --all-features
 cargo test  --quiet --doc # slow - checks all doc-tests